@@ -0,0 +1,363 @@
+use crate::{
+    algorand_accounts::account_json::AlgorandAccountJson,
+    algorand_applications::algorand_state_schema::AlgorandStateSchema,
+    algorand_consensus::consensus_params::ConsensusParams,
+    algorand_micro_algos::MicroAlgos,
+    algorand_transactions::{
+        application_transaction::OnCompletion,
+        transaction::AlgorandTransaction,
+        transaction_type::AlgorandTransactionType,
+    },
+    algorand_types::Result,
+};
+
+/// ## Account Holdings
+///
+/// Everything an account holds which counts towards its minimum balance requirement. Note that
+/// creating an asset opts the creator in to it, so assets created are counted as assets held.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AccountHoldings {
+    /// The number of assets held, including those the account created.
+    pub num_assets: u64,
+
+    /// The number of applications created.
+    pub num_apps_created: u64,
+
+    /// The number of applications opted in to.
+    pub num_apps_opted_in: u64,
+
+    /// The total number of extra program pages of the applications created.
+    pub num_extra_app_pages: u64,
+
+    /// The total of the global state schemas of the applications created, and of the local state
+    /// schemas of the applications opted in to.
+    pub app_schema: AlgorandStateSchema,
+
+    /// The number of boxes held by the account, where the account is an application's account.
+    pub num_boxes: u64,
+
+    /// The total number of bytes of the names and values of the boxes held.
+    pub box_bytes: u64,
+}
+
+impl AccountHoldings {
+    /// ## From Account Json
+    ///
+    /// Get the holdings of an account as served by algod or the indexer.
+    pub fn from_account_json(json: &AlgorandAccountJson) -> Self {
+        let len = |maybe_vec_len: Option<usize>| maybe_vec_len.unwrap_or_default() as u64;
+        Self {
+            num_assets: json
+                .total_assets_opted_in
+                .unwrap_or_else(|| len(json.assets.as_ref().map(|x| x.len()))),
+            num_apps_created: json
+                .total_created_apps
+                .unwrap_or_else(|| len(json.created_apps.as_ref().map(|x| x.len()))),
+            num_apps_opted_in: json
+                .total_apps_opted_in
+                .unwrap_or_else(|| len(json.apps_local_state.as_ref().map(|x| x.len()))),
+            num_extra_app_pages: json.apps_total_extra_pages.unwrap_or_default(),
+            app_schema: json
+                .apps_total_schema
+                .as_ref()
+                .map(AlgorandStateSchema::from_json)
+                .unwrap_or_default(),
+            num_boxes: json.total_boxes.unwrap_or_default(),
+            box_bytes: json.total_box_bytes.unwrap_or_default(),
+        }
+    }
+
+    /// ## Min Balance
+    ///
+    /// Calculate the minimum balance an account with these holdings must maintain under the given
+    /// consensus params, failing if that overflows.
+    pub fn min_balance(&self, params: &ConsensusParams) -> Result<MicroAlgos> {
+        let overflow_error = || format!("Minimum balance of {self:?} overflows!");
+        let num_entries = self
+            .app_schema
+            .num_uint
+            .checked_add(self.app_schema.num_byte_slice);
+        let num_apps_created = self.num_apps_created.checked_add(self.num_extra_app_pages);
+        [
+            (params.min_balance, self.num_assets.checked_add(1)),
+            (params.app_flat_params_min_balance, num_apps_created),
+            (
+                params.app_flat_opt_in_min_balance,
+                Some(self.num_apps_opted_in),
+            ),
+            (params.schema_min_balance_per_entry, num_entries),
+            (
+                params.schema_uint_min_balance,
+                Some(self.app_schema.num_uint),
+            ),
+            (
+                params.schema_bytes_min_balance,
+                Some(self.app_schema.num_byte_slice),
+            ),
+            (params.box_flat_min_balance, Some(self.num_boxes)),
+            (params.box_byte_min_balance, Some(self.box_bytes)),
+        ]
+        .iter()
+        .try_fold(0u64, |total, (price, count)| {
+            count
+                .and_then(|count| price.checked_mul(count))
+                .and_then(|cost| total.checked_add(cost))
+        })
+        .map(MicroAlgos::new)
+        .ok_or_else(|| overflow_error().into())
+    }
+
+    // NOTE: These saturate rather than overflow, since any saturated count is then too large for
+    // the minimum balance calculation, which fails instead.
+    fn with_schema(mut self, schema: &AlgorandStateSchema) -> Self {
+        self.app_schema.num_uint = self.app_schema.num_uint.saturating_add(schema.num_uint);
+        self.app_schema.num_byte_slice = self
+            .app_schema
+            .num_byte_slice
+            .saturating_add(schema.num_byte_slice);
+        self
+    }
+
+    /// ## With Asset Opt In
+    ///
+    /// The holdings after opting in to, or creating, an asset.
+    pub fn with_asset_opt_in(mut self) -> Self {
+        self.num_assets = self.num_assets.saturating_add(1);
+        self
+    }
+
+    /// ## With App Opt In
+    ///
+    /// The holdings after opting in to an application with the given local state schema.
+    pub fn with_app_opt_in(mut self, local_state_schema: &AlgorandStateSchema) -> Self {
+        self.num_apps_opted_in = self.num_apps_opted_in.saturating_add(1);
+        self.with_schema(local_state_schema)
+    }
+
+    /// ## With App Creation
+    ///
+    /// The holdings after creating an application with the given global state schema and number
+    /// of extra program pages.
+    pub fn with_app_creation(
+        mut self,
+        global_state_schema: &AlgorandStateSchema,
+        extra_program_pages: u64,
+    ) -> Self {
+        self.num_apps_created = self.num_apps_created.saturating_add(1);
+        self.num_extra_app_pages = self.num_extra_app_pages.saturating_add(extra_program_pages);
+        self.with_schema(global_state_schema)
+    }
+
+    /// ## With Box
+    ///
+    /// The holdings after creating a box with a name and value of the given sizes.
+    pub fn with_box(mut self, name_size: u64, value_size: u64) -> Self {
+        self.num_boxes = self.num_boxes.saturating_add(1);
+        self.box_bytes = self
+            .box_bytes
+            .saturating_add(name_size)
+            .saturating_add(value_size);
+        self
+    }
+
+    /// ## With Transaction
+    ///
+    /// The holdings of the sender after the given transaction is confirmed, where the local state
+    /// schema of the application being opted in to, if any, must be supplied since it is not part
+    /// of the transaction.
+    pub fn with_transaction(
+        self,
+        tx: &AlgorandTransaction,
+        app_local_state_schema: Option<&AlgorandStateSchema>,
+    ) -> Self {
+        let is_opt_in = tx.on_completion == Some(OnCompletion::Optin.to_u64());
+        match tx.txn_type {
+            Some(AlgorandTransactionType::AssetTransfer)
+                if tx.sender.is_some()
+                    && tx.sender == tx.asset_receiver
+                    && tx.asset_close_to.is_none()
+                    && tx.asset_sender.is_none()
+                    && tx.asset_amount.unwrap_or_default() == 0 =>
+            {
+                self.with_asset_opt_in()
+            },
+            Some(AlgorandTransactionType::AssetConfiguration)
+                if tx.asset_id.unwrap_or_default() == 0 && tx.asset_parameters.is_some() =>
+            {
+                self.with_asset_opt_in()
+            },
+            Some(AlgorandTransactionType::ApplicationCall)
+                if tx.application_id.unwrap_or_default() == 0 =>
+            {
+                let holdings = self.with_app_creation(
+                    &tx.global_state_schema.unwrap_or_default(),
+                    tx.extra_program_pages.unwrap_or_default(),
+                );
+                if is_opt_in {
+                    holdings.with_app_opt_in(&tx.local_state_schema.unwrap_or_default())
+                } else {
+                    holdings
+                }
+            },
+            Some(AlgorandTransactionType::ApplicationCall) if is_opt_in => {
+                self.with_app_opt_in(&app_local_state_schema.copied().unwrap_or_default())
+            },
+            _ => self,
+        }
+    }
+}
+
+impl AlgorandTransaction {
+    /// ## Min Balance Increase
+    ///
+    /// Calculate how much the given transaction would increase the sender's minimum balance
+    /// requirement by, eg when opting in to an asset or creating an application. The local state
+    /// schema of the application being opted in to, if any, must be supplied since it is not part
+    /// of the transaction.
+    pub fn min_balance_increase(
+        &self,
+        params: &ConsensusParams,
+        app_local_state_schema: Option<&AlgorandStateSchema>,
+    ) -> Result<MicroAlgos> {
+        let holdings = AccountHoldings::default();
+        Ok(holdings
+            .with_transaction(self, app_local_state_schema)
+            .min_balance(params)?
+            .saturating_sub(holdings.min_balance(params)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        algorand_accounts::test_utils::get_sample_account_json_str,
+        algorand_hash::AlgorandHash,
+        algorand_transactions::{
+            suggested_params::SuggestedParams,
+            transaction_builder::TransactionBuilder,
+        },
+        test_utils::get_sample_algorand_address,
+    };
+
+    fn get_sample_params() -> SuggestedParams {
+        SuggestedParams::new(
+            MicroAlgos::new(0),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+    }
+
+    #[test]
+    fn should_calculate_min_balance_of_account() {
+        let json = AlgorandAccountJson::from_str(&get_sample_account_json_str()).unwrap();
+        let holdings = AccountHoldings::from_account_json(&json);
+        let result = holdings.min_balance(&ConsensusParams::default()).unwrap();
+        let expected_result = MicroAlgos::new(json.min_balance.unwrap());
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_calculate_min_balance_of_empty_account() {
+        let result = AccountHoldings::default()
+            .min_balance(&ConsensusParams::default())
+            .unwrap();
+        assert_eq!(result, MicroAlgos::new(100_000));
+    }
+
+    #[test]
+    fn should_calculate_min_balance_with_boxes() {
+        let holdings = AccountHoldings::default().with_box(3, 1021);
+        let result = holdings.min_balance(&ConsensusParams::default()).unwrap();
+        let expected_result = MicroAlgos::new(100_000 + 2_500 + 400 * 1024);
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_calculate_min_balance_increase_of_asset_opt_in() {
+        let tx = TransactionBuilder::asset_opt_in(get_sample_algorand_address(), 1337)
+            .suggested_params(&get_sample_params())
+            .build()
+            .unwrap();
+        let result = tx
+            .min_balance_increase(&ConsensusParams::default(), None)
+            .unwrap();
+        assert_eq!(result, MicroAlgos::new(100_000));
+    }
+
+    #[test]
+    fn should_calculate_min_balance_increase_of_app_opt_in() {
+        let tx = TransactionBuilder::application_call(get_sample_algorand_address(), 1337)
+            .on_completion(OnCompletion::Optin)
+            .suggested_params(&get_sample_params())
+            .build()
+            .unwrap();
+        let local_state_schema = AlgorandStateSchema::new(1, 2);
+        let result = tx
+            .min_balance_increase(&ConsensusParams::default(), Some(&local_state_schema))
+            .unwrap();
+        let expected_result = MicroAlgos::new(100_000 + 25_000 * 3 + 3_500 * 2 + 25_000);
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_calculate_min_balance_increase_of_app_creation() {
+        let tx = TransactionBuilder::application_create(
+            get_sample_algorand_address(),
+            vec![0x06, 0x81, 0x01],
+            vec![0x06, 0x81, 0x01],
+            AlgorandStateSchema::new(2, 4),
+            AlgorandStateSchema::new(1, 2),
+        )
+        .extra_program_pages(1)
+        .suggested_params(&get_sample_params())
+        .build()
+        .unwrap();
+        let result = tx
+            .min_balance_increase(&ConsensusParams::default(), None)
+            .unwrap();
+        let expected_result = MicroAlgos::new(100_000 * 2 + 25_000 * 6 + 3_500 * 4 + 25_000 * 2);
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_not_increase_min_balance_for_payment() {
+        let address = get_sample_algorand_address();
+        let tx = TransactionBuilder::payment(address, address, 1)
+            .suggested_params(&get_sample_params())
+            .build()
+            .unwrap();
+        let result = tx
+            .min_balance_increase(&ConsensusParams::default(), None)
+            .unwrap();
+        assert_eq!(result, MicroAlgos::new(0));
+    }
+
+    #[test]
+    fn should_fail_to_calculate_min_balance_if_it_overflows() {
+        let holdings = AccountHoldings::default().with_box(u64::MAX, 1);
+        assert!(holdings.min_balance(&ConsensusParams::default()).is_err());
+    }
+
+    #[test]
+    fn should_saturate_counts_rather_than_overflow() {
+        let holdings = AccountHoldings {
+            num_assets: u64::MAX,
+            num_apps_created: u64::MAX,
+            num_apps_opted_in: u64::MAX,
+            num_boxes: u64::MAX,
+            ..Default::default()
+        }
+        .with_asset_opt_in()
+        .with_app_opt_in(&AlgorandStateSchema::default())
+        .with_app_creation(&AlgorandStateSchema::default(), 0)
+        .with_box(0, 0);
+        assert_eq!(holdings.num_assets, u64::MAX);
+        assert_eq!(holdings.num_apps_created, u64::MAX);
+        assert_eq!(holdings.num_apps_opted_in, u64::MAX);
+        assert_eq!(holdings.num_boxes, u64::MAX);
+        assert!(holdings.min_balance(&ConsensusParams::default()).is_err());
+    }
+}
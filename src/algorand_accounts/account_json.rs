@@ -0,0 +1,222 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_errors::AlgorandError,
+    algorand_transactions::{
+        application_transaction::StateSchema,
+        asset_parameters::AssetParametersJson,
+    },
+    algorand_types::Result,
+};
+
+/// ## Algorand Account Json
+///
+/// The account model served by both algod's `/v2/accounts/{address}` and indexer's `/v2/accounts`
+/// endpoints.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandAccountJson {
+    pub address: String,
+
+    pub amount: u64,
+
+    #[serde(rename = "amount-without-pending-rewards")]
+    pub amount_without_pending_rewards: Option<u64>,
+
+    #[serde(rename = "apps-local-state")]
+    pub apps_local_state: Option<Vec<ApplicationLocalStateJson>>,
+
+    #[serde(rename = "apps-total-extra-pages")]
+    pub apps_total_extra_pages: Option<u64>,
+
+    #[serde(rename = "apps-total-schema")]
+    pub apps_total_schema: Option<StateSchema>,
+
+    pub assets: Option<Vec<AssetHoldingJson>>,
+
+    #[serde(rename = "auth-addr")]
+    pub auth_address: Option<String>,
+
+    #[serde(rename = "created-apps")]
+    pub created_apps: Option<Vec<ApplicationJson>>,
+
+    #[serde(rename = "created-assets")]
+    pub created_assets: Option<Vec<AssetJson>>,
+
+    #[serde(rename = "created-at-round")]
+    pub created_at_round: Option<u64>,
+
+    pub deleted: Option<bool>,
+
+    #[serde(rename = "min-balance")]
+    pub min_balance: Option<u64>,
+
+    #[serde(rename = "pending-rewards")]
+    pub pending_rewards: Option<u64>,
+
+    #[serde(rename = "reward-base")]
+    pub reward_base: Option<u64>,
+
+    pub rewards: Option<u64>,
+
+    pub round: Option<u64>,
+
+    #[serde(rename = "sig-type")]
+    pub sig_type: Option<String>,
+
+    pub status: Option<String>,
+
+    #[serde(rename = "total-apps-opted-in")]
+    pub total_apps_opted_in: Option<u64>,
+
+    #[serde(rename = "total-assets-opted-in")]
+    pub total_assets_opted_in: Option<u64>,
+
+    #[serde(rename = "total-box-bytes")]
+    pub total_box_bytes: Option<u64>,
+
+    #[serde(rename = "total-boxes")]
+    pub total_boxes: Option<u64>,
+
+    #[serde(rename = "total-created-apps")]
+    pub total_created_apps: Option<u64>,
+
+    #[serde(rename = "total-created-assets")]
+    pub total_created_assets: Option<u64>,
+}
+
+impl FromStr for AlgorandAccountJson {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+impl Display for AlgorandAccountJson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetHoldingJson {
+    pub amount: u64,
+
+    #[serde(rename = "asset-id")]
+    pub asset_id: u64,
+
+    #[serde(rename = "is-frozen")]
+    pub is_frozen: bool,
+
+    pub deleted: Option<bool>,
+
+    #[serde(rename = "opted-in-at-round")]
+    pub opted_in_at_round: Option<u64>,
+
+    #[serde(rename = "opted-out-at-round")]
+    pub opted_out_at_round: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationLocalStateJson {
+    pub id: u64,
+
+    pub schema: StateSchema,
+
+    #[serde(rename = "key-value")]
+    pub key_value: Option<JsonValue>,
+
+    pub deleted: Option<bool>,
+
+    #[serde(rename = "opted-in-at-round")]
+    pub opted_in_at_round: Option<u64>,
+
+    #[serde(rename = "closed-out-at-round")]
+    pub closed_out_at_round: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationJson {
+    pub id: u64,
+
+    pub params: ApplicationParamsJson,
+
+    pub deleted: Option<bool>,
+
+    #[serde(rename = "created-at-round")]
+    pub created_at_round: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplicationParamsJson {
+    pub creator: Option<String>,
+
+    #[serde(rename = "approval-program")]
+    pub approval_program: Option<String>,
+
+    #[serde(rename = "clear-state-program")]
+    pub clear_state_program: Option<String>,
+
+    #[serde(rename = "extra-program-pages")]
+    pub extra_program_pages: Option<u64>,
+
+    #[serde(rename = "global-state")]
+    pub global_state: Option<JsonValue>,
+
+    #[serde(rename = "global-state-schema")]
+    pub global_state_schema: Option<StateSchema>,
+
+    #[serde(rename = "local-state-schema")]
+    pub local_state_schema: Option<StateSchema>,
+}
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetJson {
+    pub index: u64,
+
+    pub params: AssetParametersJson,
+
+    pub deleted: Option<bool>,
+
+    #[serde(rename = "created-at-round")]
+    pub created_at_round: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_accounts::test_utils::get_sample_account_json_str;
+
+    #[test]
+    fn should_get_account_json_from_str() {
+        let result = AlgorandAccountJson::from_str(&get_sample_account_json_str());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_serde_account_json_to_and_from_str() {
+        let json = AlgorandAccountJson::from_str(&get_sample_account_json_str()).unwrap();
+        let result = AlgorandAccountJson::from_str(&json.to_string()).unwrap();
+        assert_eq!(result, json);
+    }
+
+    #[test]
+    fn should_get_holdings_from_account_json() {
+        let json = AlgorandAccountJson::from_str(&get_sample_account_json_str()).unwrap();
+        let assets = json.assets.unwrap();
+        assert_eq!(assets.len(), 2);
+        assert_eq!(assets[0].asset_id, 27165954);
+        assert_eq!(json.created_assets.unwrap()[0].index, 27165954);
+        assert_eq!(json.apps_local_state.unwrap()[0].schema.num_uint, Some(2));
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod account_holdings;
+pub(crate) mod account_json;
+pub(crate) mod test_utils;
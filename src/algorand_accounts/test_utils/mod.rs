@@ -0,0 +1,8 @@
+#![cfg(test)]
+use std::fs::read_to_string;
+
+const SAMPLE_ACCOUNT_PATH: &str = "src/algorand_accounts/test_utils/sample-account.json";
+
+pub fn get_sample_account_json_str() -> String {
+    read_to_string(SAMPLE_ACCOUNT_PATH).unwrap()
+}
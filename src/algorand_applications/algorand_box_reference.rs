@@ -0,0 +1,39 @@
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize};
+
+use crate::{algorand_types::Bytes, predicates::is_zero};
+
+/// ## Algorand Box Reference
+///
+/// A reference to a box an application call may access. The index is that of the box's
+/// application in the transaction's foreign apps array, where zero refers to the called
+/// application itself.
+#[derive(Default, Debug, Eq, PartialEq, Clone, Serialize, Deserialize, Constructor)]
+pub struct AlgorandBoxReference {
+    #[serde(rename = "i", default, skip_serializing_if = "is_zero")]
+    pub index: u64,
+
+    #[serde(rename = "n", with = "serde_bytes", default)]
+    pub name: Bytes,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_box_reference_to_msg_pack() {
+        let box_reference = AlgorandBoxReference::new(1, b"box".to_vec());
+        let result = hex::encode(rmp_serde::to_vec_named(&box_reference).unwrap());
+        let expected_result = "82a16901a16ec403626f78";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_omit_zero_index_when_encoding_box_reference() {
+        let box_reference = AlgorandBoxReference::new(0, b"box".to_vec());
+        let result = hex::encode(rmp_serde::to_vec_named(&box_reference).unwrap());
+        let expected_result = "81a16ec403626f78";
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,190 @@
+use std::{cell::Cell, collections::BTreeMap};
+
+use derive_more::{Constructor, Deref};
+use rmp_serde::RawRef;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_bytes::ByteBuf;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_blocks::signed_transaction_in_block::AlgorandSignedTransactionInBlock,
+    algorand_types::{Bytes, Result},
+    predicates::{is_empty_vec, is_zero_option},
+};
+
+thread_local! {
+    static IS_ENCODING_RAW_MSG_PACK_STRS: Cell<bool> = const { Cell::new(false) };
+}
+
+// NOTE: Resets the flag to its prior value even if encoding panics.
+struct RawMsgPackStrsGuard(bool);
+
+impl Drop for RawMsgPackStrsGuard {
+    fn drop(&mut self) {
+        IS_ENCODING_RAW_MSG_PACK_STRS.with(|flag| flag.set(self.0));
+    }
+}
+
+/// ## To Msg Pack Bytes With Raw Strs
+///
+/// Encode to msgpack as `go-algorand` does, wherein byte strings which aren't valid UTF-8 are
+/// still encoded as msgpack strings, as is required for hashes of them to match.
+// NOTE: Raw strings are only safe to hand to `rmp_serde`'s own serializer, which writes their
+// bytes as-is, so they're only ever encoded whilst this is the serializer in use on this thread.
+pub(crate) fn to_msg_pack_bytes_with_raw_strs<T: Serialize>(value: &T) -> Result<Bytes> {
+    let _guard = RawMsgPackStrsGuard(IS_ENCODING_RAW_MSG_PACK_STRS.with(|flag| flag.replace(true)));
+    Ok(rmp_serde::to_vec_named(value)?)
+}
+
+/// ## Algorand Byte String
+///
+/// Bytes which `go-algorand` encodes as a msgpack string rather than as binary, such as state
+/// keys, byte slice values and logs. Since these need not be valid UTF-8, any which are not are
+/// decoded as-is. They're serialized as bytes, save for by `to_msg_pack_bytes_with_raw_strs`,
+/// which encodes them as a msgpack string so that hashes of them match.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Constructor, Deref)]
+pub struct AlgorandByteString(pub Bytes);
+
+impl Serialize for AlgorandByteString {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<<S as Serializer>::Ok, <S as Serializer>::Error>
+    where
+        S: Serializer,
+    {
+        match std::str::from_utf8(&self.0) {
+            Ok(s) => serializer.serialize_str(s),
+            Err(_) if IS_ENCODING_RAW_MSG_PACK_STRS.with(Cell::get) => {
+                RawRef::from_utf8(&self.0).serialize(serializer)
+            },
+            Err(_) => serializer.serialize_bytes(&self.0),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for AlgorandByteString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Ok(Self(ByteBuf::deserialize(deserializer)?.into_vec()))
+    }
+}
+
+impl From<&str> for AlgorandByteString {
+    fn from(s: &str) -> Self {
+        Self::new(s.as_bytes().to_vec())
+    }
+}
+
+/// ## Algorand State Delta
+///
+/// The changes made to the key/value store of an application's global state, or of the local
+/// state of an account opted in to it.
+pub type AlgorandStateDelta = BTreeMap<AlgorandByteString, AlgorandValueDelta>;
+
+/// ## Algorand Value Delta
+///
+/// A change made to a single key of an application's state, where the action is `1` when setting
+/// a byte slice, `2` when setting a uint and `3` when deleting the key.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandValueDelta {
+    #[serde(rename = "at", skip_serializing_if = "is_zero_option")]
+    pub action: Option<u64>,
+
+    #[serde(rename = "bs")]
+    pub bytes: Option<AlgorandByteString>,
+
+    #[serde(rename = "ui", skip_serializing_if = "is_zero_option")]
+    pub uint: Option<u64>,
+}
+
+/// ## Algorand Eval Delta
+///
+/// The effects of evaluating an application call which are recorded in the block, namely the
+/// changes to global and local state, the logs emitted and any inner transactions issued. Local
+/// state deltas are keyed by the index of the account in the transaction's accounts array, where
+/// zero refers to the sender, or else by the index in the shared accounts array past that.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandEvalDelta {
+    #[serde(rename = "gd")]
+    pub global_delta: Option<AlgorandStateDelta>,
+
+    #[serde(rename = "itx", skip_serializing_if = "is_empty_vec")]
+    pub inner_txs: Option<Vec<AlgorandSignedTransactionInBlock>>,
+
+    #[serde(rename = "ld")]
+    pub local_deltas: Option<BTreeMap<u64, AlgorandStateDelta>>,
+
+    #[serde(rename = "lg", skip_serializing_if = "is_empty_vec")]
+    pub logs: Option<Vec<AlgorandByteString>>,
+
+    #[serde(rename = "sa", skip_serializing_if = "is_empty_vec")]
+    pub shared_accounts: Option<Vec<AlgorandAddress>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_utf8_byte_string_as_msg_pack_str() {
+        let result = hex::encode(rmp_serde::to_vec(&AlgorandByteString::from("key")).unwrap());
+        let expected_result = "a36b6579";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_round_trip_non_utf8_byte_string_via_msg_pack_with_raw_strs() {
+        let byte_string = AlgorandByteString::new(vec![0xff, 0x00]);
+        let bytes = to_msg_pack_bytes_with_raw_strs(&byte_string).unwrap();
+        assert_eq!(hex::encode(&bytes), "a2ff00");
+        let result: AlgorandByteString = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(result, byte_string);
+    }
+
+    #[test]
+    fn should_encode_non_utf8_byte_string_as_bytes_by_default() {
+        let byte_string = AlgorandByteString::new(vec![0xff, 0x00]);
+        let bytes = rmp_serde::to_vec(&byte_string).unwrap();
+        assert_eq!(hex::encode(&bytes), "c402ff00");
+        let result: AlgorandByteString = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(result, byte_string);
+        assert_eq!(serde_json::to_string(&byte_string).unwrap(), "[255,0]");
+    }
+
+    #[test]
+    fn should_stop_encoding_raw_strs_once_done() {
+        let byte_string = AlgorandByteString::new(vec![0xff, 0x00]);
+        to_msg_pack_bytes_with_raw_strs(&byte_string).unwrap();
+        let result = hex::encode(rmp_serde::to_vec(&byte_string).unwrap());
+        assert_eq!(result, "c402ff00");
+    }
+
+    #[test]
+    fn should_decode_non_utf8_msg_pack_str_as_byte_string() {
+        let result: AlgorandByteString = rmp_serde::from_slice(&[0xa2, 0xff, 0x00]).unwrap();
+        assert_eq!(result, AlgorandByteString::new(vec![0xff, 0x00]));
+    }
+
+    #[test]
+    fn should_serde_eval_delta_to_and_from_msg_pack() {
+        let mut global_delta = AlgorandStateDelta::new();
+        global_delta.insert(AlgorandByteString::from("counter"), AlgorandValueDelta {
+            action: Some(2),
+            bytes: None,
+            uint: Some(7),
+        });
+        let eval_delta = AlgorandEvalDelta {
+            global_delta: Some(global_delta),
+            logs: Some(vec![AlgorandByteString::from("hello")]),
+            ..Default::default()
+        };
+        let bytes = rmp_serde::to_vec_named(&eval_delta).unwrap();
+        let expected_bytes = "82a2676481a7636f756e74657282a2617402a2756907a26c6791a568656c6c6f";
+        assert_eq!(hex::encode(&bytes), expected_bytes);
+        let result: AlgorandEvalDelta = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(result, eval_delta);
+    }
+}
@@ -0,0 +1,64 @@
+use derive_more::Constructor;
+use serde::{Deserialize, Serialize};
+
+use crate::{algorand_transactions::application_transaction::StateSchema, predicates::is_zero};
+
+/// ## Algorand State Schema
+///
+/// The number of uints and byte slices an application may store in its global state, or in the
+/// local state of each account opted in to it, in the format used when msgpack-ing transactions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Constructor)]
+pub struct AlgorandStateSchema {
+    #[serde(rename = "nbs", default, skip_serializing_if = "is_zero")]
+    pub num_byte_slice: u64,
+
+    #[serde(rename = "nui", default, skip_serializing_if = "is_zero")]
+    pub num_uint: u64,
+}
+
+impl AlgorandStateSchema {
+    pub fn is_empty(&self) -> bool {
+        self.num_byte_slice == 0 && self.num_uint == 0
+    }
+
+    /// ## Num Entries
+    ///
+    /// The total number of key/value entries the schema allows.
+    pub fn num_entries(&self) -> u64 {
+        self.num_byte_slice + self.num_uint
+    }
+
+    pub fn from_json(json: &StateSchema) -> Self {
+        Self {
+            num_byte_slice: json.num_byte_slice.unwrap_or_default(),
+            num_uint: json.num_uint.unwrap_or_default(),
+        }
+    }
+
+    pub fn to_json(&self) -> StateSchema {
+        StateSchema {
+            num_byte_slice: Some(self.num_byte_slice),
+            num_uint: Some(self.num_uint),
+        }
+    }
+}
+
+pub(crate) fn is_empty_schema(schema: &Option<AlgorandStateSchema>) -> bool {
+    match schema {
+        Some(schema) => schema.is_empty(),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_encode_state_schema_to_msg_pack() {
+        let schema = AlgorandStateSchema::new(1, 2);
+        let result = hex::encode(rmp_serde::to_vec_named(&schema).unwrap());
+        let expected_result = "82a36e627301a36e756902";
+        assert_eq!(result, expected_result);
+    }
+}
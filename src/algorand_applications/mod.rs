@@ -1,2 +1,5 @@
 pub(crate) mod algorand_application_args;
 pub(crate) mod algorand_application_ids;
+pub(crate) mod algorand_box_reference;
+pub(crate) mod algorand_eval_delta;
+pub(crate) mod algorand_state_schema;
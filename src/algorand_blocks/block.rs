@@ -5,13 +5,19 @@ use serde_json::json;
 use serde_with::skip_serializing_none;
 
 use crate::{
-    algorand_blocks::{block_header::AlgorandBlockHeader, block_json::AlgorandBlockJson},
+    algorand_blocks::{
+        block_header::AlgorandBlockHeader,
+        block_json::AlgorandBlockJson,
+        signed_transaction_in_block::AlgorandSignedTransactionInBlock,
+    },
     algorand_errors::AlgorandError,
     algorand_hash::AlgorandHash,
     algorand_transactions::{
+        algorand_transaction_proof::AlgorandTransactionProof,
         transaction::AlgorandTransaction,
         transaction_json::AlgorandTransactionJson,
         transactions::AlgorandTransactions,
+        transactions_merkle_tree::{AlgorandMerkleHashType, AlgorandTransactionsMerkleTree},
     },
     algorand_types::{Byte, Bytes, Result},
 };
@@ -21,6 +27,25 @@ use crate::{
 pub struct AlgorandBlock {
     pub block_header: AlgorandBlockHeader,
     pub transactions: Option<AlgorandTransactions>,
+
+    // NOTE: This is retained when decoding a block from msgpack
+    #[serde(skip)]
+    pub payset: Option<Vec<AlgorandSignedTransactionInBlock>>,
+}
+
+/// The form in which algod serves msgpack-ed blocks, where the block's certificate is ignored.
+#[derive(Deserialize)]
+struct AlgorandBlockMsgPack {
+    block: AlgorandBlockContentsMsgPack,
+}
+
+#[derive(Deserialize)]
+struct AlgorandBlockContentsMsgPack {
+    #[serde(flatten)]
+    block_header: AlgorandBlockHeader,
+
+    #[serde(default)]
+    txns: Vec<AlgorandSignedTransactionInBlock>,
 }
 
 impl AlgorandBlock {
@@ -48,6 +73,33 @@ impl AlgorandBlock {
         Self::from_json(&serde_json::from_slice::<AlgorandBlockJson>(bytes)?)
     }
 
+    /// ## From Msg Pack Bytes
+    ///
+    /// Decode a block from the msgpack-ed form served by algod, recovering the genesis ID & hash
+    /// of its transactions from the block header, and retaining their signatures, apply data and
+    /// inner transactions.
+    pub fn from_msg_pack_bytes(bytes: &[Byte]) -> Result<Self> {
+        let AlgorandBlockContentsMsgPack {
+            block_header,
+            txns: payset,
+        } = rmp_serde::from_slice::<AlgorandBlockMsgPack>(bytes)?.block;
+        let transactions = payset
+            .iter()
+            .map(|stib| {
+                stib.to_transaction(block_header.genesis_id.as_ref(), block_header.genesis_hash)
+            })
+            .collect::<Result<Vec<AlgorandTransaction>>>()?;
+        Ok(Self {
+            block_header,
+            transactions: if transactions.is_empty() {
+                None
+            } else {
+                Some(AlgorandTransactions::new(transactions))
+            },
+            payset: Some(payset),
+        })
+    }
+
     /// ## Hash
     ///
     /// Get the block's header hash
@@ -73,6 +125,7 @@ impl AlgorandBlock {
             } else {
                 Some(AlgorandTransactions::from_jsons(&json.transactions)?)
             },
+            payset: None,
         })
     }
 
@@ -127,6 +180,101 @@ impl AlgorandBlock {
             None => Err("No tx root in block header!".into()),
         }
     }
+
+    /// ## Get Transactions Root SHA-256
+    ///
+    /// Get the SHA-256 merkle root hash of the transactions in this block.
+    pub fn get_transactions_root_sha256(&self) -> Result<AlgorandHash> {
+        match &self.block_header.transactions_root_sha256 {
+            Some(root) => Ok(*root),
+            None => Err("No SHA-256 tx root in block header!".into()),
+        }
+    }
+
+    fn get_transactions_merkle_tree(
+        &self,
+        hash_type: AlgorandMerkleHashType,
+    ) -> Result<AlgorandTransactionsMerkleTree> {
+        match &self.payset {
+            Some(payset) => {
+                AlgorandTransactionsMerkleTree::new(hash_type, &self.get_transactions()?, payset)
+            },
+            None => Err(
+                "Cannot build transactions Merkle tree of a block not decoded from msgpack!".into(),
+            ),
+        }
+    }
+
+    fn check_transactions_root(
+        &self,
+        hash_type: AlgorandMerkleHashType,
+        expected_root: Option<AlgorandHash>,
+    ) -> Result<AlgorandHash> {
+        let root = self.get_transactions_merkle_tree(hash_type)?.root()?;
+        let expected_root = expected_root.unwrap_or_default();
+        if root == expected_root {
+            Ok(root)
+        } else {
+            Err(format!(
+                "Computed {hash_type} transactions root {root} does not match {expected_root} in block header!"
+            )
+            .into())
+        }
+    }
+
+    /// ## Compute Transactions Root
+    ///
+    /// Compute the SHA-512/256 Merkle root of the block's payset, failing if it does not match
+    /// the transactions root in the block header. The payset is only available on blocks decoded
+    /// from msgpack.
+    pub fn compute_transactions_root(&self) -> Result<AlgorandHash> {
+        self.check_transactions_root(
+            AlgorandMerkleHashType::Sha512_256,
+            self.block_header.transactions_root,
+        )
+    }
+
+    /// ## Compute Transactions Root SHA-256
+    ///
+    /// Compute the SHA-256 Merkle root of the block's payset, failing if it does not match the
+    /// SHA-256 transactions root in the block header.
+    pub fn compute_transactions_root_sha256(&self) -> Result<AlgorandHash> {
+        self.check_transactions_root(
+            AlgorandMerkleHashType::Sha256,
+            self.block_header.transactions_root_sha256,
+        )
+    }
+
+    fn prove_transaction_with_hash_type(
+        &self,
+        index: usize,
+        hash_type: AlgorandMerkleHashType,
+    ) -> Result<AlgorandTransactionProof> {
+        let tree = self.get_transactions_merkle_tree(hash_type)?;
+        Ok(AlgorandTransactionProof {
+            index: index as u64,
+            tree_depth: tree.depth() as u64,
+            stib_hash: tree.stib_hash(index)?,
+            hash_type: tree.hash_type().to_string(),
+            proof: tree.prove(index)?,
+        })
+    }
+
+    /// ## Prove Transaction
+    ///
+    /// Generate a proof that the transaction at the given index is included in the block's
+    /// transactions root, in the same form algod serves them.
+    pub fn prove_transaction(&self, index: usize) -> Result<AlgorandTransactionProof> {
+        self.prove_transaction_with_hash_type(index, AlgorandMerkleHashType::Sha512_256)
+    }
+
+    /// ## Prove Transaction SHA-256
+    ///
+    /// Generate a proof that the transaction at the given index is included in the block's
+    /// SHA-256 transactions root, which is far cheaper to verify on EVM chains.
+    pub fn prove_transaction_sha256(&self, index: usize) -> Result<AlgorandTransactionProof> {
+        self.prove_transaction_with_hash_type(index, AlgorandMerkleHashType::Sha256)
+    }
 }
 
 impl std::str::FromStr for AlgorandBlock {
@@ -147,15 +295,21 @@ impl Display for AlgorandBlock {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod test {
     use std::str::FromStr;
 
     use super::*;
-    use crate::algorand_blocks::test_utils::{
-        get_all_sample_blocks,
-        get_sample_block_json_str_n,
-        get_sample_block_n,
-        get_sample_block_with_state_proof_tx_json_string,
+    use crate::{
+        algorand_blocks::test_utils::{
+            get_all_sample_blocks,
+            get_sample_block_json_str_n,
+            get_sample_block_msg_pack_bytes,
+            get_sample_block_n,
+            get_sample_block_with_payset_n,
+            get_sample_block_with_state_proof_tx_json_string,
+        },
+        algorand_transactions::transaction_type::AlgorandTransactionType,
     };
 
     #[test]
@@ -284,4 +438,149 @@ mod test {
         let result = AlgorandBlock::from_str(&json_string);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn should_decode_block_from_msg_pack_bytes() {
+        let bytes = get_sample_block_msg_pack_bytes();
+        let result = AlgorandBlock::from_msg_pack_bytes(&bytes).unwrap();
+        let expected_block = get_sample_block_n(17);
+        assert_eq!(result.block_header, expected_block.block_header);
+        assert_eq!(result.hash().unwrap(), expected_block.hash().unwrap());
+        assert_eq!(result.payset.unwrap().len(), 8);
+    }
+
+    #[test]
+    fn should_get_id_of_key_reg_tx_from_block() {
+        let tx = get_sample_block_n(4)
+            .get_transactions()
+            .unwrap()
+            .iter()
+            .find(|tx| tx.txn_type == Some(AlgorandTransactionType::KeyRegistration))
+            .cloned()
+            .unwrap();
+        assert_eq!(tx.vote_key_dilution, Some(10000));
+        assert_eq!(
+            tx.to_id().unwrap(),
+            "J7PM3VVIO3QI4NAAV5RKWVHSR6TCHQMIWA3CL37IKKOT5HXPEI3A"
+        );
+    }
+
+    #[test]
+    fn should_recover_txs_from_msg_pack_block() {
+        let bytes = get_sample_block_msg_pack_bytes();
+        let result = AlgorandBlock::from_msg_pack_bytes(&bytes)
+            .unwrap()
+            .get_transactions()
+            .unwrap();
+        let expected_result = get_sample_block_n(17).get_transactions().unwrap();
+        assert_eq!(result.len(), expected_result.len());
+        result
+            .iter()
+            .zip(expected_result.iter())
+            .for_each(|(tx, expected_tx)| {
+                assert_eq!(tx.to_id().unwrap(), expected_tx.to_id().unwrap());
+                assert_eq!(tx.genesis_id, Some("mainnet-v1.0".to_string()));
+                assert_eq!(tx.genesis_hash, expected_tx.genesis_hash);
+                assert_eq!(tx.signature, expected_tx.signature);
+            });
+    }
+
+    #[test]
+    fn should_retain_apply_data_and_signatures_of_msg_pack_block_txs() {
+        let bytes = get_sample_block_msg_pack_bytes();
+        let block = AlgorandBlock::from_msg_pack_bytes(&bytes).unwrap();
+        let payset = block.payset.clone().unwrap();
+        assert_eq!(payset[0].multisig.as_ref().unwrap().threshold, 2);
+        assert!(payset[1].signature.is_some());
+        let tx = block.get_transaction_at_index(2).unwrap();
+        let inner_txs = tx
+            .apply_data
+            .as_ref()
+            .and_then(|apply_data| apply_data.eval_delta.as_ref())
+            .and_then(|eval_delta| eval_delta.inner_txs.as_ref())
+            .unwrap();
+        assert_eq!(inner_txs.len(), 1);
+        let inner_tx = tx.inner_txs.as_ref().unwrap()[0].clone();
+        assert_eq!(inner_tx.asset_amount, Some(304_500_000_000));
+        assert_eq!(inner_tx.parent_tx_id, Some(tx.to_raw_tx_id().unwrap()));
+        assert_eq!(inner_tx.to_id().unwrap(), tx.to_id().unwrap());
+    }
+
+    #[test]
+    fn should_compute_transactions_root_of_msg_pack_block() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let result = block.compute_transactions_root().unwrap();
+        let expected_result =
+            AlgorandHash::from_str("nh1Nl24nAHcq/6PWWV6k3PXCsXaxP1xRwY9T79FfQrg=").unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_compute_sha256_transactions_root_of_msg_pack_block() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let result = block.compute_transactions_root_sha256().unwrap();
+        let expected_result =
+            AlgorandHash::from_str("G5hB/9p1t4s/WiJNqPPA5G09F8sv4kRBQLgjS2ILMYY=").unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_compute_transactions_roots_of_block_with_non_power_of_two_payset() {
+        let block = get_sample_block_with_payset_n(11);
+        assert_eq!(block.get_transactions().unwrap().len(), 28);
+        let result = block.compute_transactions_root_sha256().unwrap();
+        let expected_result =
+            AlgorandHash::from_str("s+5r7jLIWC17xlEXYBDe3l4Efv2gdxv0O5zrDV69EEg=").unwrap();
+        assert_eq!(result, expected_result);
+        let result = block.compute_transactions_root().unwrap();
+        let expected_result =
+            AlgorandHash::from_str("e2Iw9qXS9lSdu0c7bXuhRkfDQjviMy2SvZMEeIDq7a8=").unwrap();
+        assert_eq!(result, expected_result);
+        let proof = block.prove_transaction_sha256(27).unwrap();
+        assert_eq!(proof.tree_depth, 5);
+        assert!(proof.validate(&block).is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_compute_transactions_root_if_payset_does_not_match() {
+        let mut block =
+            AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        block.payset.as_mut().unwrap()[1].signature = None;
+        let result = block.compute_transactions_root();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_to_compute_transactions_root_of_json_block() {
+        let block = get_sample_block_n(17);
+        let expected_error =
+            "Cannot build transactions Merkle tree of a block not decoded from msgpack!";
+        match block.compute_transactions_root() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_prove_and_validate_every_transaction_in_block() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        (0..8).for_each(|i| {
+            let proof = block.prove_transaction(i).unwrap();
+            assert_eq!(proof.tree_depth, 3);
+            assert_eq!(proof.hash_type, "sha512_256");
+            proof.validate(&block).unwrap();
+        });
+    }
+
+    #[test]
+    fn should_fail_to_prove_transaction_not_in_block() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let expected_error = "Cannot get tx @ index 8 - only 8 txs in tree!";
+        match block.prove_transaction(8) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
 }
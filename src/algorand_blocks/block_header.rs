@@ -178,10 +178,7 @@ impl AlgorandBlockHeader {
             },
             compact_cert_voters_total: match &json.compact_certificates {
                 None => None,
-                Some(cert) => match cert.compact_cert_voters_total {
-                    None => None,
-                    Some(algos) => Some(MicroAlgos::from_algos(algos)?),
-                },
+                Some(cert) => cert.compact_cert_voters_total.map(MicroAlgos::new),
             },
             compact_cert_next_round: match &json.compact_certificates {
                 Some(certs) => certs.compact_cert_next_round,
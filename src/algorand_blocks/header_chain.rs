@@ -0,0 +1,252 @@
+use derive_more::Deref;
+
+use crate::{
+    algorand_blocks::block_header::AlgorandBlockHeader,
+    algorand_errors::AlgorandError,
+    algorand_types::Result,
+};
+
+/// ## Algorand Header Chain
+///
+/// A sequence of consecutive block headers, each of which is checked to follow on from the one
+/// before it: its previous block hash must be the hash of the prior header, its round must be
+/// the next one, its genesis hash & ID must be the same, its transactions counter must not have
+/// decreased, and its protocol may only change at the round the prior header scheduled the
+/// switch to that protocol for.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deref)]
+pub struct AlgorandHeaderChain(Vec<AlgorandBlockHeader>);
+
+impl AlgorandHeaderChain {
+    /// ## New
+    ///
+    /// Create a chain from the given headers, failing at the first which does not follow on from
+    /// the one before it.
+    pub fn new(headers: Vec<AlgorandBlockHeader>) -> Result<Self> {
+        match Self::find_first_inconsistency(&headers) {
+            Some((_, error)) => Err(error),
+            None => Ok(Self(headers)),
+        }
+    }
+
+    /// ## Find First Inconsistency
+    ///
+    /// Get the index of the first header which does not follow on from the one before it, along
+    /// with the reason why, if there is such a header.
+    pub fn find_first_inconsistency(
+        headers: &[AlgorandBlockHeader],
+    ) -> Option<(usize, AlgorandError)> {
+        headers.windows(2).enumerate().find_map(|(i, pair)| {
+            match Self::check_link(&pair[0], &pair[1]) {
+                Ok(()) => None,
+                Err(error) => Some((i + 1, error)),
+            }
+        })
+    }
+
+    fn check_link(previous: &AlgorandBlockHeader, header: &AlgorandBlockHeader) -> Result<()> {
+        let round = header.round();
+        if previous.round().checked_add(1) != Some(round) {
+            return Err(format!(
+                "Header for round {round} does not follow header for round {}!",
+                previous.round()
+            )
+            .into());
+        };
+        let previous_hash = previous.hash()?;
+        if header.previous_block_hash != Some(previous_hash) {
+            return Err(format!(
+                "Header for round {round} does not commit to previous header hash {previous_hash}!"
+            )
+            .into());
+        };
+        if header.genesis_hash != previous.genesis_hash || header.genesis_id != previous.genesis_id
+        {
+            return Err(format!("Header for round {round} has a different genesis!").into());
+        };
+        if header.transactions_counter < previous.transactions_counter {
+            return Err(format!(
+                "Header for round {round} has transactions counter {}, lower than {}!",
+                header.transactions_counter, previous.transactions_counter
+            )
+            .into());
+        };
+        let scheduled_protocol = previous
+            .next_protocol
+            .as_ref()
+            .filter(|protocol| !protocol.is_empty());
+        let scheduled_switch_round = previous
+            .next_protocol_switch_on
+            .filter(|switch_round| *switch_round != 0);
+        match (scheduled_protocol, scheduled_switch_round) {
+            (Some(protocol), Some(switch_round)) if switch_round == round => {
+                if header.current_protocol.as_ref() == Some(protocol) {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "Header for round {round} did not switch to protocol {protocol} as scheduled!"
+                    )
+                    .into())
+                }
+            },
+            _ if header.current_protocol != previous.current_protocol => Err(format!(
+                "Header for round {round} switches protocol without it being scheduled!"
+            )
+            .into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// ## Tip
+    ///
+    /// Get the latest header in the chain, if any.
+    pub fn tip(&self) -> Option<&AlgorandBlockHeader> {
+        self.0.last()
+    }
+
+    /// ## Append
+    ///
+    /// Add a header to the tip of the chain, failing if it does not follow on from the current
+    /// tip.
+    pub fn append(&mut self, header: AlgorandBlockHeader) -> Result<()> {
+        if let Some(tip) = self.tip() {
+            Self::check_link(tip, &header)?;
+        };
+        self.0.push(header);
+        Ok(())
+    }
+
+    /// ## Truncate
+    ///
+    /// Shorten the chain to the given number of headers, dropping those nearest the tip.
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_blocks::test_utils::get_sample_block_header_n;
+
+    fn assert_first_inconsistency(
+        headers: &[AlgorandBlockHeader],
+        expected_index: usize,
+        expected_error: &str,
+    ) {
+        match AlgorandHeaderChain::find_first_inconsistency(headers) {
+            None => panic!("Should have found an inconsistency!"),
+            Some((index, AlgorandError::Custom(error))) => {
+                assert_eq!(index, expected_index);
+                assert_eq!(error, expected_error);
+            },
+            Some(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    fn get_sample_headers() -> Vec<AlgorandBlockHeader> {
+        (13..16).map(get_sample_block_header_n).collect()
+    }
+
+    fn relink(headers: &mut [AlgorandBlockHeader]) {
+        (1..headers.len()).for_each(|i| {
+            headers[i].previous_block_hash = Some(headers[i - 1].hash().unwrap());
+        });
+    }
+
+    #[test]
+    fn should_create_chain_of_consecutive_headers() {
+        let headers = get_sample_headers();
+        let result = AlgorandHeaderChain::new(headers.clone()).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result.tip(), headers.last());
+    }
+
+    #[test]
+    fn should_append_and_truncate_headers() {
+        let headers = get_sample_headers();
+        let mut chain = AlgorandHeaderChain::default();
+        headers
+            .iter()
+            .for_each(|header| chain.append(header.clone()).unwrap());
+        assert_eq!(chain.len(), 3);
+        chain.truncate(1);
+        assert_eq!(chain.tip(), headers.first());
+        chain.append(headers[1].clone()).unwrap();
+        assert_eq!(chain.tip(), headers.get(1));
+    }
+
+    #[test]
+    fn should_fail_to_append_non_consecutive_header() {
+        let headers = get_sample_headers();
+        let mut chain = AlgorandHeaderChain::new(vec![headers[0].clone()]).unwrap();
+        let expected_error = "Header for round 29285130 does not follow header for round 29285128!";
+        match chain.append(headers[2].clone()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+        assert_eq!(chain.len(), 1);
+    }
+
+    #[test]
+    fn should_find_header_following_max_round() {
+        let mut headers = get_sample_headers();
+        headers[0].round = u64::MAX;
+        let expected_error = format!(
+            "Header for round 29285129 does not follow header for round {}!",
+            u64::MAX
+        );
+        assert_first_inconsistency(&headers, 1, &expected_error);
+    }
+
+    #[test]
+    fn should_find_header_not_committing_to_previous_hash() {
+        let mut headers = get_sample_headers();
+        headers[1].seed = None;
+        let expected_error = format!(
+            "Header for round 29285130 does not commit to previous header hash {}!",
+            headers[1].hash().unwrap()
+        );
+        assert_first_inconsistency(&headers, 2, &expected_error);
+    }
+
+    #[test]
+    fn should_find_header_with_different_genesis() {
+        let mut headers = get_sample_headers();
+        headers[2].genesis_id = Some("testnet-v1.0".to_string());
+        let expected_error = "Header for round 29285130 has a different genesis!";
+        assert_first_inconsistency(&headers, 2, expected_error);
+    }
+
+    #[test]
+    fn should_find_header_with_decreasing_transactions_counter() {
+        let mut headers = get_sample_headers();
+        headers[1].transactions_counter = 0;
+        relink(&mut headers);
+        let expected_error =
+            "Header for round 29285129 has transactions counter 0, lower than 1109587553!";
+        assert_first_inconsistency(&headers, 1, expected_error);
+    }
+
+    #[test]
+    fn should_only_allow_scheduled_protocol_switches() {
+        let next_protocol = "future".to_string();
+        let mut headers = get_sample_headers();
+        headers[2].current_protocol = Some(next_protocol.clone());
+        relink(&mut headers);
+        let expected_error =
+            "Header for round 29285130 switches protocol without it being scheduled!";
+        assert_first_inconsistency(&headers, 2, expected_error);
+
+        headers[1].next_protocol = Some(next_protocol.clone());
+        headers[1].next_protocol_switch_on = Some(29285130);
+        relink(&mut headers);
+        assert!(AlgorandHeaderChain::new(headers.clone()).is_ok());
+
+        headers[2].current_protocol = headers[1].current_protocol.clone();
+        relink(&mut headers);
+        let expected_error =
+            "Header for round 29285130 did not switch to protocol future as scheduled!";
+        assert_first_inconsistency(&headers, 2, expected_error);
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_blocks::block_header::AlgorandBlockHeader,
+    algorand_hash::AlgorandHash,
+    algorand_types::{Bytes, Result},
+    crypto_utils::sha256_hash_bytes,
+    predicates::{is_zero, is_zero_hash_or_none},
+};
+
+const LIGHT_BLOCK_HEADER_PREFIX: [u8; 4] = *b"B256";
+
+/// ## Algorand Light Block Header
+///
+/// The subset of a block header which state proofs commit to, namely its seed, round, genesis
+/// hash and SHA-256 transactions root. A state proof's block headers commitment is a SHA-256
+/// vector commitment to the light block headers of every round in its interval, so along with the
+/// `txn256` root, a transaction's inclusion can be proven using only state proofs.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandLightBlockHeader {
+    #[serde(rename = "0", skip_serializing_if = "is_zero_hash_or_none")]
+    pub seed: Option<AlgorandHash>,
+
+    #[serde(rename = "gh", skip_serializing_if = "is_zero_hash_or_none")]
+    pub genesis_hash: Option<AlgorandHash>,
+
+    #[serde(rename = "r", default, skip_serializing_if = "is_zero")]
+    pub round: u64,
+
+    // NOTE: Unlike the other fields, this is encoded even when zeroed.
+    #[serde(rename = "tc")]
+    pub transactions_root_sha256: Option<AlgorandHash>,
+}
+
+impl From<&AlgorandBlockHeader> for AlgorandLightBlockHeader {
+    fn from(header: &AlgorandBlockHeader) -> Self {
+        Self {
+            seed: header.seed,
+            genesis_hash: header.genesis_hash,
+            round: header.round(),
+            transactions_root_sha256: Some(header.transactions_root_sha256.unwrap_or_default()),
+        }
+    }
+}
+
+impl AlgorandLightBlockHeader {
+    /// ## To Bytes
+    ///
+    /// Convert the light block header to msgpack-ed bytes.
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(rmp_serde::to_vec_named(&self)?)
+    }
+
+    /// ## Hash
+    ///
+    /// The SHA-256 hash of the `B256` prefixed light block header, which is the leaf it's
+    /// committed to in a state proof's block headers commitment.
+    pub fn hash(&self) -> Result<AlgorandHash> {
+        AlgorandHash::from_slice(&sha256_hash_bytes(
+            &[LIGHT_BLOCK_HEADER_PREFIX.to_vec(), self.to_bytes()?].concat(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_blocks::test_utils::get_sample_block_header_n;
+
+    #[test]
+    fn should_get_light_block_header_from_block_header() {
+        let header = get_sample_block_header_n(15);
+        let result = AlgorandLightBlockHeader::from(&header);
+        assert_eq!(result.round, 29285130);
+        assert_eq!(result.seed, header.seed);
+        assert_eq!(result.genesis_hash, header.genesis_hash);
+        assert_eq!(
+            result.transactions_root_sha256,
+            header.transactions_root_sha256
+        );
+    }
+
+    #[test]
+    fn should_encode_light_block_header_to_msg_pack_bytes() {
+        let header = AlgorandLightBlockHeader {
+            seed: Some(AlgorandHash::from_slice(&[1u8; 32]).unwrap()),
+            genesis_hash: Some(AlgorandHash::from_slice(&[2u8; 32]).unwrap()),
+            round: 1,
+            transactions_root_sha256: Some(AlgorandHash::default()),
+        };
+        let result = hex::encode(header.to_bytes().unwrap());
+        let expected_result = format!(
+            "84a130c420{}a26768c420{}a17201a27463c420{}",
+            "01".repeat(32),
+            "02".repeat(32),
+            "00".repeat(32)
+        );
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_omit_zero_fields_other_than_transactions_root() {
+        let header = AlgorandLightBlockHeader::from(&AlgorandBlockHeader::default());
+        let result = hex::encode(header.to_bytes().unwrap());
+        let expected_result = format!("81a27463c420{}", "00".repeat(32));
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serde_light_block_header_to_and_from_msg_pack() {
+        let header = AlgorandLightBlockHeader::from(&get_sample_block_header_n(15));
+        let result: AlgorandLightBlockHeader =
+            rmp_serde::from_slice(&header.to_bytes().unwrap()).unwrap();
+        assert_eq!(result, header);
+    }
+
+    // NOTE: The light block header of mainnet round 29285130, assembled by hand per go-algorand's
+    // codec, ie its four fields in key order with the round as a uint32, & its `B256` prefixed
+    // SHA-256 hash as taken independently of this crate.
+    #[test]
+    fn should_hash_light_block_header_of_mainnet_block() {
+        let header = AlgorandLightBlockHeader::from(&get_sample_block_header_n(15));
+        let expected_bytes = [
+            "84a130c42000fe28159b884f3d8236d6b927c9d52b89b7aec52a26d40a3f8a9f604d0c949b",
+            "a26768c420c061c4d8fc1dbdded2d7604be4568e3f6d041987ac37bde4b620b5ab39248adf",
+            "a172ce01bedb0a",
+            "a27463c420f878059d81d6d2078b4bc7c06cd312fa2ab5a56fd33226a7aecfac066cdb8e40",
+        ]
+        .concat();
+        assert_eq!(hex::encode(header.to_bytes().unwrap()), expected_bytes);
+        let result = hex::encode(header.hash().unwrap().to_bytes());
+        let expected_result = "5be1b62dcb37505fa195df89e4ca06311ace6f7d12f8d6e6d07a573b37fd43ed";
+        assert_eq!(result, expected_result);
+    }
+}
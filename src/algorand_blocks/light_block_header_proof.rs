@@ -0,0 +1,288 @@
+use std::{fmt::Display, str::FromStr};
+
+use base64::{decode as base64_decode, encode as base64_encode};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::{
+    algorand_blocks::light_block_header::AlgorandLightBlockHeader,
+    algorand_errors::AlgorandError,
+    algorand_hash::AlgorandHash,
+    algorand_transactions::transactions_merkle_tree::{
+        AlgorandMerkleHashType,
+        AlgorandTransactionsMerkleTree,
+    },
+    algorand_types::{Byte, Bytes, Result},
+};
+
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AlgorandLightBlockHeaderProofJson {
+    index: u64,
+
+    proof: String,
+
+    #[serde(rename = "treedepth")]
+    tree_depth: u64,
+}
+
+impl Display for AlgorandLightBlockHeaderProofJson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", json!(self))
+    }
+}
+
+impl FromStr for AlgorandLightBlockHeaderProofJson {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+/// ## Algorand Light Block Header Proof
+///
+/// A proof that a light block header is included in a state proof's block headers commitment,
+/// as served by algod's `/v2/blocks/{round}/lightheader/proof` endpoint. The index is that of the
+/// header's round within the state proof's interval, and the proof is the sibling of each node
+/// on the path from the header's leaf to the root of the commitment.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct AlgorandLightBlockHeaderProof {
+    pub index: u64,
+    pub tree_depth: u64,
+    pub proof: Vec<Bytes>,
+}
+
+impl FromStr for AlgorandLightBlockHeaderProof {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_json(&AlgorandLightBlockHeaderProofJson::from_str(s)?)
+    }
+}
+
+impl Display for AlgorandLightBlockHeaderProof {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_json())
+    }
+}
+
+impl AlgorandLightBlockHeaderProof {
+    pub fn from_json(json: &AlgorandLightBlockHeaderProofJson) -> Result<Self> {
+        let bytes = base64_decode(&json.proof)?;
+        if bytes.len() % 32 != 0 {
+            return Err(format!(
+                "Light block header proof of {} bytes is not a whole number of hashes!",
+                bytes.len()
+            )
+            .into());
+        };
+        Ok(Self {
+            index: json.index,
+            tree_depth: json.tree_depth,
+            proof: bytes.chunks(32).map(|hash| hash.to_vec()).collect(),
+        })
+    }
+
+    pub fn to_json(&self) -> AlgorandLightBlockHeaderProofJson {
+        AlgorandLightBlockHeaderProofJson {
+            index: self.index,
+            tree_depth: self.tree_depth,
+            proof: base64_encode(self.proof.concat()),
+        }
+    }
+
+    fn to_root_hash(&self, leaf: &[Byte]) -> Result<AlgorandHash> {
+        let tree_depth = self.tree_depth as usize;
+        if self.proof.len() != tree_depth {
+            return Err(format!(
+                "Light block header proof has {} hashes but a tree depth of {tree_depth}!",
+                self.proof.len()
+            )
+            .into());
+        };
+        if tree_depth >= u64::BITS as usize || self.index >> tree_depth != 0 {
+            return Err(format!(
+                "Light block header index {} is out of range for a tree depth of {tree_depth}!",
+                self.index
+            )
+            .into());
+        };
+        let hash_type = AlgorandMerkleHashType::Sha256;
+        let position =
+            AlgorandTransactionsMerkleTree::reverse_bits(self.index as usize, tree_depth);
+        AlgorandHash::from_slice(&self.proof.iter().enumerate().fold(
+            leaf.to_vec(),
+            |hash, (depth, sibling)| {
+                if (position >> depth) & 1 == 0 {
+                    AlgorandTransactionsMerkleTree::hash_node(hash_type, &hash, sibling)
+                } else {
+                    AlgorandTransactionsMerkleTree::hash_node(hash_type, sibling, &hash)
+                }
+            },
+        ))
+    }
+
+    /// ## Validate
+    ///
+    /// Validate the proof of the given light block header against a state proof's block headers
+    /// commitment.
+    pub fn validate(
+        &self,
+        header: &AlgorandLightBlockHeader,
+        block_headers_commitment: &AlgorandHash,
+    ) -> Result<()> {
+        if &self.to_root_hash(&header.hash()?.to_bytes())? == block_headers_commitment {
+            Ok(())
+        } else {
+            Err("Invalid light block header proof!".into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_blocks::{
+            block::AlgorandBlock,
+            test_utils::{get_sample_block_header_n, get_sample_block_with_payset_n},
+        },
+        algorand_transactions::algorand_transaction_proof::AlgorandTransactionProof,
+    };
+
+    // NOTE: A state proof's block headers commitment is a SHA-256 vector commitment of the same
+    // shape as a block's `txn256` root, so proofs are checked against that of mainnet block
+    // 23595044, whose 28 txs are padded to a tree depth of 5.
+    fn get_sample_block() -> AlgorandBlock {
+        get_sample_block_with_payset_n(11)
+    }
+
+    fn get_sample_proof(block: &AlgorandBlock, index: usize) -> AlgorandLightBlockHeaderProof {
+        let AlgorandTransactionProof {
+            index,
+            tree_depth,
+            proof,
+            ..
+        } = block.prove_transaction_sha256(index).unwrap();
+        AlgorandLightBlockHeaderProof {
+            index,
+            tree_depth,
+            proof,
+        }
+    }
+
+    fn get_sample_leaf(block: &AlgorandBlock, index: usize) -> Bytes {
+        let tx = block.get_transaction_at_index(index).unwrap();
+        let stib_hash = block.prove_transaction_sha256(index).unwrap().stib_hash;
+        AlgorandTransactionsMerkleTree::hash_leaf(
+            AlgorandMerkleHashType::Sha256,
+            &tx.to_raw_tx_id_sha256().unwrap().to_bytes(),
+            &stib_hash,
+        )
+    }
+
+    fn get_sample_light_block_header() -> AlgorandLightBlockHeader {
+        AlgorandLightBlockHeader::from(&get_sample_block_header_n(11))
+    }
+
+    #[test]
+    fn should_serde_proof_to_and_from_str() {
+        let s = json!({
+            "index": 2,
+            "proof": base64_encode([[1 as Byte; 32], [2; 32]].concat()),
+            "treedepth": 2,
+        })
+        .to_string();
+        let result = AlgorandLightBlockHeaderProof::from_str(&s).unwrap();
+        assert_eq!(result.index, 2);
+        assert_eq!(result.tree_depth, 2);
+        assert_eq!(result.proof, vec![vec![1; 32], vec![2; 32]]);
+        assert_eq!(
+            AlgorandLightBlockHeaderProof::from_str(&result.to_string()).unwrap(),
+            result
+        );
+    }
+
+    #[test]
+    fn should_fail_to_get_proof_from_str_with_partial_hash() {
+        let s = json!({ "index": 0, "proof": base64_encode([0; 33]), "treedepth": 1 }).to_string();
+        let expected_error =
+            "Light block header proof of 33 bytes is not a whole number of hashes!";
+        match AlgorandLightBlockHeaderProof::from_str(&s) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fold_proof_of_every_leaf_to_mainnet_vector_commitment() {
+        let block = get_sample_block();
+        let expected_result = block.get_transactions_root_sha256().unwrap();
+        assert_eq!(
+            expected_result,
+            AlgorandHash::from_str("s+5r7jLIWC17xlEXYBDe3l4Efv2gdxv0O5zrDV69EEg=").unwrap()
+        );
+        (0..28).for_each(|i| {
+            let proof = get_sample_proof(&block, i);
+            assert_eq!(proof.tree_depth, 5);
+            let result = proof.to_root_hash(&get_sample_leaf(&block, i)).unwrap();
+            assert_eq!(result, expected_result);
+        });
+    }
+
+    #[test]
+    fn should_validate_proof_of_single_light_block_header() {
+        let header = get_sample_light_block_header();
+        let commitment = header.hash().unwrap();
+        let proof = AlgorandLightBlockHeaderProof::default();
+        assert!(proof.validate(&header, &commitment).is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_validate_proof_of_other_leaf() {
+        let block = get_sample_block();
+        let proof = get_sample_proof(&block, 1);
+        let expected_error = "Invalid light block header proof!";
+        match proof.validate(
+            &get_sample_light_block_header(),
+            &block.get_transactions_root_sha256().unwrap(),
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_validate_proof_with_index_out_of_range() {
+        let block = get_sample_block();
+        let mut proof = get_sample_proof(&block, 1);
+        proof.index = 32;
+        let expected_error = "Light block header index 32 is out of range for a tree depth of 5!";
+        match proof.validate(
+            &get_sample_light_block_header(),
+            &block.get_transactions_root_sha256().unwrap(),
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_validate_proof_with_wrong_number_of_hashes() {
+        let block = get_sample_block();
+        let mut proof = get_sample_proof(&block, 1);
+        proof.proof.pop();
+        let expected_error = "Light block header proof has 4 hashes but a tree depth of 5!";
+        match proof.validate(
+            &get_sample_light_block_header(),
+            &block.get_transactions_root_sha256().unwrap(),
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
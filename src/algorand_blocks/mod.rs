@@ -2,8 +2,12 @@ pub(crate) mod block;
 pub(crate) mod block_header;
 pub(crate) mod block_header_json;
 pub(crate) mod block_json;
+pub(crate) mod header_chain;
+pub(crate) mod light_block_header;
+pub(crate) mod light_block_header_proof;
 pub(crate) mod participation_updates;
 pub(crate) mod rewards_state;
+pub(crate) mod signed_transaction_in_block;
 pub(crate) mod state_proof_tracking;
 pub(crate) mod test_utils;
 pub(crate) mod upgrade_state;
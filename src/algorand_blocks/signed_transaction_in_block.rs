@@ -0,0 +1,154 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_applications::algorand_eval_delta::AlgorandEvalDelta,
+    algorand_hash::AlgorandHash,
+    algorand_logic_sig::AlgorandLogicSig,
+    algorand_multisig::AlgorandMultisig,
+    algorand_signature::AlgorandSignature,
+    algorand_transactions::{apply_data::AlgorandApplyData, transaction::AlgorandTransaction},
+    algorand_types::Result,
+    predicates::{is_false, is_zero_option},
+};
+
+/// ## Algorand Signed Transaction In Block
+///
+/// A signed transaction along with its apply data, as it appears in the payset of a msgpack-ed
+/// block. The genesis ID & hash are stripped from the transaction, with flags recording whether
+/// they are to be restored from the block header. The same form, sans flags, is used for the
+/// inner transactions of an application call's eval delta.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandSignedTransactionInBlock {
+    #[serde(rename = "aca", skip_serializing_if = "is_zero_option")]
+    pub asset_closing_amount: Option<u64>,
+
+    #[serde(rename = "apid", skip_serializing_if = "is_zero_option")]
+    pub application_id: Option<u64>,
+
+    #[serde(rename = "ca", skip_serializing_if = "is_zero_option")]
+    pub closing_amount: Option<u64>,
+
+    #[serde(rename = "caid", skip_serializing_if = "is_zero_option")]
+    pub config_asset: Option<u64>,
+
+    #[serde(rename = "dt")]
+    pub eval_delta: Option<AlgorandEvalDelta>,
+
+    #[serde(rename = "hgh", skip_serializing_if = "is_false")]
+    pub has_genesis_hash: Option<bool>,
+
+    #[serde(rename = "hgi", skip_serializing_if = "is_false")]
+    pub has_genesis_id: Option<bool>,
+
+    #[serde(rename = "lsig")]
+    pub logic_sig: Option<AlgorandLogicSig>,
+
+    #[serde(rename = "msig")]
+    pub multisig: Option<AlgorandMultisig>,
+
+    #[serde(rename = "rc", skip_serializing_if = "is_zero_option")]
+    pub close_rewards: Option<u64>,
+
+    #[serde(rename = "rr", skip_serializing_if = "is_zero_option")]
+    pub receiver_rewards: Option<u64>,
+
+    #[serde(rename = "rs", skip_serializing_if = "is_zero_option")]
+    pub sender_rewards: Option<u64>,
+
+    #[serde(rename = "sgnr")]
+    pub signer: Option<AlgorandAddress>,
+
+    #[serde(rename = "sig")]
+    pub signature: Option<AlgorandSignature>,
+
+    #[serde(rename = "txn")]
+    pub transaction: AlgorandTransaction,
+}
+
+impl AlgorandSignedTransactionInBlock {
+    /// ## Apply Data
+    ///
+    /// Get the apply data recorded alongside the transaction.
+    pub fn apply_data(&self) -> AlgorandApplyData {
+        AlgorandApplyData {
+            asset_closing_amount: self.asset_closing_amount,
+            application_id: self.application_id,
+            closing_amount: self.closing_amount,
+            config_asset: self.config_asset,
+            eval_delta: self.eval_delta.clone(),
+            close_rewards: self.close_rewards,
+            receiver_rewards: self.receiver_rewards,
+            sender_rewards: self.sender_rewards,
+        }
+    }
+
+    /// ## To Transaction
+    ///
+    /// Recover the transaction, restoring its genesis ID & hash from those given where the block
+    /// stripped them, and retaining its signature, apply data and any inner transactions. Every
+    /// protocol since the genesis of mainnet requires the genesis hash, in which case the `hgh`
+    /// flag is omitted and the hash is always restored.
+    pub fn to_transaction(
+        &self,
+        genesis_id: Option<&String>,
+        genesis_hash: Option<AlgorandHash>,
+    ) -> Result<AlgorandTransaction> {
+        let mut tx = self.transaction.clone();
+        if self.has_genesis_id == Some(true) {
+            tx.genesis_id = genesis_id.cloned();
+        };
+        if tx.genesis_hash.is_none() {
+            tx.genesis_hash = genesis_hash;
+        };
+        tx.signature = self.signature.clone();
+        tx.close_amount = self.closing_amount;
+        tx.asset_close_amount = self.asset_closing_amount;
+        tx.apply_data = Some(self.apply_data());
+        let inner_txs = self
+            .eval_delta
+            .as_ref()
+            .and_then(|eval_delta| eval_delta.inner_txs.as_ref());
+        if let Some(inner_txs) = inner_txs {
+            let tx_id = tx.to_raw_tx_id()?;
+            tx.inner_txs = Some(
+                inner_txs
+                    .iter()
+                    .map(|inner_tx| {
+                        inner_tx
+                            .to_transaction(None, None)
+                            .map(|inner_tx| inner_tx.assign_parent_id(tx_id))
+                    })
+                    .collect::<Result<Vec<AlgorandTransaction>>>()?,
+            );
+        };
+        Ok(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn should_only_restore_genesis_id_if_flagged() {
+        let genesis_id = "mainnet-v1.0".to_string();
+        let genesis_hash =
+            AlgorandHash::from_str("wGHE2Pwdvd7S12BL5FaOP20EGYesN73ktiC1qzkkit8=").unwrap();
+        let mut stib = AlgorandSignedTransactionInBlock::default();
+        let result = stib
+            .to_transaction(Some(&genesis_id), Some(genesis_hash))
+            .unwrap();
+        assert_eq!(result.genesis_id, None);
+        assert_eq!(result.genesis_hash, Some(genesis_hash));
+        stib.has_genesis_id = Some(true);
+        let result = stib
+            .to_transaction(Some(&genesis_id), Some(genesis_hash))
+            .unwrap();
+        assert_eq!(result.genesis_id, Some(genesis_id));
+    }
+}
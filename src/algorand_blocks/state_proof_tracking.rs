@@ -13,11 +13,12 @@ use crate::{
 pub struct StateProofTracking {
     #[serde(rename = "n")]
     pub next_round: Option<u64>,
-    #[serde(rename(serialize = "t"), skip_serializing_if = "is_zero_option")]
+    #[serde(rename = "t", skip_serializing_if = "is_zero_option")]
     pub online_total_weight: Option<u64>,
     #[serde(
         with = "serde_bytes",
         rename = "v",
+        default,
         skip_serializing_if = "is_empty_vec"
     )]
     pub voters_commitment: Option<Vec<u8>>,
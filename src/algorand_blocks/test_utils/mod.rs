@@ -1,17 +1,31 @@
 #![cfg(test)]
-use std::{fs::read_to_string, str::FromStr};
+use std::{
+    fs::{read, read_to_string},
+    str::FromStr,
+};
 
 use paste::paste;
 
 use crate::{
+    algorand_applications::algorand_eval_delta::{
+        AlgorandByteString,
+        AlgorandEvalDelta,
+        AlgorandStateDelta,
+        AlgorandValueDelta,
+    },
     algorand_blocks::{
         block::AlgorandBlock,
         block_header::AlgorandBlockHeader,
         block_header_json::AlgorandBlockHeaderJson,
         block_json::AlgorandBlockJson,
+        signed_transaction_in_block::AlgorandSignedTransactionInBlock,
     },
     algorand_errors::AlgorandError,
-    algorand_types::Result,
+    algorand_transactions::{
+        transaction::AlgorandTransaction,
+        transaction_type::AlgorandTransactionType,
+    },
+    algorand_types::{Bytes, Result},
 };
 
 macro_rules! write_paths_and_getter_fxn {
@@ -60,6 +74,19 @@ pub fn get_sample_block_with_state_proof_tx_json_string() -> String {
     read_to_string(get_path_n(12).unwrap()).unwrap()
 }
 
+/// The state proof tx of block 23595666 (sample block 12), which attests to rounds 23595265 to
+/// 23595520.
+pub fn get_sample_state_proof_tx() -> AlgorandTransaction {
+    AlgorandBlock::from_str(&get_sample_block_with_state_proof_tx_json_string())
+        .unwrap()
+        .get_transactions()
+        .unwrap()
+        .iter()
+        .find(|tx| tx.txn_type == Some(AlgorandTransactionType::StateProof))
+        .cloned()
+        .unwrap()
+}
+
 pub fn get_sample_block_json_str_n(n: usize) -> String {
     read_to_string(get_path_n(n).unwrap()).unwrap()
 }
@@ -80,6 +107,60 @@ pub fn get_sample_block_n(n: usize) -> AlgorandBlock {
     AlgorandBlock::from_str(&get_sample_block_json_str_n(n)).unwrap()
 }
 
+/// Block 29620992 (sample block 17) in the msgpack-ed form served by algod, built from the txs in
+/// its JSON form, with a stub certificate.
+pub fn get_sample_block_msg_pack_bytes() -> Bytes {
+    read("src/algorand_blocks/test_utils/block-29620992.msgpack").unwrap()
+}
+
+/// A JSON sample block with its payset rebuilt from its txs, since only msgpack-ed blocks retain
+/// their payset. Only suitable for blocks whose txs carry no apply data other than a global state
+/// delta, such as block 23595044 (sample block 11).
+pub fn get_sample_block_with_payset_n(n: usize) -> AlgorandBlock {
+    let mut block = get_sample_block_n(n);
+    let json = serde_json::from_str::<serde_json::Value>(&get_sample_block_json_str_n(n)).unwrap();
+    let decode_base64 = |value: &serde_json::Value| {
+        AlgorandByteString::new(base64::decode(value.as_str().unwrap()).unwrap())
+    };
+    let payset = block
+        .get_transactions()
+        .unwrap()
+        .iter()
+        .zip(json["transactions"].as_array().unwrap())
+        .map(|(tx, tx_json)| {
+            let global_delta = tx_json["global-state-delta"].as_array().map(|deltas| {
+                deltas
+                    .iter()
+                    .map(|delta| {
+                        let value = &delta["value"];
+                        let value_delta = AlgorandValueDelta {
+                            action: value["action"].as_u64(),
+                            bytes: Some(decode_base64(&value["bytes"])),
+                            uint: value["uint"].as_u64(),
+                        };
+                        (decode_base64(&delta["key"]), value_delta)
+                    })
+                    .collect::<AlgorandStateDelta>()
+            });
+            let mut transaction = tx.clone();
+            transaction.genesis_id = None;
+            transaction.genesis_hash = None;
+            AlgorandSignedTransactionInBlock {
+                has_genesis_id: Some(tx.genesis_id.is_some()),
+                eval_delta: global_delta.map(|global_delta| AlgorandEvalDelta {
+                    global_delta: Some(global_delta),
+                    ..Default::default()
+                }),
+                signature: tx.signature.clone(),
+                transaction,
+                ..Default::default()
+            }
+        })
+        .collect();
+    block.payset = Some(payset);
+    block
+}
+
 mod tests {
     use super::*;
 
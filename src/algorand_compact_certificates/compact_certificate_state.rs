@@ -51,7 +51,7 @@ impl CompactCertificateState {
             compact_cert_voters_total: self
                 .compact_cert_voters_total
                 .as_ref()
-                .map(|micro_algos| micro_algos.to_algos()),
+                .map(|micro_algos| micro_algos.0),
             compact_cert_voters: self
                 .compact_cert_voters
                 .as_ref()
@@ -0,0 +1,220 @@
+/// ## Consensus Params
+///
+/// The protocol limits a transaction must respect in order to be accepted by the network. The
+/// defaults are those of the current consensus protocol.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ConsensusParams {
+    /// The maximum size in bytes of a transaction's note.
+    pub max_txn_note_bytes: usize,
+
+    /// The maximum number of rounds a transaction may be valid for.
+    pub max_txn_life: u64,
+
+    /// The minimum fee in microAlgos a transaction must pay.
+    pub min_txn_fee: u64,
+
+    /// The maximum number of transactions in a group.
+    pub max_tx_group_size: usize,
+
+    /// The maximum number of arguments an application call may pass.
+    pub max_app_args: usize,
+
+    /// The maximum combined size in bytes of an application call's arguments.
+    pub max_app_total_arg_len: usize,
+
+    /// The maximum number of accounts an application call may reference.
+    pub max_app_txn_accounts: usize,
+
+    /// The maximum number of foreign applications an application call may reference.
+    pub max_app_txn_foreign_apps: usize,
+
+    /// The maximum number of foreign assets an application call may reference.
+    pub max_app_txn_foreign_assets: usize,
+
+    /// The maximum number of boxes an application call may reference.
+    pub max_app_box_references: usize,
+
+    /// The maximum combined number of accounts, foreign applications, foreign assets and boxes an
+    /// application call may reference.
+    pub max_app_total_txn_references: usize,
+
+    /// The maximum size in bytes of an asset's name.
+    pub max_asset_name_bytes: usize,
+
+    /// The maximum size in bytes of an asset's unit name.
+    pub max_asset_unit_name_bytes: usize,
+
+    /// The maximum size in bytes of an asset's URL.
+    pub max_asset_url_bytes: usize,
+
+    /// The minimum balance in microAlgos of an account, and the extra minimum balance required
+    /// per asset held.
+    pub min_balance: u64,
+
+    /// The maximum number of assets an account may hold, where zero means unlimited.
+    pub max_assets_per_account: usize,
+
+    /// The maximum number of applications an account may create, where zero means unlimited.
+    pub max_apps_created: usize,
+
+    /// The maximum number of applications an account may opt in to, where zero means unlimited.
+    pub max_apps_opted_in: usize,
+
+    /// The maximum number of extra program pages an application may request.
+    pub max_extra_app_program_pages: usize,
+
+    /// The extra minimum balance required per application created, and per extra program page.
+    pub app_flat_params_min_balance: u64,
+
+    /// The extra minimum balance required per application opted in to.
+    pub app_flat_opt_in_min_balance: u64,
+
+    /// The extra minimum balance required per application state schema entry.
+    pub schema_min_balance_per_entry: u64,
+
+    /// The additional minimum balance required per uint application state schema entry.
+    pub schema_uint_min_balance: u64,
+
+    /// The additional minimum balance required per byte-slice application state schema entry.
+    pub schema_bytes_min_balance: u64,
+
+    /// The extra minimum balance required per box.
+    pub box_flat_min_balance: u64,
+
+    /// The extra minimum balance required per byte of a box's name and value.
+    pub box_byte_min_balance: u64,
+
+    /// The latest version of the AVM, ie of TEAL programs, which the protocol supports.
+    pub avm_version: u64,
+
+    /// The number of rounds each state proof attests to, or zero if state proofs are disabled.
+    pub state_proof_interval: u64,
+
+    /// The number of bits of security a state proof must provide.
+    pub state_proof_strength_target: u64,
+
+    /// The fraction, out of 2^32, of the online stake whose signatures a state proof must prove.
+    pub state_proof_weight_threshold: u64,
+
+    /// The number of rounds back the seed a round's proposer derives its new seed from is.
+    pub seed_lookback: u64,
+
+    /// The number of multiples of the seed lookback after which a seed also commits to an older
+    /// block, so that it can't be biased indefinitely.
+    pub seed_refresh_interval: u64,
+}
+
+impl ConsensusParams {
+    /// ## V30
+    ///
+    /// The params of consensus protocol version 30, which introduced AVM version 5.
+    pub(crate) fn v30() -> Self {
+        Self {
+            max_txn_note_bytes: 1024,
+            max_txn_life: 1000,
+            min_txn_fee: 1000,
+            max_tx_group_size: 16,
+            max_app_args: 16,
+            max_app_total_arg_len: 2048,
+            max_app_txn_accounts: 4,
+            max_app_txn_foreign_apps: 8,
+            max_app_txn_foreign_assets: 8,
+            max_app_box_references: 0,
+            max_app_total_txn_references: 8,
+            max_asset_name_bytes: 32,
+            max_asset_unit_name_bytes: 8,
+            max_asset_url_bytes: 96,
+            min_balance: 100_000,
+            max_assets_per_account: 1000,
+            max_apps_created: 10,
+            max_apps_opted_in: 50,
+            max_extra_app_program_pages: 3,
+            app_flat_params_min_balance: 100_000,
+            app_flat_opt_in_min_balance: 100_000,
+            schema_min_balance_per_entry: 25_000,
+            schema_uint_min_balance: 3_500,
+            schema_bytes_min_balance: 25_000,
+            box_flat_min_balance: 0,
+            box_byte_min_balance: 0,
+            avm_version: 5,
+            state_proof_interval: 0,
+            state_proof_strength_target: 0,
+            state_proof_weight_threshold: 0,
+            seed_lookback: 2,
+            seed_refresh_interval: 80,
+        }
+    }
+
+    /// ## V31
+    ///
+    /// The params of consensus protocol version 31, which introduced AVM version 6.
+    pub(crate) fn v31() -> Self {
+        Self {
+            avm_version: 6,
+            ..Self::v30()
+        }
+    }
+
+    /// ## V32
+    ///
+    /// The params of consensus protocol version 32, which removed the limits on the number of
+    /// assets and applications an account may hold, create or opt in to.
+    pub(crate) fn v32() -> Self {
+        Self {
+            max_assets_per_account: 0,
+            max_apps_created: 0,
+            max_apps_opted_in: 0,
+            ..Self::v31()
+        }
+    }
+
+    /// ## V33
+    ///
+    /// The params of consensus protocol version 33.
+    pub(crate) fn v33() -> Self {
+        Self::v32()
+    }
+
+    /// ## V35
+    ///
+    /// The params of consensus protocol version 35, which introduced state proofs and AVM
+    /// version 7.
+    pub(crate) fn v35() -> Self {
+        Self {
+            avm_version: 7,
+            state_proof_interval: 256,
+            state_proof_strength_target: 256,
+            state_proof_weight_threshold: (1 << 32) * 30 / 100,
+            ..Self::v33()
+        }
+    }
+
+    /// ## V36
+    ///
+    /// The params of consensus protocol version 36, which introduced boxes and AVM version 8.
+    pub(crate) fn v36() -> Self {
+        Self {
+            avm_version: 8,
+            max_app_box_references: 8,
+            box_flat_min_balance: 2_500,
+            box_byte_min_balance: 400,
+            ..Self::v35()
+        }
+    }
+
+    /// ## V38
+    ///
+    /// The params of consensus protocol version 38, which introduced AVM version 9.
+    pub(crate) fn v38() -> Self {
+        Self {
+            avm_version: 9,
+            ..Self::v36()
+        }
+    }
+}
+
+impl Default for ConsensusParams {
+    fn default() -> Self {
+        Self::v38()
+    }
+}
@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use crate::{
+    algorand_blocks::block_header::AlgorandBlockHeader,
+    algorand_consensus::consensus_params::ConsensusParams,
+    algorand_transactions::suggested_params::SuggestedParams,
+    algorand_types::Result,
+};
+
+const PROTOCOL_URL_PREFIX: &str = "https://github.com/algorandfoundation/specs/tree/";
+
+lazy_static! {
+    static ref DEFAULT_CONSENSUS_REGISTRY: ConsensusRegistry = ConsensusRegistry::default();
+}
+
+/// ## Consensus Registry
+///
+/// A registry of consensus params keyed by protocol version, where a version is the URL of the
+/// specification it implements, as found in a block header's current protocol field. The
+/// default registry contains every protocol version known to this crate, and further versions
+/// may be inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsensusRegistry(HashMap<String, ConsensusParams>);
+
+impl ConsensusRegistry {
+    fn to_protocol_url(protocol: &str) -> String {
+        if protocol.starts_with(PROTOCOL_URL_PREFIX) {
+            protocol.to_string()
+        } else {
+            format!("{PROTOCOL_URL_PREFIX}{protocol}")
+        }
+    }
+
+    /// ## Insert
+    ///
+    /// Insert the consensus params for a protocol version, given as either its URL or the commit
+    /// hash it ends with, returning any params previously held for that version.
+    pub fn insert(&mut self, protocol: &str, params: ConsensusParams) -> Option<ConsensusParams> {
+        self.0.insert(Self::to_protocol_url(protocol), params)
+    }
+
+    /// ## Get
+    ///
+    /// Get the consensus params for a protocol version, given as either its URL or the commit
+    /// hash it ends with.
+    pub fn get(&self, protocol: &str) -> Result<ConsensusParams> {
+        match self.0.get(&Self::to_protocol_url(protocol)) {
+            Some(params) => Ok(params.clone()),
+            None => Err(format!("Unknown consensus protocol version: '{protocol}'!").into()),
+        }
+    }
+}
+
+impl Default for ConsensusRegistry {
+    fn default() -> Self {
+        let mut registry = Self(HashMap::new());
+        vec![
+            (
+                "bc36005dbd776e6d1eaf0c560619bb183215645c",
+                ConsensusParams::v30(),
+            ),
+            (
+                "85e6db1fdbdef00aa232c75199e10dc5fe9498f6",
+                ConsensusParams::v31(),
+            ),
+            (
+                "d5ac876d7ede07367dbaa26e149aa42589aac1f7",
+                ConsensusParams::v32(),
+            ),
+            (
+                "830a4e673148498cc7230a0d1ba1ed0a5471acc6",
+                ConsensusParams::v33(),
+            ),
+            (
+                "433d8e9a7274b6fca703d91213e05c7e6a589e69",
+                ConsensusParams::v35(),
+            ),
+            (
+                "44fa607d6051730f5264526bf3c108d51f0eadb6",
+                ConsensusParams::v36(),
+            ),
+            (
+                "abd3d4823c6f77349fc04c3af7b1e99fe4df699f",
+                ConsensusParams::v38(),
+            ),
+        ]
+        .into_iter()
+        .for_each(|(protocol, params)| {
+            registry.insert(protocol, params);
+        });
+        registry
+    }
+}
+
+impl ConsensusParams {
+    /// ## From Protocol
+    ///
+    /// Get the consensus params of a protocol version known to this crate, given as either its
+    /// URL or the commit hash it ends with.
+    pub fn from_protocol(protocol: &str) -> Result<Self> {
+        DEFAULT_CONSENSUS_REGISTRY.get(protocol)
+    }
+}
+
+impl AlgorandBlockHeader {
+    /// ## Consensus Params
+    ///
+    /// Get the consensus params of the protocol this block was produced under.
+    pub fn consensus_params(&self) -> Result<ConsensusParams> {
+        match &self.current_protocol {
+            Some(protocol) => ConsensusParams::from_protocol(protocol),
+            None => Err("No current protocol in block header!".into()),
+        }
+    }
+}
+
+impl SuggestedParams {
+    /// ## Consensus Params
+    ///
+    /// Get the consensus params of the protocol the network suggesting these params runs.
+    pub fn consensus_params(&self) -> Result<ConsensusParams> {
+        match &self.consensus_version {
+            Some(protocol) => ConsensusParams::from_protocol(protocol),
+            None => Err("No consensus version in suggested params!".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{algorand_blocks::test_utils::get_sample_block_n, algorand_errors::AlgorandError};
+
+    #[test]
+    fn should_get_consensus_params_from_protocol_url() {
+        let protocol = "https://github.com/algorandfoundation/specs/tree/44fa607d6051730f5264526bf3c108d51f0eadb6";
+        let result = ConsensusParams::from_protocol(protocol).unwrap();
+        assert_eq!(result.avm_version, 8);
+        assert_eq!(result.max_app_box_references, 8);
+        assert_eq!(result.box_flat_min_balance, 2_500);
+    }
+
+    #[test]
+    fn should_get_consensus_params_from_protocol_hash() {
+        let result =
+            ConsensusParams::from_protocol("85e6db1fdbdef00aa232c75199e10dc5fe9498f6").unwrap();
+        assert_eq!(result, ConsensusParams::v31());
+    }
+
+    #[test]
+    fn should_fail_to_get_unknown_protocol() {
+        let protocol = "deadbeef";
+        let expected_error = format!("Unknown consensus protocol version: '{protocol}'!");
+        match ConsensusParams::from_protocol(protocol) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_insert_custom_protocol() {
+        let mut registry = ConsensusRegistry::default();
+        let params = ConsensusParams {
+            min_txn_fee: 2000,
+            ..ConsensusParams::default()
+        };
+        registry.insert("future", params.clone());
+        let result = registry.get("future").unwrap();
+        assert_eq!(result, params);
+    }
+
+    #[test]
+    fn should_get_consensus_params_from_block_headers() {
+        let block_params = get_sample_block_n(0)
+            .block_header
+            .consensus_params()
+            .unwrap();
+        assert_eq!(block_params.avm_version, 5);
+        assert_eq!(block_params.max_assets_per_account, 1000);
+        let block_params = get_sample_block_n(6)
+            .block_header
+            .consensus_params()
+            .unwrap();
+        assert_eq!(block_params.avm_version, 6);
+        assert_eq!(block_params.max_assets_per_account, 0);
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod consensus_params;
+pub(crate) mod consensus_registry;
@@ -38,5 +38,8 @@ quick_error! {
             from()
             display("✘ Rust message pack error:: {}", err)
         }
+        ValidationErrors(violations: Vec<String>) {
+            display("✘ Validation errors: {}", violations.join(", "))
+        }
     }
 }
@@ -0,0 +1,133 @@
+pub(crate) const FALCON_Q: u32 = 12289;
+
+const FALCON_Q_GENERATOR: u32 = 11;
+
+fn pow_mod_q(base: u32, exponent: u32) -> u32 {
+    (0..u32::BITS).rev().fold(1, |result: u32, i| {
+        let squared = result * result % FALCON_Q;
+        if (exponent >> i) & 1 == 1 {
+            squared * base % FALCON_Q
+        } else {
+            squared
+        }
+    })
+}
+
+fn inverse_mod_q(x: u32) -> u32 {
+    pow_mod_q(x, FALCON_Q - 2)
+}
+
+// NOTE: An in-place iterative radix-2 number theoretic transform, using the given primitive
+// `n`th root of unity, where `n` is the length of the input.
+fn ntt(coefficients: &mut [u32], root: u32) {
+    let n = coefficients.len();
+    let log_n = n.trailing_zeros();
+    (0..n).for_each(|i| {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            coefficients.swap(i, j);
+        }
+    });
+    let mut length = 2;
+    while length <= n {
+        let step_root = pow_mod_q(root, (n / length) as u32);
+        coefficients.chunks_mut(length).for_each(|chunk| {
+            let mut twiddle = 1;
+            (0..length / 2).for_each(|i| {
+                let u = chunk[i];
+                let v = chunk[i + length / 2] * twiddle % FALCON_Q;
+                chunk[i] = (u + v) % FALCON_Q;
+                chunk[i + length / 2] = (u + FALCON_Q - v) % FALCON_Q;
+                twiddle = twiddle * step_root % FALCON_Q;
+            });
+        });
+        length *= 2;
+    }
+}
+
+/// ## Multiply Mod Q
+///
+/// Multiply two polynomials in `Z_q[x] / (x^n + 1)`, the ring Falcon works over, where `n` is a
+/// power of two dividing 1024 and `q` is 12289. Coefficients must be reduced mod `q`.
+///
+/// The product is negacyclic, so both inputs are first weighted by powers of a primitive `2n`th
+/// root of unity, which turns it into a cyclic convolution computable via the NTT.
+pub(crate) fn multiply_mod_q(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let n = a.len();
+    let psi = pow_mod_q(FALCON_Q_GENERATOR, (FALCON_Q - 1) / (2 * n as u32));
+    let omega = psi * psi % FALCON_Q;
+    let mut psi_powers = Vec::with_capacity(n);
+    (0..n).fold(1, |power, _| {
+        psi_powers.push(power);
+        power * psi % FALCON_Q
+    });
+    let weigh = |x: &[u32]| {
+        let mut weighted = x
+            .iter()
+            .zip(psi_powers.iter())
+            .map(|(coefficient, power)| coefficient * power % FALCON_Q)
+            .collect::<Vec<u32>>();
+        ntt(&mut weighted, omega);
+        weighted
+    };
+    let mut product = weigh(a)
+        .iter()
+        .zip(weigh(b).iter())
+        .map(|(x, y)| x * y % FALCON_Q)
+        .collect::<Vec<u32>>();
+    ntt(&mut product, inverse_mod_q(omega));
+    let n_inverse = inverse_mod_q(n as u32);
+    product
+        .iter()
+        .zip(psi_powers.iter())
+        .map(|(coefficient, power)| {
+            coefficient * n_inverse % FALCON_Q * inverse_mod_q(*power) % FALCON_Q
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    use super::*;
+
+    fn multiply_mod_q_schoolbook(a: &[u32], b: &[u32]) -> Vec<u32> {
+        let n = a.len();
+        let mut product = vec![0u64; n];
+        (0..n).for_each(|i| {
+            (0..n).for_each(|j| {
+                let term = a[i] as u64 * b[j] as u64 % FALCON_Q as u64;
+                let k = (i + j) % n;
+                product[k] = if i + j < n {
+                    (product[k] + term) % FALCON_Q as u64
+                } else {
+                    (product[k] + FALCON_Q as u64 - term) % FALCON_Q as u64
+                };
+            })
+        });
+        product.iter().map(|x| *x as u32).collect()
+    }
+
+    #[test]
+    fn should_multiply_mod_q() {
+        let mut rng = StdRng::seed_from_u64(1337);
+        [4, 64, 1024].iter().for_each(|n| {
+            let a = (0..*n)
+                .map(|_| rng.gen_range(0, FALCON_Q))
+                .collect::<Vec<u32>>();
+            let b = (0..*n)
+                .map(|_| rng.gen_range(0, FALCON_Q))
+                .collect::<Vec<u32>>();
+            assert_eq!(multiply_mod_q(&a, &b), multiply_mod_q_schoolbook(&a, &b));
+        });
+    }
+
+    #[test]
+    fn should_wrap_negatively_when_multiplying_mod_q() {
+        let x = [0, 1, 0, 0];
+        let x_cubed = [0, 0, 0, 1];
+        let result = multiply_mod_q(&x, &x_cubed);
+        assert_eq!(result, vec![FALCON_Q - 1, 0, 0, 0]);
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    algorand_falcon::{falcon_polynomial::FALCON_Q, falcon_signature::FALCON_DET1024_N},
+    algorand_types::{Byte, Result},
+};
+
+pub(crate) const FALCON_DET1024_PUBLIC_KEY_SIZE: usize = 1793;
+
+const FALCON_DET1024_PUBLIC_KEY_HEADER: Byte = 0x0a;
+const FALCON_PUBLIC_KEY_BITS: usize = 14;
+
+/// ## Falcon Public Key
+///
+/// A Falcon-1024 public key, namely the polynomial `h`, decoded from the 1793 byte form Algorand
+/// uses. That's a header byte of `0x0a`, followed by each of the 1024 coefficients of `h` as 14
+/// big-endian bits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FalconPublicKey {
+    pub h: Vec<u32>,
+}
+
+impl FalconPublicKey {
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        if bytes.len() != FALCON_DET1024_PUBLIC_KEY_SIZE {
+            return Err(format!(
+                "Falcon public key must be {FALCON_DET1024_PUBLIC_KEY_SIZE} bytes, got {}!",
+                bytes.len()
+            )
+            .into());
+        };
+        if bytes[0] != FALCON_DET1024_PUBLIC_KEY_HEADER {
+            return Err("Not a Falcon-1024 public key!".into());
+        };
+        let mask = (1u32 << FALCON_PUBLIC_KEY_BITS) - 1;
+        let mut h = Vec::with_capacity(FALCON_DET1024_N);
+        let (mut accumulator, mut accumulator_length) = (0u32, 0);
+        for byte in &bytes[1..] {
+            accumulator = (accumulator << 8) | *byte as u32;
+            accumulator_length += 8;
+            if accumulator_length >= FALCON_PUBLIC_KEY_BITS {
+                accumulator_length -= FALCON_PUBLIC_KEY_BITS;
+                let coefficient = (accumulator >> accumulator_length) & mask;
+                if coefficient >= FALCON_Q {
+                    return Err("Falcon public key coefficient is out of range!".into());
+                };
+                h.push(coefficient);
+            };
+        }
+        Ok(Self { h })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_decode_falcon_public_key() {
+        let mut bytes = vec![0u8; FALCON_DET1024_PUBLIC_KEY_SIZE];
+        bytes[0] = FALCON_DET1024_PUBLIC_KEY_HEADER;
+        // NOTE: The first coefficient is 1, and the second is 12288, ie q - 1.
+        bytes[1..5].copy_from_slice(&[0b0000_0000, 0b0000_0111, 0b0000_0000, 0b0000_0000]);
+        let result = FalconPublicKey::from_bytes(&bytes).unwrap();
+        assert_eq!(result.h.len(), FALCON_DET1024_N);
+        assert_eq!(result.h[..3], [1, 12288, 0]);
+    }
+
+    #[test]
+    fn should_fail_to_decode_falcon_public_key_with_coefficient_out_of_range() {
+        let mut bytes = vec![0xff; FALCON_DET1024_PUBLIC_KEY_SIZE];
+        bytes[0] = FALCON_DET1024_PUBLIC_KEY_HEADER;
+        assert!(FalconPublicKey::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_decode_falcon_public_key_of_wrong_length() {
+        assert!(FalconPublicKey::from_bytes(&[FALCON_DET1024_PUBLIC_KEY_HEADER]).is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+use crate::{
+    algorand_falcon::{
+        falcon_polynomial::{multiply_mod_q, FALCON_Q},
+        falcon_public_key::FalconPublicKey,
+    },
+    algorand_types::{Byte, Bytes, Result},
+};
+
+pub(crate) const FALCON_DET1024_N: usize = 1024;
+pub(crate) const FALCON_DET1024_SIG_COMPRESSED_HEADER: Byte = 0xba;
+pub(crate) const FALCON_DET1024_SIG_CT_HEADER: Byte = 0xda;
+pub(crate) const FALCON_DET1024_SIG_CT_SIZE: usize = 1538;
+
+const FALCON_DET1024_LOGN: Byte = 10;
+const FALCON_DET1024_SIG_CT_BITS: usize = 12;
+const FALCON_DET1024_SALT_SIZE: usize = 40;
+const FALCON_DET1024_SALT_REST: [u8; 10] = *b"FALCON_DET";
+const FALCON_DET1024_L2_BOUND: u64 = 70265242;
+const FALCON_MAX_COMPRESSED_COEFFICIENT: i32 = 2047;
+const FALCON_HASH_TO_POINT_BOUND: u32 = 5 * FALCON_Q;
+
+/// ## Falcon Compressed Signature
+///
+/// A deterministic Falcon-1024 signature as Algorand encodes them, namely a header byte, a salt
+/// version byte, and the signature's `s2` polynomial in Falcon's variable length compressed
+/// format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FalconCompressedSignature {
+    pub salt_version: Byte,
+    pub s2: Vec<i16>,
+}
+
+impl FalconCompressedSignature {
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        if bytes.len() < 2 || bytes[0] != FALCON_DET1024_SIG_COMPRESSED_HEADER {
+            return Err("Not a compressed deterministic Falcon-1024 signature!".into());
+        };
+        Ok(Self {
+            salt_version: bytes[1],
+            s2: Self::decode_s2(&bytes[2..])?,
+        })
+    }
+
+    // NOTE: Each coefficient is a sign bit & its seven low bits, followed by its high bits in
+    // unary, terminated by a set bit. Negative zero and any set bits after the last coefficient
+    // are rejected, so that every polynomial has exactly one encoding.
+    fn decode_s2(bytes: &[Byte]) -> Result<Vec<i16>> {
+        let error = "Invalid compressed Falcon signature encoding!";
+        let get_bit = |i: usize| {
+            bytes
+                .get(i / 8)
+                .map(|byte| (byte >> (7 - i % 8)) & 1)
+                .ok_or(error)
+        };
+        let mut position = 0;
+        let mut s2 = Vec::with_capacity(FALCON_DET1024_N);
+        for _ in 0..FALCON_DET1024_N {
+            let is_negative = get_bit(position)? == 1;
+            let mut magnitude = 0i32;
+            for i in 1..8 {
+                magnitude = (magnitude << 1) | get_bit(position + i)? as i32;
+            }
+            position += 8;
+            while get_bit(position)? == 0 {
+                position += 1;
+                magnitude += 128;
+                if magnitude > FALCON_MAX_COMPRESSED_COEFFICIENT {
+                    return Err(error.into());
+                };
+            }
+            position += 1;
+            if is_negative && magnitude == 0 {
+                return Err(error.into());
+            };
+            s2.push(if is_negative { -magnitude } else { magnitude } as i16);
+        }
+        let bytes_used = position.div_ceil(8);
+        if bytes_used != bytes.len() || (position..bytes_used * 8).any(|i| get_bit(i) == Ok(1)) {
+            return Err(error.into());
+        };
+        Ok(s2)
+    }
+
+    /// ## To CT Bytes
+    ///
+    /// Convert the signature to Falcon's fixed length constant time format, which is what a
+    /// state proof's signature commitment commits to.
+    pub fn to_ct_bytes(&self) -> Result<Bytes> {
+        let max_coefficient = (1i16 << (FALCON_DET1024_SIG_CT_BITS - 1)) - 1;
+        if self.s2.iter().any(|x| x.abs() > max_coefficient) {
+            return Err("Falcon signature coefficient too large for CT encoding!".into());
+        };
+        let mask = (1u32 << FALCON_DET1024_SIG_CT_BITS) - 1;
+        let mut bytes = vec![FALCON_DET1024_SIG_CT_HEADER, self.salt_version];
+        let (mut accumulator, mut accumulator_length) = (0u32, 0);
+        self.s2.iter().for_each(|x| {
+            accumulator = (accumulator << FALCON_DET1024_SIG_CT_BITS) | (*x as u32 & mask);
+            accumulator_length += FALCON_DET1024_SIG_CT_BITS;
+            while accumulator_length >= 8 {
+                accumulator_length -= 8;
+                bytes.push((accumulator >> accumulator_length) as Byte);
+            }
+        });
+        if accumulator_length > 0 {
+            bytes.push((accumulator << (8 - accumulator_length)) as Byte);
+        };
+        Ok(bytes)
+    }
+
+    // NOTE: Deterministic Falcon signs with a fixed salt, namely the salt version, the log of
+    // the degree, and the `FALCON_DET` string padded with zeros to forty bytes.
+    fn get_salt(&self) -> Bytes {
+        let mut salt = vec![0u8; FALCON_DET1024_SALT_SIZE];
+        salt[0] = self.salt_version;
+        salt[1] = FALCON_DET1024_LOGN;
+        salt[2..2 + FALCON_DET1024_SALT_REST.len()].copy_from_slice(&FALCON_DET1024_SALT_REST);
+        salt
+    }
+
+    // NOTE: Reads big-endian u16s from SHAKE256 over the salt & message, keeping those less than
+    // 5q, reduced mod q, until there's one for every coefficient of the point.
+    fn hash_to_point(&self, message: &[Byte]) -> Vec<u32> {
+        let mut shake = Shake256::default();
+        shake.update(&self.get_salt());
+        shake.update(message);
+        let mut reader = shake.finalize_xof();
+        let mut point = Vec::with_capacity(FALCON_DET1024_N);
+        while point.len() < FALCON_DET1024_N {
+            let mut bytes = [0u8; 2];
+            reader.read(&mut bytes);
+            let value = u16::from_be_bytes(bytes) as u32;
+            if value < FALCON_HASH_TO_POINT_BOUND {
+                point.push(value % FALCON_Q);
+            };
+        }
+        point
+    }
+
+    /// ## Verify
+    ///
+    /// Verify the signature of the given message under the given public key. That is, that
+    /// `s1 = c - s2 * h`, for `c` the hash of the message to a point, is such that the vector
+    /// `(s1, s2)` is short enough.
+    pub fn verify(&self, public_key: &FalconPublicKey, message: &[Byte]) -> Result<()> {
+        let s2_mod_q = self
+            .s2
+            .iter()
+            .map(|x| (*x as i32).rem_euclid(FALCON_Q as i32) as u32)
+            .collect::<Vec<u32>>();
+        let s2_times_h = multiply_mod_q(&s2_mod_q, &public_key.h);
+        let squared_norm = self
+            .hash_to_point(message)
+            .iter()
+            .zip(s2_times_h.iter())
+            .map(|(c, x)| {
+                let s1 = (c + FALCON_Q - x) % FALCON_Q;
+                let centered_s1 = if s1 > FALCON_Q / 2 {
+                    s1 as i64 - FALCON_Q as i64
+                } else {
+                    s1 as i64
+                };
+                (centered_s1 * centered_s1) as u64
+            })
+            .chain(self.s2.iter().map(|x| (*x as i64 * *x as i64) as u64))
+            .sum::<u64>();
+        if squared_norm <= FALCON_DET1024_L2_BOUND {
+            Ok(())
+        } else {
+            Err("Falcon signature verification failed!".into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_errors::AlgorandError;
+
+    // NOTE: Coefficients as signs & magnitudes, so that negative zero can be encoded too. Their
+    // high bits make the encoding a bit over a whole number of bytes, leaving padding.
+    fn get_sample_coefficients() -> Vec<(bool, u32)> {
+        let mut coefficients = vec![(false, 0); FALCON_DET1024_N];
+        coefficients[..3].copy_from_slice(&[(false, 128), (true, 2047), (true, 261)]);
+        coefficients
+    }
+
+    fn compress(coefficients: &[(bool, u32)]) -> Bytes {
+        let bits = coefficients
+            .iter()
+            .flat_map(|(is_negative, magnitude)| {
+                std::iter::once(*is_negative as u8)
+                    .chain((0..7).rev().map(move |i| ((magnitude >> i) & 1) as u8))
+                    .chain(std::iter::repeat_n(0, (magnitude >> 7) as usize))
+                    .chain(std::iter::once(1))
+            })
+            .collect::<Vec<u8>>();
+        [FALCON_DET1024_SIG_COMPRESSED_HEADER, 0]
+            .into_iter()
+            .chain(bits.chunks(8).map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .fold(0, |byte, (i, bit)| byte | (bit << (7 - i)))
+            }))
+            .collect()
+    }
+
+    // NOTE: Sign extends each twelve bit coefficient of the CT format.
+    fn decode_ct_s2(bytes: &[Byte]) -> Vec<i16> {
+        bytes
+            .chunks(3)
+            .flat_map(|chunk| {
+                let word = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+                [word >> 12, word & 0xfff].map(|x| ((x << 20) as i32 >> 20) as i16)
+            })
+            .collect()
+    }
+
+    fn assert_invalid_encoding(bytes: &[Byte]) {
+        match FalconCompressedSignature::from_bytes(bytes) {
+            Err(AlgorandError::Custom(error)) => {
+                assert_eq!(error, "Invalid compressed Falcon signature encoding!")
+            },
+            _ => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_decode_compressed_signature() {
+        let mut bytes = compress(&get_sample_coefficients());
+        bytes[1] = 7;
+        let result = FalconCompressedSignature::from_bytes(&bytes).unwrap();
+        assert_eq!(result.salt_version, 7);
+        assert_eq!(result.s2.len(), FALCON_DET1024_N);
+        assert_eq!(result.s2[..4], [128, -2047, -261, 0]);
+        assert!(result.s2[3..].iter().all(|x| *x == 0));
+    }
+
+    #[test]
+    fn should_fail_to_decode_signature_with_wrong_header() {
+        let mut bytes = compress(&get_sample_coefficients());
+        bytes[0] = FALCON_DET1024_SIG_CT_HEADER;
+        assert!(FalconCompressedSignature::from_bytes(&bytes).is_err());
+        assert!(FalconCompressedSignature::from_bytes(&bytes[..1]).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_decode_negative_zero() {
+        let mut coefficients = get_sample_coefficients();
+        coefficients[3] = (true, 0);
+        assert_invalid_encoding(&compress(&coefficients));
+    }
+
+    #[test]
+    fn should_fail_to_decode_coefficient_over_max() {
+        let mut coefficients = get_sample_coefficients();
+        coefficients[3] = (false, 2048);
+        assert_invalid_encoding(&compress(&coefficients));
+    }
+
+    #[test]
+    fn should_fail_to_decode_signature_with_trailing_set_bits() {
+        let mut bytes = compress(&get_sample_coefficients());
+        *bytes.last_mut().unwrap() |= 1;
+        assert_invalid_encoding(&bytes);
+    }
+
+    #[test]
+    fn should_fail_to_decode_signature_with_unused_bytes() {
+        let mut bytes = compress(&get_sample_coefficients());
+        bytes.push(0);
+        assert_invalid_encoding(&bytes);
+    }
+
+    #[test]
+    fn should_fail_to_decode_truncated_signature() {
+        let bytes = compress(&get_sample_coefficients());
+        assert_invalid_encoding(&bytes[..bytes.len() - 1]);
+    }
+
+    #[test]
+    fn should_round_trip_signature_via_ct_bytes() {
+        let signature =
+            FalconCompressedSignature::from_bytes(&compress(&get_sample_coefficients())).unwrap();
+        let result = signature.to_ct_bytes().unwrap();
+        assert_eq!(result.len(), FALCON_DET1024_SIG_CT_SIZE);
+        assert_eq!(result[..2], [FALCON_DET1024_SIG_CT_HEADER, 0]);
+        assert_eq!(decode_ct_s2(&result[2..]), signature.s2);
+    }
+
+    #[test]
+    fn should_fail_to_convert_coefficient_too_large_for_ct_bytes() {
+        let mut signature =
+            FalconCompressedSignature::from_bytes(&compress(&get_sample_coefficients())).unwrap();
+        signature.s2[0] = -2048;
+        assert!(signature.to_ct_bytes().is_err());
+    }
+}
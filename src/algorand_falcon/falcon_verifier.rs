@@ -0,0 +1,134 @@
+use crate::{
+    algorand_state_proofs::state_proof::{AlgorandMerkleSignature, MerkleSignatureVerifier},
+    algorand_types::{Byte, Result},
+};
+
+/// ## Algorand Falcon Verifier
+///
+/// Verifies signatures made under Algorand's Merkle signature scheme, wherein a participant
+/// commits to a deterministic Falcon-1024 key for each `key_lifetime` rounds they're online for.
+/// A signature for a round is valid if its key is proven to be the one committed to for that
+/// round, and the Falcon signature itself verifies under that key.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AlgorandFalconVerifier {
+    pub merkle_signature_verifier: MerkleSignatureVerifier,
+}
+
+impl AlgorandFalconVerifier {
+    pub fn new(merkle_signature_verifier: &MerkleSignatureVerifier) -> Self {
+        Self {
+            merkle_signature_verifier: merkle_signature_verifier.clone(),
+        }
+    }
+
+    /// ## Get Key Lifetime
+    ///
+    /// Get the number of rounds each of the participant's ephemeral keys is valid for.
+    pub fn get_key_lifetime(&self) -> u64 {
+        self.merkle_signature_verifier.key_lifetime
+    }
+
+    /// ## Verify
+    ///
+    /// Verify the signature of the given message for the given round.
+    pub fn verify(
+        &self,
+        round: u64,
+        message: &[Byte],
+        signature: &AlgorandMerkleSignature,
+    ) -> Result<()> {
+        self.merkle_signature_verifier
+            .verify_ephemeral_key(round, signature)?;
+        signature
+            .verifying_key
+            .verify_bytes(message, &signature.falcon_signature)
+    }
+}
+
+impl From<&MerkleSignatureVerifier> for AlgorandFalconVerifier {
+    fn from(merkle_signature_verifier: &MerkleSignatureVerifier) -> Self {
+        Self::new(merkle_signature_verifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_blocks::test_utils::get_sample_state_proof_tx,
+        algorand_state_proofs::{
+            state_proof::AlgorandReveal,
+            state_proof_message::StateProofMessage,
+        },
+        algorand_types::Bytes,
+    };
+
+    // NOTE: The reveals of a mainnet state proof, made by go-algorand's prover, whose signatures
+    // are of the hash of the message for the state proof's last attested round.
+    fn get_sample_reveals_and_message() -> (Vec<AlgorandReveal>, StateProofMessage) {
+        let tx = get_sample_state_proof_tx();
+        (
+            tx.state_proof.unwrap().reveals.into_values().collect(),
+            tx.state_proof_message.unwrap(),
+        )
+    }
+
+    fn get_message_hash(message: &StateProofMessage) -> Bytes {
+        message.hash().unwrap().to_bytes()
+    }
+
+    #[test]
+    fn should_verify_merkle_signatures() {
+        let (reveals, message) = get_sample_reveals_and_message();
+        let message_hash = get_message_hash(&message);
+        reveals.iter().for_each(|reveal| {
+            AlgorandFalconVerifier::from(&reveal.participant.verifier)
+                .verify(
+                    message.last_attested_round,
+                    &message_hash,
+                    &reveal.sig_slot.signature,
+                )
+                .unwrap()
+        });
+    }
+
+    #[test]
+    fn should_fail_to_verify_merkle_signature_of_different_message() {
+        let (reveals, message) = get_sample_reveals_and_message();
+        let mut message_hash = get_message_hash(&message);
+        message_hash[0] ^= 1;
+        let reveal = &reveals[0];
+        assert!(AlgorandFalconVerifier::from(&reveal.participant.verifier)
+            .verify(
+                message.last_attested_round,
+                &message_hash,
+                &reveal.sig_slot.signature
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_merkle_signature_for_different_round() {
+        let (reveals, message) = get_sample_reveals_and_message();
+        let reveal = &reveals[0];
+        let verifier = AlgorandFalconVerifier::from(&reveal.participant.verifier);
+        assert!(verifier
+            .verify(
+                message.last_attested_round + verifier.get_key_lifetime(),
+                &get_message_hash(&message),
+                &reveal.sig_slot.signature
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_merkle_signature_under_different_key() {
+        let (reveals, message) = get_sample_reveals_and_message();
+        let mut signature = reveals[0].sig_slot.signature.clone();
+        signature.verifying_key = reveals[1].sig_slot.signature.verifying_key.clone();
+        assert!(signature
+            .verifying_key
+            .verify_bytes(&get_message_hash(&message), &signature.falcon_signature)
+            .is_err());
+    }
+}
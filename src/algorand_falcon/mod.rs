@@ -0,0 +1,4 @@
+pub(crate) mod falcon_polynomial;
+pub(crate) mod falcon_public_key;
+pub(crate) mod falcon_signature;
+pub(crate) mod falcon_verifier;
@@ -79,6 +79,7 @@ impl Display for AlgorandGenesisId {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for AlgorandGenesisId {
     fn default() -> Self {
         Self::Mainnet
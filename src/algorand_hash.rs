@@ -91,6 +91,7 @@ impl AlgorandHash {
     }
 
     #[cfg(test)]
+    #[allow(clippy::wrong_self_convention)]
     fn to_hex(&self) -> String {
         hex::encode(self.0)
     }
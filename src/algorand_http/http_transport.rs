@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use crate::algorand_types::{Byte, Bytes, Result};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AlgorandHttpMethod {
+    Get,
+    Post,
+    Delete,
+}
+
+impl Display for AlgorandHttpMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Get => write!(f, "GET"),
+            Self::Post => write!(f, "POST"),
+            Self::Delete => write!(f, "DELETE"),
+        }
+    }
+}
+
+/// ## Algorand Http Request
+///
+/// A transport-agnostic description of a request one of the API clients wishes to make.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AlgorandHttpRequest {
+    pub method: AlgorandHttpMethod,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<Bytes>,
+}
+
+impl AlgorandHttpRequest {
+    pub fn new(method: AlgorandHttpMethod, url: &str) -> Self {
+        Self {
+            method,
+            url: url.to_string(),
+            headers: vec![],
+            body: None,
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn with_body(mut self, body: &[Byte]) -> Self {
+        self.body = Some(body.to_vec());
+        self
+    }
+
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// ## Algorand Http Response
+///
+/// The status code and raw body a transport received in response to an `AlgorandHttpRequest`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AlgorandHttpResponse {
+    pub status: u16,
+    pub body: Bytes,
+}
+
+impl AlgorandHttpResponse {
+    pub fn new(status: u16, body: &[Byte]) -> Self {
+        Self {
+            status,
+            body: body.to_vec(),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// ## Into Body
+    ///
+    /// Returns the body of a successful response, or an error containing the status code and
+    /// whatever message the server sent back.
+    pub fn into_body(self) -> Result<Bytes> {
+        if self.is_success() {
+            Ok(self.body)
+        } else {
+            Err(format!(
+                "Http request failed with status {}: {}",
+                self.status,
+                String::from_utf8_lossy(&self.body)
+            )
+            .into())
+        }
+    }
+}
+
+/// ## Algorand Http Transport
+///
+/// The pluggable transport used by the API clients in this crate. Implement it for whichever http
+/// client your application already depends on, so that this crate need not pick one for you.
+pub trait AlgorandHttpTransport {
+    fn send(&self, request: &AlgorandHttpRequest) -> Result<AlgorandHttpResponse>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_errors::AlgorandError;
+
+    #[test]
+    fn should_get_header_case_insensitively() {
+        let request = AlgorandHttpRequest::new(AlgorandHttpMethod::Get, "http://localhost")
+            .with_header("X-Indexer-API-Token", "token");
+        let result = request.get_header("x-indexer-api-token");
+        assert_eq!(result, Some("token"));
+    }
+
+    #[test]
+    fn should_get_body_from_successful_response() {
+        let body = b"{}".to_vec();
+        let result = AlgorandHttpResponse::new(200, &body).into_body().unwrap();
+        assert_eq!(result, body);
+    }
+
+    #[test]
+    fn should_fail_to_get_body_from_unsuccessful_response() {
+        let expected_error = "Http request failed with status 404: not found";
+        match AlgorandHttpResponse::new(404, b"not found").into_body() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
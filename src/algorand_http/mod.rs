@@ -0,0 +1,4 @@
+pub(crate) mod http_transport;
+pub(crate) mod std_http_transport;
+pub(crate) mod test_utils;
+pub(crate) mod url_encoding;
@@ -0,0 +1,202 @@
+use std::{
+    io::{Read, Write},
+    net::TcpStream,
+    time::Duration,
+};
+
+use crate::{
+    algorand_http::http_transport::{
+        AlgorandHttpRequest,
+        AlgorandHttpResponse,
+        AlgorandHttpTransport,
+    },
+    algorand_types::{Byte, Bytes, Result},
+};
+
+const HTTP_SCHEME: &str = "http://";
+const DEFAULT_TIMEOUT_SECONDS: u64 = 30;
+
+/// ## Std Http Transport
+///
+/// A minimal, dependency-free `AlgorandHttpTransport` speaking plain HTTP/1.1 over a std
+/// `TcpStream`, one connection per request. It is suited to talking to a local node or daemon
+/// such as kmd. It does not support TLS, so use your own transport for remote endpoints.
+#[derive(Clone, Debug)]
+pub struct StdHttpTransport {
+    timeout: Duration,
+}
+
+impl Default for StdHttpTransport {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
+        }
+    }
+}
+
+impl StdHttpTransport {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout }
+    }
+
+    fn split_url(url: &str) -> Result<(String, String)> {
+        match url.strip_prefix(HTTP_SCHEME) {
+            None => {
+                Err(format!("Only plain `{HTTP_SCHEME}` urls are supported, got: {url}").into())
+            },
+            Some(rest) => match rest.find('/') {
+                Some(index) => Ok((rest[..index].to_string(), rest[index..].to_string())),
+                None => Ok((rest.to_string(), "/".to_string())),
+            },
+        }
+    }
+
+    fn find_header_end(bytes: &[Byte]) -> Option<usize> {
+        bytes.windows(4).position(|window| window == b"\r\n\r\n")
+    }
+
+    fn decode_chunked_body(bytes: &[Byte]) -> Result<Bytes> {
+        let mut body = vec![];
+        let mut remaining = bytes;
+        loop {
+            let line_end = remaining
+                .windows(2)
+                .position(|window| window == b"\r\n")
+                .ok_or("Malformed chunked http response body!")?;
+            let size_str = String::from_utf8_lossy(&remaining[..line_end]);
+            let size_str = size_str.split(';').next().unwrap_or_default().trim();
+            let size = usize::from_str_radix(size_str, 16)
+                .map_err(|_| format!("Malformed http chunk size: '{size_str}'!"))?;
+            remaining = &remaining[line_end + 2..];
+            if size == 0 {
+                return Ok(body);
+            };
+            if remaining.len() < size {
+                return Err("Truncated chunked http response body!".into());
+            };
+            body.extend_from_slice(&remaining[..size]);
+            remaining = remaining.get(size + 2..).unwrap_or_default();
+        }
+    }
+
+    fn parse_response(bytes: &[Byte]) -> Result<AlgorandHttpResponse> {
+        let header_end = Self::find_header_end(bytes).ok_or("Malformed http response!")?;
+        let head = String::from_utf8_lossy(&bytes[..header_end]);
+        let mut lines = head.split("\r\n");
+        let status = lines
+            .next()
+            .and_then(|status_line| status_line.split_whitespace().nth(1))
+            .and_then(|status| status.parse::<u16>().ok())
+            .ok_or("Malformed http response status line!")?;
+        let headers = lines
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+            .collect::<Vec<(String, String)>>();
+        let get_header = |name: &str| {
+            headers
+                .iter()
+                .find(|(header_name, _)| header_name == name)
+                .map(|(_, value)| value.clone())
+        };
+        let raw_body = &bytes[header_end + 4..];
+        let body = if get_header("transfer-encoding")
+            .map(|value| value.eq_ignore_ascii_case("chunked"))
+            .unwrap_or(false)
+        {
+            Self::decode_chunked_body(raw_body)?
+        } else {
+            match get_header("content-length").and_then(|value| value.parse::<usize>().ok()) {
+                Some(length) if length <= raw_body.len() => raw_body[..length].to_vec(),
+                Some(_) => return Err("Truncated http response body!".into()),
+                None => raw_body.to_vec(),
+            }
+        };
+        Ok(AlgorandHttpResponse::new(status, &body))
+    }
+}
+
+impl AlgorandHttpTransport for StdHttpTransport {
+    fn send(&self, request: &AlgorandHttpRequest) -> Result<AlgorandHttpResponse> {
+        let (host, path) = Self::split_url(&request.url)?;
+        let body = request.body.clone().unwrap_or_default();
+        let mut head = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\nContent-Length: {}\r\n",
+            request.method,
+            path,
+            host,
+            body.len()
+        );
+        request
+            .headers
+            .iter()
+            .for_each(|(name, value)| head.push_str(&format!("{name}: {value}\r\n")));
+        head.push_str("\r\n");
+        let mut stream = TcpStream::connect(&host)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(&body)?;
+        let mut response_bytes = vec![];
+        stream.read_to_end(&mut response_bytes)?;
+        Self::parse_response(&response_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_errors::AlgorandError,
+        algorand_http::{
+            http_transport::AlgorandHttpMethod,
+            test_utils::{StubHttpRoute, StubHttpServer},
+        },
+    };
+
+    #[test]
+    fn should_split_url() {
+        let result = StdHttpTransport::split_url("http://localhost:7833/v1/wallets?x=1").unwrap();
+        let expected_result = ("localhost:7833".to_string(), "/v1/wallets?x=1".to_string());
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_split_https_url() {
+        let expected_error = "Only plain `http://` urls are supported, got: https://localhost";
+        match StdHttpTransport::split_url("https://localhost") {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_parse_chunked_response() {
+        let bytes = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\nWiki\r\n5\r\npedia\r\n0\r\n\r\n";
+        let result = StdHttpTransport::parse_response(bytes).unwrap();
+        assert_eq!(result, AlgorandHttpResponse::new(200, b"Wikipedia"));
+    }
+
+    #[test]
+    fn should_parse_response_with_content_length() {
+        let bytes = b"HTTP/1.1 404 Not Found\r\nContent-Length: 2\r\n\r\n{}";
+        let result = StdHttpTransport::parse_response(bytes).unwrap();
+        assert_eq!(result, AlgorandHttpResponse::new(404, b"{}"));
+    }
+
+    #[test]
+    fn should_send_request_to_server() {
+        let server = StubHttpServer::start(vec![StubHttpRoute::new("/v1/echo", 200, "{\"a\":1}")]);
+        let request =
+            AlgorandHttpRequest::new(AlgorandHttpMethod::Post, &format!("{}/v1/echo", server.url))
+                .with_header("X-Test", "yes")
+                .with_body(b"hello");
+        let result = StdHttpTransport::default().send(&request).unwrap();
+        assert_eq!(result, AlgorandHttpResponse::new(200, b"{\"a\":1}"));
+        let received = server.next_request();
+        assert_eq!(received.method, AlgorandHttpMethod::Post);
+        assert_eq!(received.url, "/v1/echo");
+        assert_eq!(received.get_header("x-test"), Some("yes"));
+        assert_eq!(received.body, Some(b"hello".to_vec()));
+    }
+}
@@ -0,0 +1,156 @@
+#![cfg(test)]
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io::{Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::mpsc::{channel, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    algorand_http::http_transport::{
+        AlgorandHttpMethod,
+        AlgorandHttpRequest,
+        AlgorandHttpResponse,
+        AlgorandHttpTransport,
+    },
+    algorand_types::Result,
+};
+
+/// A transport which replays canned responses in order and records every request it was sent.
+#[derive(Default)]
+pub struct StubHttpTransport {
+    pub requests: RefCell<Vec<AlgorandHttpRequest>>,
+    responses: RefCell<VecDeque<AlgorandHttpResponse>>,
+}
+
+impl StubHttpTransport {
+    pub fn new(responses: Vec<AlgorandHttpResponse>) -> Self {
+        Self {
+            requests: RefCell::new(vec![]),
+            responses: RefCell::new(responses.into()),
+        }
+    }
+}
+
+impl AlgorandHttpTransport for StubHttpTransport {
+    fn send(&self, request: &AlgorandHttpRequest) -> Result<AlgorandHttpResponse> {
+        self.requests.borrow_mut().push(request.clone());
+        self.responses
+            .borrow_mut()
+            .pop_front()
+            .ok_or_else(|| "Stub transport has no responses left!".into())
+    }
+}
+
+#[derive(Clone)]
+pub struct StubHttpRoute {
+    path: String,
+    status: u16,
+    body: String,
+}
+
+impl StubHttpRoute {
+    pub fn new(path: &str, status: u16, body: &str) -> Self {
+        Self {
+            status,
+            path: path.to_string(),
+            body: body.to_string(),
+        }
+    }
+}
+
+/// A local http server, listening on a random port, which answers requests from a fixed set of
+/// routes and records every request it receives. The url it records is the request's path.
+pub struct StubHttpServer {
+    pub url: String,
+    receiver: Receiver<AlgorandHttpRequest>,
+}
+
+impl StubHttpServer {
+    pub fn start(routes: Vec<StubHttpRoute>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = channel();
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                Self::handle_connection(stream, &routes, &sender);
+            }
+        });
+        Self { url, receiver }
+    }
+
+    pub fn next_request(&self) -> AlgorandHttpRequest {
+        self.receiver.recv_timeout(Duration::from_secs(5)).unwrap()
+    }
+
+    fn read_request(stream: &mut TcpStream) -> AlgorandHttpRequest {
+        let mut bytes = vec![];
+        let mut buffer = [0u8; 1024];
+        let header_end = loop {
+            let num_bytes = stream.read(&mut buffer).unwrap();
+            bytes.extend_from_slice(&buffer[..num_bytes]);
+            if let Some(index) = bytes.windows(4).position(|window| window == b"\r\n\r\n") {
+                break index;
+            };
+        };
+        let head = String::from_utf8_lossy(&bytes[..header_end]).to_string();
+        let mut lines = head.split("\r\n");
+        let mut request_line = lines.next().unwrap().split_whitespace();
+        let method = match request_line.next().unwrap() {
+            "GET" => AlgorandHttpMethod::Get,
+            "POST" => AlgorandHttpMethod::Post,
+            _ => AlgorandHttpMethod::Delete,
+        };
+        let mut request = AlgorandHttpRequest::new(method, request_line.next().unwrap());
+        lines
+            .filter_map(|line| line.split_once(':'))
+            .for_each(|(name, value)| {
+                request
+                    .headers
+                    .push((name.trim().into(), value.trim().into()))
+            });
+        let content_length = request
+            .get_header("content-length")
+            .map(|value| value.parse::<usize>().unwrap())
+            .unwrap_or_default();
+        let mut body = bytes[header_end + 4..].to_vec();
+        while body.len() < content_length {
+            let num_bytes = stream.read(&mut buffer).unwrap();
+            body.extend_from_slice(&buffer[..num_bytes]);
+        }
+        if content_length > 0 {
+            request.body = Some(body);
+        };
+        request
+    }
+
+    fn handle_connection(
+        mut stream: TcpStream,
+        routes: &[StubHttpRoute],
+        sender: &Sender<AlgorandHttpRequest>,
+    ) {
+        let request = Self::read_request(&mut stream);
+        let path = request
+            .url
+            .split('?')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        let route = routes
+            .iter()
+            .find(|route| route.path == path)
+            .cloned()
+            .unwrap_or_else(|| StubHttpRoute::new(&path, 404, "{\"message\":\"not found\"}"));
+        let response = format!(
+            "HTTP/1.1 {} Stub\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            route.status,
+            route.body.len(),
+            route.body
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+        sender.send(request).unwrap();
+    }
+}
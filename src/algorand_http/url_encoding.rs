@@ -0,0 +1,60 @@
+/// ## Url Encode
+///
+/// Percent-encodes everything in the given string except the RFC 3986 unreserved characters.
+pub(crate) fn url_encode(s: &str) -> String {
+    s.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            },
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// ## To Query String
+///
+/// Url encodes the given key-value pairs into a query string, including the leading `?`. Returns
+/// an empty string if there are no pairs.
+pub(crate) fn to_query_string(pairs: &[(&str, String)]) -> String {
+    if pairs.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "?{}",
+            pairs
+                .iter()
+                .map(|(key, value)| format!("{}={}", url_encode(key), url_encode(value)))
+                .collect::<Vec<String>>()
+                .join("&")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_url_encode_string() {
+        let result = url_encode("aGk+/w== ~x");
+        let expected_result = "aGk%2B%2Fw%3D%3D%20~x";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_create_query_string() {
+        let pairs = vec![
+            ("limit", "10".to_string()),
+            ("note-prefix", "a+b".to_string()),
+        ];
+        let result = to_query_string(&pairs);
+        let expected_result = "?limit=10&note-prefix=a%2Bb";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_create_empty_query_string() {
+        assert_eq!(to_query_string(&[]), "");
+    }
+}
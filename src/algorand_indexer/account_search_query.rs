@@ -0,0 +1,124 @@
+use crate::{algorand_address::AlgorandAddress, algorand_http::url_encoding::to_query_string};
+
+/// ## Account Search Query
+///
+/// A builder for the query parameters of the indexer's `/v2/accounts` endpoint.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AccountSearchQuery {
+    asset_id: Option<u64>,
+    application_id: Option<u64>,
+    auth_address: Option<AlgorandAddress>,
+    currency_greater_than: Option<u64>,
+    currency_less_than: Option<u64>,
+    round: Option<u64>,
+    limit: Option<u64>,
+    pub(crate) next: Option<String>,
+}
+
+impl AccountSearchQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// ## Asset Id
+    ///
+    /// Only return accounts holding this asset.
+    pub fn asset_id(mut self, asset_id: u64) -> Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+
+    /// ## Application Id
+    ///
+    /// Only return accounts opted into this application.
+    pub fn application_id(mut self, application_id: u64) -> Self {
+        self.application_id = Some(application_id);
+        self
+    }
+
+    /// ## Auth Address
+    ///
+    /// Only return accounts which have been rekeyed to this address.
+    pub fn auth_address(mut self, address: &AlgorandAddress) -> Self {
+        self.auth_address = Some(*address);
+        self
+    }
+
+    /// ## Currency Greater Than
+    ///
+    /// Only return accounts holding more than this amount. The amount is in µAlgos, or in base
+    /// units of the asset if an asset id is also specified.
+    pub fn currency_greater_than(mut self, amount: u64) -> Self {
+        self.currency_greater_than = Some(amount);
+        self
+    }
+
+    /// ## Currency Less Than
+    ///
+    /// Only return accounts holding less than this amount. The amount is in µAlgos, or in base
+    /// units of the asset if an asset id is also specified.
+    pub fn currency_less_than(mut self, amount: u64) -> Self {
+        self.currency_less_than = Some(amount);
+        self
+    }
+
+    /// ## Round
+    ///
+    /// Return account state as it was at this round.
+    pub fn round(mut self, round: u64) -> Self {
+        self.round = Some(round);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// ## Next
+    ///
+    /// The `next-token` returned by a previous page of results.
+    pub fn next(mut self, next_token: &str) -> Self {
+        self.next = Some(next_token.to_string());
+        self
+    }
+
+    pub fn to_query_string(&self) -> String {
+        let pairs = [
+            ("asset-id", self.asset_id.map(|x| x.to_string())),
+            ("application-id", self.application_id.map(|x| x.to_string())),
+            ("auth-addr", self.auth_address.map(|x| x.to_string())),
+            (
+                "currency-greater-than",
+                self.currency_greater_than.map(|x| x.to_string()),
+            ),
+            (
+                "currency-less-than",
+                self.currency_less_than.map(|x| x.to_string()),
+            ),
+            ("round", self.round.map(|x| x.to_string())),
+            ("limit", self.limit.map(|x| x.to_string())),
+            ("next", self.next.clone()),
+        ]
+        .into_iter()
+        .filter_map(|(key, maybe_value)| maybe_value.map(|value| (key, value)))
+        .collect::<Vec<(&str, String)>>();
+        to_query_string(&pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_create_account_search_query_string() {
+        let result = AccountSearchQuery::new()
+            .asset_id(27165954)
+            .currency_greater_than(0)
+            .limit(2)
+            .to_query_string();
+        let expected_result = "?asset-id=27165954&currency-greater-than=0&limit=2";
+        assert_eq!(result, expected_result);
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::VecDeque;
+
+use crate::{
+    algorand_accounts::account_json::AlgorandAccountJson,
+    algorand_http::http_transport::{
+        AlgorandHttpMethod,
+        AlgorandHttpRequest,
+        AlgorandHttpTransport,
+    },
+    algorand_indexer::{
+        account_search_query::AccountSearchQuery,
+        indexer_page::IndexerPage,
+        transaction_search_query::TransactionSearchQuery,
+    },
+    algorand_transactions::transaction::AlgorandTransaction,
+    algorand_types::{Byte, Result},
+};
+
+const INDEXER_API_TOKEN_HEADER: &str = "X-Indexer-API-Token";
+
+/// ## Indexer Search Query
+///
+/// Implemented by the query builders of the paginated indexer search endpoints, allowing the
+/// `IndexerClient` to fetch and follow their pages generically.
+pub trait IndexerSearchQuery: Clone {
+    type Item;
+
+    fn path() -> &'static str;
+
+    fn to_query_string(&self) -> String;
+
+    fn set_next_token(&mut self, next_token: &str);
+
+    fn parse_page(bytes: &[Byte]) -> Result<IndexerPage<Self::Item>>;
+}
+
+impl IndexerSearchQuery for TransactionSearchQuery {
+    type Item = AlgorandTransaction;
+
+    fn path() -> &'static str {
+        "/v2/transactions"
+    }
+
+    fn to_query_string(&self) -> String {
+        TransactionSearchQuery::to_query_string(self)
+    }
+
+    fn set_next_token(&mut self, next_token: &str) {
+        self.next = Some(next_token.to_string());
+    }
+
+    fn parse_page(bytes: &[Byte]) -> Result<IndexerPage<Self::Item>> {
+        IndexerPage::from_transactions_json_bytes(bytes)
+    }
+}
+
+impl IndexerSearchQuery for AccountSearchQuery {
+    type Item = AlgorandAccountJson;
+
+    fn path() -> &'static str {
+        "/v2/accounts"
+    }
+
+    fn to_query_string(&self) -> String {
+        AccountSearchQuery::to_query_string(self)
+    }
+
+    fn set_next_token(&mut self, next_token: &str) {
+        self.next = Some(next_token.to_string());
+    }
+
+    fn parse_page(bytes: &[Byte]) -> Result<IndexerPage<Self::Item>> {
+        IndexerPage::from_accounts_json_bytes(bytes)
+    }
+}
+
+/// ## Indexer Client
+///
+/// A client for the search endpoints of an Algorand indexer, sending its requests via whichever
+/// `AlgorandHttpTransport` it is given.
+pub struct IndexerClient<T: AlgorandHttpTransport> {
+    url: String,
+    transport: T,
+    api_token: Option<String>,
+}
+
+impl<T: AlgorandHttpTransport> IndexerClient<T> {
+    pub fn new(url: &str, transport: T) -> Self {
+        Self {
+            transport,
+            api_token: None,
+            url: url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn with_api_token(mut self, api_token: &str) -> Self {
+        self.api_token = Some(api_token.to_string());
+        self
+    }
+
+    fn search<Q: IndexerSearchQuery>(&self, query: &Q) -> Result<IndexerPage<Q::Item>> {
+        let url = format!("{}{}{}", self.url, Q::path(), query.to_query_string());
+        let request = match &self.api_token {
+            Some(api_token) => AlgorandHttpRequest::new(AlgorandHttpMethod::Get, &url)
+                .with_header(INDEXER_API_TOKEN_HEADER, api_token),
+            None => AlgorandHttpRequest::new(AlgorandHttpMethod::Get, &url),
+        };
+        Q::parse_page(&self.transport.send(&request)?.into_body()?)
+    }
+
+    /// ## Search Transactions
+    ///
+    /// Fetches a single page of transactions matching the given query.
+    pub fn search_transactions(
+        &self,
+        query: &TransactionSearchQuery,
+    ) -> Result<IndexerPage<AlgorandTransaction>> {
+        self.search(query)
+    }
+
+    /// ## Search Accounts
+    ///
+    /// Fetches a single page of accounts matching the given query.
+    pub fn search_accounts(
+        &self,
+        query: &AccountSearchQuery,
+    ) -> Result<IndexerPage<AlgorandAccountJson>> {
+        self.search(query)
+    }
+
+    /// ## Transactions
+    ///
+    /// Returns an iterator over every transaction matching the given query, fetching further pages
+    /// via their `next-token` as it goes.
+    pub fn transactions(
+        &self,
+        query: &TransactionSearchQuery,
+    ) -> IndexerSearchIterator<'_, T, TransactionSearchQuery> {
+        IndexerSearchIterator::new(self, query)
+    }
+
+    /// ## Accounts
+    ///
+    /// Returns an iterator over every account matching the given query, fetching further pages
+    /// via their `next-token` as it goes.
+    pub fn accounts(
+        &self,
+        query: &AccountSearchQuery,
+    ) -> IndexerSearchIterator<'_, T, AccountSearchQuery> {
+        IndexerSearchIterator::new(self, query)
+    }
+}
+
+/// ## Indexer Search Iterator
+///
+/// Iterates over the items of an indexer search, transparently following `next-token`s until the
+/// indexer runs out of results. Should fetching a page fail, the error is yielded and iteration
+/// stops.
+pub struct IndexerSearchIterator<'a, T: AlgorandHttpTransport, Q: IndexerSearchQuery> {
+    query: Q,
+    is_exhausted: bool,
+    buffer: VecDeque<Q::Item>,
+    client: &'a IndexerClient<T>,
+}
+
+impl<'a, T: AlgorandHttpTransport, Q: IndexerSearchQuery> IndexerSearchIterator<'a, T, Q> {
+    fn new(client: &'a IndexerClient<T>, query: &Q) -> Self {
+        Self {
+            client,
+            query: query.clone(),
+            is_exhausted: false,
+            buffer: VecDeque::new(),
+        }
+    }
+}
+
+impl<T: AlgorandHttpTransport, Q: IndexerSearchQuery> Iterator for IndexerSearchIterator<'_, T, Q> {
+    type Item = Result<Q::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.buffer.pop_front() {
+                return Some(Ok(item));
+            };
+            if self.is_exhausted {
+                return None;
+            };
+            match self.client.search(&self.query) {
+                Err(error) => {
+                    self.is_exhausted = true;
+                    return Some(Err(error));
+                },
+                Ok(page) => {
+                    match page.next_token {
+                        Some(ref next_token) if !page.items.is_empty() => {
+                            self.query.set_next_token(next_token)
+                        },
+                        _ => self.is_exhausted = true,
+                    };
+                    self.buffer.extend(page.items);
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_errors::AlgorandError,
+        algorand_http::{http_transport::AlgorandHttpResponse, test_utils::StubHttpTransport},
+        algorand_indexer::test_utils::{
+            get_sample_accounts_page_json_str,
+            get_sample_transactions_page_json_str,
+        },
+        algorand_transactions::test_utils::get_sample_txs_n,
+    };
+
+    fn get_sample_client(responses: Vec<AlgorandHttpResponse>) -> IndexerClient<StubHttpTransport> {
+        IndexerClient::new("http://localhost:8980/", StubHttpTransport::new(responses))
+    }
+
+    #[test]
+    fn should_search_transactions() {
+        let page_json = get_sample_transactions_page_json_str(0, None);
+        let client = get_sample_client(vec![AlgorandHttpResponse::new(200, page_json.as_bytes())])
+            .with_api_token("secret");
+        let query = TransactionSearchQuery::new().asset_id(27165954).limit(2);
+        let result = client.search_transactions(&query).unwrap();
+        let expected_txs = get_sample_txs_n(0)[0..2].to_vec();
+        assert_eq!(result.items, expected_txs);
+        let requests = client.transport.requests.borrow();
+        assert_eq!(
+            requests[0].url,
+            "http://localhost:8980/v2/transactions?asset-id=27165954&limit=2"
+        );
+        assert_eq!(
+            requests[0].get_header(INDEXER_API_TOKEN_HEADER),
+            Some("secret")
+        );
+    }
+
+    #[test]
+    fn should_follow_next_tokens_when_iterating_transactions() {
+        let client = get_sample_client(vec![
+            AlgorandHttpResponse::new(
+                200,
+                get_sample_transactions_page_json_str(0, Some("page-2")).as_bytes(),
+            ),
+            AlgorandHttpResponse::new(
+                200,
+                get_sample_transactions_page_json_str(2, Some("page-3")).as_bytes(),
+            ),
+            AlgorandHttpResponse::new(
+                200,
+                get_sample_transactions_page_json_str(4, None).as_bytes(),
+            ),
+        ]);
+        let query = TransactionSearchQuery::new().limit(2);
+        let result = client
+            .transactions(&query)
+            .collect::<Result<Vec<AlgorandTransaction>>>()
+            .unwrap();
+        let expected_result = get_sample_txs_n(0)[0..6].to_vec();
+        assert_eq!(result, expected_result);
+        let urls = client
+            .transport
+            .requests
+            .borrow()
+            .iter()
+            .map(|request| request.url.clone())
+            .collect::<Vec<String>>();
+        assert_eq!(urls, vec![
+            "http://localhost:8980/v2/transactions?limit=2",
+            "http://localhost:8980/v2/transactions?limit=2&next=page-2",
+            "http://localhost:8980/v2/transactions?limit=2&next=page-3",
+        ]);
+    }
+
+    #[test]
+    fn should_yield_error_and_stop_iterating_on_failed_request() {
+        let client = get_sample_client(vec![
+            AlgorandHttpResponse::new(
+                200,
+                get_sample_transactions_page_json_str(0, Some("page-2")).as_bytes(),
+            ),
+            AlgorandHttpResponse::new(500, b"boom"),
+        ]);
+        let results = client
+            .transactions(&TransactionSearchQuery::new())
+            .collect::<Vec<Result<AlgorandTransaction>>>();
+        assert_eq!(results.len(), 3);
+        let expected_error = "Http request failed with status 500: boom";
+        match &results[2] {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_iterate_accounts() {
+        let client = get_sample_client(vec![AlgorandHttpResponse::new(
+            200,
+            get_sample_accounts_page_json_str().as_bytes(),
+        )]);
+        let query = AccountSearchQuery::new().asset_id(27165954);
+        let result = client
+            .accounts(&query)
+            .collect::<Result<Vec<AlgorandAccountJson>>>()
+            .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(
+            result[0].address,
+            "ZW3ISEHZUHPO7OZGMKLKIIMKVICOUDRCERI454I3DB2BH52HGLSO67W754"
+        );
+        assert_eq!(
+            client.transport.requests.borrow()[0].url,
+            "http://localhost:8980/v2/accounts?asset-id=27165954"
+        );
+    }
+}
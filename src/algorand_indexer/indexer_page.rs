@@ -0,0 +1,89 @@
+use serde::Deserialize;
+
+use crate::{
+    algorand_accounts::account_json::AlgorandAccountJson,
+    algorand_transactions::{
+        transaction::AlgorandTransaction,
+        transaction_json::AlgorandTransactionJson,
+        transactions::AlgorandTransactions,
+    },
+    algorand_types::{Byte, Result},
+};
+
+/// ## Indexer Page
+///
+/// A single page of indexer search results, along with the `next-token` required to fetch the
+/// following page, if there is one.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IndexerPage<T> {
+    pub current_round: u64,
+    pub next_token: Option<String>,
+    pub items: Vec<T>,
+}
+
+#[derive(Deserialize)]
+struct TransactionsPageJson {
+    #[serde(rename = "current-round")]
+    current_round: u64,
+
+    #[serde(rename = "next-token")]
+    next_token: Option<String>,
+
+    transactions: Vec<AlgorandTransactionJson>,
+}
+
+#[derive(Deserialize)]
+struct AccountsPageJson {
+    #[serde(rename = "current-round")]
+    current_round: u64,
+
+    #[serde(rename = "next-token")]
+    next_token: Option<String>,
+
+    accounts: Vec<AlgorandAccountJson>,
+}
+
+impl IndexerPage<AlgorandTransaction> {
+    pub(crate) fn from_transactions_json_bytes(bytes: &[Byte]) -> Result<Self> {
+        let json = serde_json::from_slice::<TransactionsPageJson>(bytes)?;
+        Ok(Self {
+            current_round: json.current_round,
+            next_token: json.next_token,
+            items: json
+                .transactions
+                .iter()
+                .map(AlgorandTransaction::from_json)
+                .collect::<Result<Vec<AlgorandTransaction>>>()?,
+        })
+    }
+
+    pub fn to_transactions(&self) -> AlgorandTransactions {
+        AlgorandTransactions(self.items.clone())
+    }
+}
+
+impl IndexerPage<AlgorandAccountJson> {
+    pub(crate) fn from_accounts_json_bytes(bytes: &[Byte]) -> Result<Self> {
+        let json = serde_json::from_slice::<AccountsPageJson>(bytes)?;
+        Ok(Self {
+            current_round: json.current_round,
+            next_token: json.next_token,
+            items: json.accounts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_indexer::test_utils::get_sample_transactions_page_json_str;
+
+    #[test]
+    fn should_get_transactions_page_from_json_bytes() {
+        let json = get_sample_transactions_page_json_str(0, Some("token"));
+        let result = IndexerPage::from_transactions_json_bytes(json.as_bytes()).unwrap();
+        assert_eq!(result.current_round, 17962555);
+        assert_eq!(result.next_token, Some("token".to_string()));
+        assert_eq!(result.to_transactions().len(), 2);
+    }
+}
@@ -0,0 +1,5 @@
+pub(crate) mod account_search_query;
+pub(crate) mod indexer_client;
+pub(crate) mod indexer_page;
+pub(crate) mod test_utils;
+pub(crate) mod transaction_search_query;
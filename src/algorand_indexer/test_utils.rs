@@ -0,0 +1,27 @@
+#![cfg(test)]
+use serde_json::json;
+
+use crate::{
+    algorand_accounts::test_utils::get_sample_account_json_str,
+    algorand_transactions::test_utils::get_sample_txs_jsons,
+};
+
+/// Returns a page of two of the sample transactions from block 17962555, starting at `offset`.
+pub fn get_sample_transactions_page_json_str(offset: usize, next_token: Option<&str>) -> String {
+    let txs = get_sample_txs_jsons(0);
+    json!({
+        "current-round": 17962555,
+        "next-token": next_token,
+        "transactions": txs[offset..offset + 2],
+    })
+    .to_string()
+}
+
+pub fn get_sample_accounts_page_json_str() -> String {
+    let account: serde_json::Value = serde_json::from_str(&get_sample_account_json_str()).unwrap();
+    json!({
+        "current-round": 29285129,
+        "accounts": [account],
+    })
+    .to_string()
+}
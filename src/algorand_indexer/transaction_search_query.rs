@@ -0,0 +1,199 @@
+use std::fmt::Display;
+
+use base64::encode as base64_encode;
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_http::url_encoding::to_query_string,
+    algorand_transactions::transaction_type::AlgorandTransactionType,
+    algorand_types::{Byte, Bytes},
+};
+
+/// ## Address Role
+///
+/// Restricts an address-based transaction search to transactions where the address plays the
+/// given role.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AddressRole {
+    Sender,
+    Receiver,
+    FreezeTarget,
+}
+
+impl Display for AddressRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Sender => write!(f, "sender"),
+            Self::Receiver => write!(f, "receiver"),
+            Self::FreezeTarget => write!(f, "freeze-target"),
+        }
+    }
+}
+
+/// ## Transaction Search Query
+///
+/// A builder for the query parameters of the indexer's `/v2/transactions` endpoint.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TransactionSearchQuery {
+    address: Option<AlgorandAddress>,
+    address_role: Option<AddressRole>,
+    asset_id: Option<u64>,
+    application_id: Option<u64>,
+    min_round: Option<u64>,
+    max_round: Option<u64>,
+    note_prefix: Option<Bytes>,
+    tx_type: Option<AlgorandTransactionType>,
+    currency_greater_than: Option<u64>,
+    currency_less_than: Option<u64>,
+    limit: Option<u64>,
+    pub(crate) next: Option<String>,
+}
+
+impl TransactionSearchQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn address(mut self, address: &AlgorandAddress) -> Self {
+        self.address = Some(*address);
+        self
+    }
+
+    pub fn address_role(mut self, address_role: AddressRole) -> Self {
+        self.address_role = Some(address_role);
+        self
+    }
+
+    pub fn asset_id(mut self, asset_id: u64) -> Self {
+        self.asset_id = Some(asset_id);
+        self
+    }
+
+    pub fn application_id(mut self, application_id: u64) -> Self {
+        self.application_id = Some(application_id);
+        self
+    }
+
+    pub fn min_round(mut self, round: u64) -> Self {
+        self.min_round = Some(round);
+        self
+    }
+
+    pub fn max_round(mut self, round: u64) -> Self {
+        self.max_round = Some(round);
+        self
+    }
+
+    pub fn note_prefix(mut self, note_prefix: &[Byte]) -> Self {
+        self.note_prefix = Some(note_prefix.to_vec());
+        self
+    }
+
+    pub fn tx_type(mut self, tx_type: AlgorandTransactionType) -> Self {
+        self.tx_type = Some(tx_type);
+        self
+    }
+
+    /// ## Currency Greater Than
+    ///
+    /// Only return transactions moving more than this amount. The amount is in µAlgos, or in base
+    /// units of the asset if an asset id is also specified.
+    pub fn currency_greater_than(mut self, amount: u64) -> Self {
+        self.currency_greater_than = Some(amount);
+        self
+    }
+
+    /// ## Currency Less Than
+    ///
+    /// Only return transactions moving less than this amount. The amount is in µAlgos, or in base
+    /// units of the asset if an asset id is also specified.
+    pub fn currency_less_than(mut self, amount: u64) -> Self {
+        self.currency_less_than = Some(amount);
+        self
+    }
+
+    pub fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// ## Next
+    ///
+    /// The `next-token` returned by a previous page of results.
+    pub fn next(mut self, next_token: &str) -> Self {
+        self.next = Some(next_token.to_string());
+        self
+    }
+
+    pub fn to_query_string(&self) -> String {
+        let pairs = [
+            ("address", self.address.map(|x| x.to_string())),
+            ("address-role", self.address_role.map(|x| x.to_string())),
+            ("asset-id", self.asset_id.map(|x| x.to_string())),
+            ("application-id", self.application_id.map(|x| x.to_string())),
+            ("min-round", self.min_round.map(|x| x.to_string())),
+            ("max-round", self.max_round.map(|x| x.to_string())),
+            ("note-prefix", self.note_prefix.as_ref().map(base64_encode)),
+            ("tx-type", self.tx_type.as_ref().map(|x| x.to_string())),
+            (
+                "currency-greater-than",
+                self.currency_greater_than.map(|x| x.to_string()),
+            ),
+            (
+                "currency-less-than",
+                self.currency_less_than.map(|x| x.to_string()),
+            ),
+            ("limit", self.limit.map(|x| x.to_string())),
+            ("next", self.next.clone()),
+        ]
+        .into_iter()
+        .filter_map(|(key, maybe_value)| maybe_value.map(|value| (key, value)))
+        .collect::<Vec<(&str, String)>>();
+        to_query_string(&pairs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn should_create_empty_query_string() {
+        let result = TransactionSearchQuery::new().to_query_string();
+        assert_eq!(result, "");
+    }
+
+    #[test]
+    fn should_create_transaction_search_query_string() {
+        let address =
+            AlgorandAddress::from_str("ZW3ISEHZUHPO7OZGMKLKIIMKVICOUDRCERI454I3DB2BH52HGLSO67W754")
+                .unwrap();
+        let result = TransactionSearchQuery::new()
+            .address(&address)
+            .address_role(AddressRole::Receiver)
+            .asset_id(27165954)
+            .min_round(17962553)
+            .max_round(17963553)
+            .note_prefix(b"\xfa\xfb")
+            .tx_type(AlgorandTransactionType::AssetTransfer)
+            .currency_greater_than(10)
+            .currency_less_than(1000)
+            .limit(100)
+            .next("token")
+            .to_query_string();
+        let expected_result = "?address=ZW3ISEHZUHPO7OZGMKLKIIMKVICOUDRCERI454I3DB2BH52HGLSO67W754&address-role=receiver&asset-id=27165954&min-round=17962553&max-round=17963553&note-prefix=%2Bvs%3D&tx-type=axfer&currency-greater-than=10&currency-less-than=1000&limit=100&next=token";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_create_application_search_query_string() {
+        let result = TransactionSearchQuery::new()
+            .application_id(90556484)
+            .tx_type(AlgorandTransactionType::ApplicationCall)
+            .to_query_string();
+        let expected_result = "?application-id=90556484&tx-type=appl";
+        assert_eq!(result, expected_result);
+    }
+}
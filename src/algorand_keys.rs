@@ -9,6 +9,7 @@ use ed25519_dalek::{
     PublicKey,
     SecretKey,
     Signer,
+    KEYPAIR_LENGTH,
     SECRET_KEY_LENGTH,
 };
 use rand::rngs::OsRng;
@@ -71,6 +72,33 @@ impl AlgorandKeys {
         self.0.secret.to_bytes().to_vec()
     }
 
+    /// ## From Keypair Bytes
+    ///
+    /// Create the algorand key pair from 64 bytes comprising the private key followed by its public
+    /// key, which is the format exported by kmd.
+    pub fn from_keypair_bytes(bytes: &[Byte]) -> Result<Self> {
+        if bytes.len() != KEYPAIR_LENGTH {
+            return Err(format!(
+                "Keypair must be {KEYPAIR_LENGTH} bytes, got {}!",
+                bytes.len()
+            )
+            .into());
+        };
+        let keys = Self::from_bytes(&bytes[..SECRET_KEY_LENGTH])?;
+        if keys.to_pub_key_bytes()[..] != bytes[SECRET_KEY_LENGTH..] {
+            Err("Public key in keypair bytes does not match its private key!".into())
+        } else {
+            Ok(keys)
+        }
+    }
+
+    /// ## To Keypair Bytes
+    ///
+    /// Convert the key pair to 64 bytes comprising the private key followed by the public key.
+    pub fn to_keypair_bytes(&self) -> Bytes {
+        self.0.to_bytes().to_vec()
+    }
+
     /// ## To Address
     ///
     /// Convert the algorand keypair to an algorand address.
@@ -142,11 +170,14 @@ impl Eq for AlgorandKeys {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{
-        get_sample_algorand_keys,
-        get_sample_mnemonic,
-        get_sample_mnemonic_string,
-        get_sample_private_key_bytes,
+    use crate::{
+        algorand_errors::AlgorandError,
+        test_utils::{
+            get_sample_algorand_keys,
+            get_sample_mnemonic,
+            get_sample_mnemonic_string,
+            get_sample_private_key_bytes,
+        },
     };
 
     #[test]
@@ -162,6 +193,27 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn should_make_keypair_bytes_round_trip() {
+        let keys = get_sample_algorand_keys();
+        let bytes = keys.to_keypair_bytes();
+        assert_eq!(bytes.len(), KEYPAIR_LENGTH);
+        let result = AlgorandKeys::from_keypair_bytes(&bytes).unwrap();
+        assert_eq!(result, keys);
+    }
+
+    #[test]
+    fn should_fail_to_get_keys_from_mismatched_keypair_bytes() {
+        let mut bytes = get_sample_algorand_keys().to_keypair_bytes();
+        bytes[KEYPAIR_LENGTH - 1] ^= 1;
+        let expected_error = "Public key in keypair bytes does not match its private key!";
+        match AlgorandKeys::from_keypair_bytes(&bytes) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
     #[test]
     fn should_fail_to_get_keys_from_wrong_number_of_bytes() {
         let bad_bytes =
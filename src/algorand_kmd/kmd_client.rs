@@ -0,0 +1,499 @@
+use std::str::FromStr;
+
+use base64::{decode as base64_decode, encode as base64_encode};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value as JsonValue};
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_http::http_transport::{
+        AlgorandHttpMethod,
+        AlgorandHttpRequest,
+        AlgorandHttpTransport,
+    },
+    algorand_keys::AlgorandKeys,
+    algorand_kmd::{
+        kmd_json::{
+            KmdAddressJson,
+            KmdAddressesJson,
+            KmdErrorJson,
+            KmdMultisigJson,
+            KmdPrivateKeyJson,
+            KmdSignedTransactionJson,
+            KmdWalletHandleJson,
+            KmdWalletHandleResponseJson,
+            KmdWalletHandleTokenJson,
+            KmdWalletJson,
+            KmdWalletResponseJson,
+            KmdWalletsJson,
+        },
+        kmd_secrets::{WalletHandleToken, WalletPassword},
+    },
+    algorand_multisig::AlgorandMultisig,
+    algorand_traits::ToMsgPackBytes,
+    algorand_transactions::transaction::AlgorandTransaction,
+    algorand_types::{Bytes, Result},
+};
+
+const KMD_API_TOKEN_HEADER: &str = "X-KMD-API-Token";
+const KMD_DEFAULT_WALLET_DRIVER: &str = "sqlite";
+
+/// ## Kmd Client
+///
+/// A client for the v1 API of kmd, the Algorand key management daemon, sending its requests via
+/// whichever `AlgorandHttpTransport` it is given.
+pub struct KmdClient<T: AlgorandHttpTransport> {
+    url: String,
+    transport: T,
+    api_token: String,
+}
+
+impl<T: AlgorandHttpTransport> KmdClient<T> {
+    pub fn new(url: &str, api_token: &str, transport: T) -> Self {
+        Self {
+            transport,
+            api_token: api_token.to_string(),
+            url: url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    fn request<R: DeserializeOwned>(
+        &self,
+        method: AlgorandHttpMethod,
+        path: &str,
+        body: Option<JsonValue>,
+    ) -> Result<R> {
+        let mut request = AlgorandHttpRequest::new(method, &format!("{}{}", self.url, path))
+            .with_header(KMD_API_TOKEN_HEADER, &self.api_token);
+        if let Some(json) = body {
+            request = request
+                .with_header("Content-Type", "application/json")
+                .with_body(json.to_string().as_bytes());
+        };
+        let response = self.transport.send(&request)?;
+        if !response.is_success() {
+            if let Ok(error_json) = serde_json::from_slice::<KmdErrorJson>(&response.body) {
+                return Err(format!(
+                    "Kmd request to '{path}' failed with status {}: {}",
+                    response.status, error_json.message
+                )
+                .into());
+            };
+        };
+        Ok(serde_json::from_slice(&response.into_body()?)?)
+    }
+
+    fn post<R: DeserializeOwned>(&self, path: &str, body: JsonValue) -> Result<R> {
+        self.request(AlgorandHttpMethod::Post, path, Some(body))
+    }
+
+    /// ## List Wallets
+    ///
+    /// List the wallets held by kmd.
+    pub fn list_wallets(&self) -> Result<Vec<KmdWalletJson>> {
+        self.request::<KmdWalletsJson>(AlgorandHttpMethod::Get, "/v1/wallets", None)
+            .map(|json| json.wallets)
+    }
+
+    /// ## Create Wallet
+    ///
+    /// Create a new wallet with the given name & password, using kmd's default wallet driver.
+    pub fn create_wallet(&self, name: &str, password: &WalletPassword) -> Result<KmdWalletJson> {
+        self.post::<KmdWalletResponseJson>(
+            "/v1/wallet",
+            json!({
+                "wallet_name": name,
+                "wallet_password": password.expose_secret(),
+                "wallet_driver_name": KMD_DEFAULT_WALLET_DRIVER,
+            }),
+        )
+        .map(|json| json.wallet)
+    }
+
+    /// ## Init Wallet Handle
+    ///
+    /// Unlock the wallet with the given id, returning a handle token with which to access it.
+    pub fn init_wallet_handle(
+        &self,
+        wallet_id: &str,
+        password: &WalletPassword,
+    ) -> Result<WalletHandleToken> {
+        self.post::<KmdWalletHandleTokenJson>(
+            "/v1/wallet/init",
+            json!({ "wallet_id": wallet_id, "wallet_password": password.expose_secret() }),
+        )
+        .map(|json| WalletHandleToken::new(json.wallet_handle_token))
+    }
+
+    /// ## Renew Wallet Handle
+    ///
+    /// Reset the expiry of the given wallet handle token.
+    pub fn renew_wallet_handle(&self, token: &WalletHandleToken) -> Result<KmdWalletHandleJson> {
+        self.post::<KmdWalletHandleResponseJson>(
+            "/v1/wallet/renew",
+            json!({ "wallet_handle_token": token.expose_secret() }),
+        )
+        .map(|json| json.wallet_handle)
+    }
+
+    /// ## Release Wallet Handle
+    ///
+    /// Invalidate the given wallet handle token, locking the wallet again.
+    pub fn release_wallet_handle(&self, token: &WalletHandleToken) -> Result<()> {
+        self.post::<JsonValue>(
+            "/v1/wallet/release",
+            json!({ "wallet_handle_token": token.expose_secret() }),
+        )
+        .map(|_| ())
+    }
+
+    /// ## Import Key
+    ///
+    /// Import the given keys into the wallet, returning their address.
+    pub fn import_key(
+        &self,
+        token: &WalletHandleToken,
+        keys: &AlgorandKeys,
+    ) -> Result<AlgorandAddress> {
+        self.post::<KmdAddressJson>(
+            "/v1/key/import",
+            json!({
+                "wallet_handle_token": token.expose_secret(),
+                "private_key": base64_encode(keys.to_keypair_bytes()),
+            }),
+        )
+        .and_then(|json| AlgorandAddress::from_str(&json.address))
+    }
+
+    /// ## Export Key
+    ///
+    /// Export the keys for the given address from the wallet.
+    pub fn export_key(
+        &self,
+        token: &WalletHandleToken,
+        password: &WalletPassword,
+        address: &AlgorandAddress,
+    ) -> Result<AlgorandKeys> {
+        self.post::<KmdPrivateKeyJson>(
+            "/v1/key/export",
+            json!({
+                "wallet_handle_token": token.expose_secret(),
+                "wallet_password": password.expose_secret(),
+                "address": address.to_string(),
+            }),
+        )
+        .and_then(|json| AlgorandKeys::from_keypair_bytes(&base64_decode(json.private_key)?))
+    }
+
+    /// ## Generate Key
+    ///
+    /// Have kmd derive a new key in the wallet, returning its address.
+    pub fn generate_key(&self, token: &WalletHandleToken) -> Result<AlgorandAddress> {
+        self.post::<KmdAddressJson>(
+            "/v1/key",
+            json!({ "wallet_handle_token": token.expose_secret(), "display_mnemonic": false }),
+        )
+        .and_then(|json| AlgorandAddress::from_str(&json.address))
+    }
+
+    /// ## List Keys
+    ///
+    /// List the addresses of all the keys held in the wallet.
+    pub fn list_keys(&self, token: &WalletHandleToken) -> Result<Vec<AlgorandAddress>> {
+        self.post::<KmdAddressesJson>(
+            "/v1/key/list",
+            json!({ "wallet_handle_token": token.expose_secret() }),
+        )
+        .and_then(|json| {
+            json.addresses
+                .iter()
+                .map(|address| AlgorandAddress::from_str(address))
+                .collect()
+        })
+    }
+
+    /// ## Sign Transaction
+    ///
+    /// Have kmd sign the transaction with the sender's key from the wallet, returning the
+    /// msgpack-encoded signed transaction ready for broadcasting.
+    pub fn sign_transaction(
+        &self,
+        token: &WalletHandleToken,
+        password: &WalletPassword,
+        tx: &AlgorandTransaction,
+    ) -> Result<Bytes> {
+        self.post::<KmdSignedTransactionJson>(
+            "/v1/transaction/sign",
+            json!({
+                "wallet_handle_token": token.expose_secret(),
+                "wallet_password": password.expose_secret(),
+                "transaction": base64_encode(tx.to_msg_pack_bytes()?),
+            }),
+        )
+        .and_then(|json| Ok(base64_decode(json.signed_transaction)?))
+    }
+
+    /// ## Sign Multisig Transaction
+    ///
+    /// Have kmd add the signature of the given participant's key from the wallet to a partially
+    /// signed multisig.
+    pub fn sign_multisig_transaction(
+        &self,
+        token: &WalletHandleToken,
+        password: &WalletPassword,
+        tx: &AlgorandTransaction,
+        public_key: &AlgorandAddress,
+        partial_multisig: &AlgorandMultisig,
+    ) -> Result<AlgorandMultisig> {
+        let subsigs = partial_multisig
+            .subsigs
+            .iter()
+            .map(|subsig| match &subsig.signature {
+                Some(signature) => json!({
+                    "pk": base64_encode(subsig.public_key.to_bytes()),
+                    "s": signature.to_string(),
+                }),
+                None => json!({ "pk": base64_encode(subsig.public_key.to_bytes()) }),
+            })
+            .collect::<Vec<JsonValue>>();
+        self.post::<KmdMultisigJson>(
+            "/v1/multisig/sign",
+            json!({
+                "wallet_handle_token": token.expose_secret(),
+                "wallet_password": password.expose_secret(),
+                "transaction": base64_encode(tx.to_msg_pack_bytes()?),
+                "public_key": base64_encode(public_key.to_bytes()),
+                "partial_multisig": {
+                    "subsig": subsigs,
+                    "thr": partial_multisig.threshold,
+                    "v": partial_multisig.version,
+                },
+            }),
+        )
+        .and_then(|json| AlgorandMultisig::from_msg_pack_bytes(&base64_decode(json.multisig)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_errors::AlgorandError,
+        algorand_http::{
+            std_http_transport::StdHttpTransport,
+            test_utils::{StubHttpRoute, StubHttpServer},
+        },
+        algorand_transactions::{signing_options::SigningOptions, test_utils::get_sample_pay_tx},
+        test_utils::{get_sample_algorand_address, get_sample_algorand_keys},
+    };
+
+    const API_TOKEN: &str = "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa";
+
+    fn get_sample_client(
+        routes: Vec<StubHttpRoute>,
+    ) -> (KmdClient<StdHttpTransport>, StubHttpServer) {
+        let server = StubHttpServer::start(routes);
+        (
+            KmdClient::new(&server.url, API_TOKEN, StdHttpTransport::default()),
+            server,
+        )
+    }
+
+    fn get_request_json(server: &StubHttpServer) -> JsonValue {
+        let request = server.next_request();
+        assert_eq!(request.get_header(KMD_API_TOKEN_HEADER), Some(API_TOKEN));
+        serde_json::from_slice(&request.body.unwrap()).unwrap()
+    }
+
+    fn get_sample_token() -> WalletHandleToken {
+        WalletHandleToken::from("handle")
+    }
+
+    fn get_sample_password() -> WalletPassword {
+        WalletPassword::from("password")
+    }
+    #[test]
+    fn should_list_wallets() {
+        let body = r#"{"wallets":[{"driver_name":"sqlite","driver_version":1,"id":"4596a5cb20ccbedcec668762449363c1","mnemonic_ux":false,"name":"unencrypted-default-wallet","supported_txs":["pay","keyreg"]}]}"#;
+        let (client, server) =
+            get_sample_client(vec![StubHttpRoute::new("/v1/wallets", 200, body)]);
+        let result = client.list_wallets().unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].id, "4596a5cb20ccbedcec668762449363c1");
+        assert_eq!(result[0].name, "unencrypted-default-wallet");
+        let request = server.next_request();
+        assert_eq!(request.method, AlgorandHttpMethod::Get);
+        assert_eq!(request.get_header(KMD_API_TOKEN_HEADER), Some(API_TOKEN));
+    }
+
+    #[test]
+    fn should_create_wallet() {
+        let body = r#"{"wallet":{"id":"abc","name":"test-wallet"}}"#;
+        let (client, server) = get_sample_client(vec![StubHttpRoute::new("/v1/wallet", 200, body)]);
+        let result = client
+            .create_wallet("test-wallet", &get_sample_password())
+            .unwrap();
+        assert_eq!(result.id, "abc");
+        let expected_json = json!({
+            "wallet_name": "test-wallet",
+            "wallet_password": "password",
+            "wallet_driver_name": "sqlite",
+        });
+        assert_eq!(get_request_json(&server), expected_json);
+    }
+
+    #[test]
+    fn should_init_and_renew_wallet_handle() {
+        let (client, server) = get_sample_client(vec![
+            StubHttpRoute::new(
+                "/v1/wallet/init",
+                200,
+                r#"{"wallet_handle_token":"handle"}"#,
+            ),
+            StubHttpRoute::new(
+                "/v1/wallet/renew",
+                200,
+                r#"{"wallet_handle":{"expires_seconds":59,"wallet":{"id":"abc","name":"w"}}}"#,
+            ),
+        ]);
+        let token = client
+            .init_wallet_handle("abc", &get_sample_password())
+            .unwrap();
+        assert_eq!(token, get_sample_token());
+        assert_eq!(
+            get_request_json(&server),
+            json!({ "wallet_id": "abc", "wallet_password": "password" })
+        );
+        let result = client.renew_wallet_handle(&token).unwrap();
+        assert_eq!(result.expires_seconds, 59);
+        assert_eq!(
+            get_request_json(&server),
+            json!({ "wallet_handle_token": "handle" })
+        );
+    }
+
+    #[test]
+    fn should_import_key() {
+        let address = get_sample_algorand_address();
+        let body = format!("{{\"address\":\"{address}\"}}");
+        let (client, server) =
+            get_sample_client(vec![StubHttpRoute::new("/v1/key/import", 200, &body)]);
+        let keys = get_sample_algorand_keys();
+        let result = client.import_key(&get_sample_token(), &keys).unwrap();
+        assert_eq!(result, address);
+        let expected_json = json!({
+            "wallet_handle_token": "handle",
+            "private_key": base64_encode(keys.to_keypair_bytes()),
+        });
+        assert_eq!(get_request_json(&server), expected_json);
+    }
+
+    #[test]
+    fn should_export_key() {
+        let keys = get_sample_algorand_keys();
+        let body = format!(
+            "{{\"private_key\":\"{}\"}}",
+            base64_encode(keys.to_keypair_bytes())
+        );
+        let (client, server) =
+            get_sample_client(vec![StubHttpRoute::new("/v1/key/export", 200, &body)]);
+        let address = get_sample_algorand_address();
+        let result = client
+            .export_key(&get_sample_token(), &get_sample_password(), &address)
+            .unwrap();
+        assert_eq!(result, keys);
+        assert_eq!(
+            get_request_json(&server)["address"],
+            json!(address.to_string())
+        );
+    }
+
+    #[test]
+    fn should_list_keys() {
+        let address = get_sample_algorand_address();
+        let body = format!("{{\"addresses\":[\"{address}\"]}}");
+        let (client, _server) =
+            get_sample_client(vec![StubHttpRoute::new("/v1/key/list", 200, &body)]);
+        let result = client.list_keys(&get_sample_token()).unwrap();
+        assert_eq!(result, vec![address]);
+    }
+
+    #[test]
+    fn should_sign_transaction() {
+        let tx = get_sample_pay_tx();
+        let keys = get_sample_algorand_keys();
+        let options = SigningOptions::default().with_auth_address(keys.to_address().unwrap());
+        let signed_tx_bytes = tx
+            .sign_with_options(&keys, &options)
+            .unwrap()
+            .to_msg_pack_bytes()
+            .unwrap();
+        let body = format!(
+            "{{\"signed_transaction\":\"{}\"}}",
+            base64_encode(&signed_tx_bytes)
+        );
+        let (client, server) =
+            get_sample_client(vec![StubHttpRoute::new("/v1/transaction/sign", 200, &body)]);
+        let result = client
+            .sign_transaction(&get_sample_token(), &get_sample_password(), &tx)
+            .unwrap();
+        assert_eq!(result, signed_tx_bytes);
+        let request_json = get_request_json(&server);
+        assert_eq!(
+            request_json["transaction"],
+            json!(base64_encode(tx.to_msg_pack_bytes().unwrap()))
+        );
+    }
+
+    #[test]
+    fn should_sign_multisig_transaction() {
+        let keys = get_sample_algorand_keys();
+        let address = get_sample_algorand_address();
+        let multisig = AlgorandMultisig::new(1, &[address, AlgorandAddress::default()]).unwrap();
+        let mut signed_multisig = multisig.clone();
+        signed_multisig.subsigs[0].signature = Some(keys.sign(b"tx"));
+        let body = format!(
+            "{{\"multisig\":\"{}\"}}",
+            base64_encode(signed_multisig.to_msg_pack_bytes().unwrap())
+        );
+        let (client, server) =
+            get_sample_client(vec![StubHttpRoute::new("/v1/multisig/sign", 200, &body)]);
+        let result = client
+            .sign_multisig_transaction(
+                &get_sample_token(),
+                &get_sample_password(),
+                &get_sample_pay_tx(),
+                &address,
+                &multisig,
+            )
+            .unwrap();
+        assert_eq!(result, signed_multisig);
+        let request_json = get_request_json(&server);
+        assert_eq!(
+            request_json["public_key"],
+            json!(base64_encode(address.to_bytes()))
+        );
+        assert_eq!(request_json["partial_multisig"]["thr"], json!(1));
+        assert_eq!(
+            request_json["partial_multisig"]["subsig"]
+                .as_array()
+                .unwrap()
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn should_surface_kmd_error_message() {
+        let body = r#"{"error":true,"message":"wrong password"}"#;
+        let (client, _server) =
+            get_sample_client(vec![StubHttpRoute::new("/v1/wallet/init", 401, body)]);
+        let expected_error =
+            "Kmd request to '/v1/wallet/init' failed with status 401: wrong password";
+        match client.init_wallet_handle("abc", &get_sample_password()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
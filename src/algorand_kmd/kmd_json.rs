@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// ## Kmd Wallet Json
+///
+/// Describes a wallet held by kmd.
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KmdWalletJson {
+    pub id: String,
+
+    pub name: String,
+
+    pub driver_name: Option<String>,
+
+    pub driver_version: Option<u64>,
+
+    pub mnemonic_ux: Option<bool>,
+
+    pub supported_txs: Option<Vec<String>>,
+}
+
+/// ## Kmd Wallet Handle Json
+///
+/// Describes the wallet a handle token unlocks, and how many seconds remain until it expires.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KmdWalletHandleJson {
+    pub expires_seconds: i64,
+
+    pub wallet: KmdWalletJson,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdErrorJson {
+    pub message: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdWalletsJson {
+    #[serde(default)]
+    pub wallets: Vec<KmdWalletJson>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdWalletResponseJson {
+    pub wallet: KmdWalletJson,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdWalletHandleTokenJson {
+    pub wallet_handle_token: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdWalletHandleResponseJson {
+    pub wallet_handle: KmdWalletHandleJson,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdAddressJson {
+    pub address: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdAddressesJson {
+    #[serde(default)]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdPrivateKeyJson {
+    pub private_key: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdSignedTransactionJson {
+    pub signed_transaction: String,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct KmdMultisigJson {
+    pub multisig: String,
+}
@@ -0,0 +1,71 @@
+use std::fmt::{Debug, Display};
+
+use derive_more::Constructor;
+
+macro_rules! make_redacted_secret {
+    ($($(#[$attr:meta])* $name:ident),*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Clone, PartialEq, Eq, Constructor)]
+            pub struct $name(String);
+
+            impl $name {
+                /// ## Expose Secret
+                ///
+                /// Get the underlying secret. Take care not to log it!
+                pub fn expose_secret(&self) -> &str {
+                    &self.0
+                }
+            }
+
+            impl From<&str> for $name {
+                fn from(s: &str) -> Self {
+                    Self(s.to_string())
+                }
+            }
+
+            impl Debug for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "{}(<redacted>)", stringify!($name))
+                }
+            }
+
+            impl Display for $name {
+                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    write!(f, "<redacted>")
+                }
+            }
+        )*
+    }
+}
+
+make_redacted_secret!(
+    /// ## Wallet Password
+    ///
+    /// The password of a kmd wallet, redacted from its `Debug` and `Display` output.
+    WalletPassword,
+    /// ## Wallet Handle Token
+    ///
+    /// A token granting access to an unlocked kmd wallet until it expires, redacted from its
+    /// `Debug` and `Display` output.
+    WalletHandleToken
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_redact_wallet_password() {
+        let password = WalletPassword::from("hunter2");
+        assert_eq!(format!("{password}"), "<redacted>");
+        assert_eq!(format!("{password:?}"), "WalletPassword(<redacted>)");
+        assert_eq!(password.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn should_redact_wallet_handle_token() {
+        let token = WalletHandleToken::new("abc".to_string());
+        assert_eq!(format!("{token:?}"), "WalletHandleToken(<redacted>)");
+    }
+}
@@ -0,0 +1,3 @@
+pub(crate) mod kmd_client;
+pub(crate) mod kmd_json;
+pub(crate) mod kmd_secrets;
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_multisig::AlgorandMultisig,
+    algorand_signature::AlgorandSignature,
+    algorand_traits::ToMsgPackBytes,
+    algorand_types::{Byte, Bytes, Result},
+    predicates::is_empty_vec,
+};
+
+/// ## Algorand Logic Sig
+///
+/// A compiled TEAL program along with the arguments to pass to it, which authorizes a transaction
+/// if the program approves it. If the program is delegated, the logic sig also carries the
+/// delegating account's signature or multisig over the program.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandLogicSig {
+    #[serde(rename = "arg", default, skip_serializing_if = "is_empty_vec")]
+    pub args: Option<Vec<ByteBuf>>,
+
+    #[serde(rename = "l", with = "serde_bytes")]
+    pub program: Bytes,
+
+    #[serde(rename = "msig")]
+    pub multisig: Option<AlgorandMultisig>,
+
+    #[serde(rename = "sig")]
+    pub signature: Option<AlgorandSignature>,
+}
+
+impl ToMsgPackBytes for AlgorandLogicSig {}
+
+impl AlgorandLogicSig {
+    /// ## New
+    ///
+    /// Create an undelegated logic sig from a compiled program and the arguments to pass to it.
+    pub fn new(program: &[Byte], args: &[Bytes]) -> Self {
+        Self {
+            program: program.to_vec(),
+            args: Some(args.iter().cloned().map(ByteBuf::from).collect()),
+            multisig: None,
+            signature: None,
+        }
+    }
+
+    pub fn from_msg_pack_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(rmp_serde::from_slice(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serialize_logic_sig_to_msg_pack() {
+        let lsig = AlgorandLogicSig::new(&[0x06, 0x81, 0x01], &[vec![0x01, 0x02]]);
+        let result = hex::encode(lsig.to_msg_pack_bytes().unwrap());
+        let expected_result = "82a361726791c4020102a16cc403068101";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_skip_empty_args_when_serializing_logic_sig() {
+        let lsig = AlgorandLogicSig::new(&[0x06, 0x81, 0x01], &[]);
+        let result = hex::encode(lsig.to_msg_pack_bytes().unwrap());
+        let expected_result = "81a16cc403068101";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_round_trip_logic_sig_via_msg_pack() {
+        let lsig = AlgorandLogicSig::new(&[0x06, 0x81, 0x01], &[vec![0x01, 0x02]]);
+        let result =
+            AlgorandLogicSig::from_msg_pack_bytes(&lsig.to_msg_pack_bytes().unwrap()).unwrap();
+        assert_eq!(result, lsig);
+    }
+}
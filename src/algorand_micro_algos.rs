@@ -1,15 +1,31 @@
 use std::{
     fmt,
-    ops::{Add, Mul, Sub},
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Sub, SubAssign},
+    str::FromStr,
 };
 
 use derive_more::Constructor;
 use serde::{Deserialize, Serialize, Serializer};
 
-use crate::algorand_types::Result;
+use crate::{
+    algorand_errors::AlgorandError,
+    algorand_transactions::asset_amount::{format_decimal, parse_decimal_str},
+    algorand_types::Result,
+};
 
 const ALGORAND_MINIMUM_FEE: u64 = 1_000;
 pub(crate) const MICRO_ALGOS_MULTIPLIER: u64 = 1_000_000;
+const ALGO_DECIMALS: u64 = 6;
+const ALGO_UNITS: [&str; 2] = ["algo", "algos"];
+const MICRO_ALGO_UNITS: [&str; 6] = [
+    "µalgo",
+    "µalgos",
+    "ualgo",
+    "ualgos",
+    "microalgo",
+    "microalgos",
+];
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Deserialize, Constructor)]
 pub struct MicroAlgos(pub(crate) u64);
@@ -17,13 +33,54 @@ pub struct MicroAlgos(pub(crate) u64);
 impl MicroAlgos {
     /// From Algos
     ///
-    /// Create MicroAlgos from Algos.
+    /// Create MicroAlgos from a whole number of Algos, failing if that overflows.
     pub fn from_algos(algos: u64) -> Result<Self> {
-        Ok(Self(algos / MICRO_ALGOS_MULTIPLIER))
+        match algos.checked_mul(MICRO_ALGOS_MULTIPLIER) {
+            Some(micro_algos) => Ok(Self(micro_algos)),
+            None => {
+                Err(format!("{algos} Algos overflows the maximum amount of MicroAlgos!").into())
+            },
+        }
     }
 
+    /// ## To Algos
+    ///
+    /// Get the whole number of Algos in this amount, rounding down.
     pub fn to_algos(&self) -> u64 {
-        self.0 * MICRO_ALGOS_MULTIPLIER
+        self.0 / MICRO_ALGOS_MULTIPLIER
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.0.checked_add(rhs.0).map(Self)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.0.checked_sub(rhs.0).map(Self)
+    }
+
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    /// ## Checked Sum
+    ///
+    /// Sum the given amounts, returning `None` if that overflows.
+    pub fn checked_sum<I: IntoIterator<Item = Self>>(amounts: I) -> Option<Self> {
+        amounts
+            .into_iter()
+            .try_fold(Self(0), |total, amount| total.checked_add(amount))
+    }
+
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    pub fn saturating_mul(self, rhs: u64) -> Self {
+        Self(self.0.saturating_mul(rhs))
     }
 
     fn satisfies_minimum_fee(&self) -> bool {
@@ -55,7 +112,78 @@ impl MicroAlgos {
 
 impl fmt::Display for MicroAlgos {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_algos())
+        write!(f, "{} ALGO", format_decimal(self.0, ALGO_DECIMALS))
+    }
+}
+
+impl FromStr for MicroAlgos {
+    type Err = AlgorandError;
+
+    /// Parse an amount with its unit, eg `1.234567 ALGO` or `1500 µAlgo`. The unit is required
+    /// since a bare number could mean either.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let unit_index = s
+            .find(|c: char| !c.is_ascii_digit() && c != '.')
+            .unwrap_or(s.len());
+        let (amount, unit) = (
+            s[..unit_index].trim(),
+            s[unit_index..].trim().to_lowercase(),
+        );
+        if ALGO_UNITS.contains(&unit.as_str()) {
+            Ok(Self(parse_decimal_str(amount, ALGO_DECIMALS)?))
+        } else if MICRO_ALGO_UNITS.contains(&unit.as_str()) {
+            Ok(Self(parse_decimal_str(amount, 0)?))
+        } else {
+            Err(format!(
+                "Cannot parse '{s}' as MicroAlgos - expected an amount followed by ALGO or µAlgo!"
+            )
+            .into())
+        }
+    }
+}
+
+// NOTE: The operators panic with a message on overflow, rather than wrapping in release builds.
+// Use the checked or saturating methods where an overflow is possible.
+impl Add for MicroAlgos {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| panic!("Adding {rhs} to {self} overflows!"))
+    }
+}
+
+impl AddAssign for MicroAlgos {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for MicroAlgos {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .unwrap_or_else(|| panic!("Subtracting {rhs} from {self} underflows!"))
+    }
+}
+
+impl SubAssign for MicroAlgos {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl Sum for MicroAlgos {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(0), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a MicroAlgos> for MicroAlgos {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
     }
 }
 
@@ -63,7 +191,7 @@ impl Add<u64> for MicroAlgos {
     type Output = Self;
 
     fn add(self, rhs: u64) -> Self::Output {
-        MicroAlgos(self.0 + rhs)
+        self + Self(rhs)
     }
 }
 
@@ -71,7 +199,7 @@ impl Sub<u64> for MicroAlgos {
     type Output = Self;
 
     fn sub(self, rhs: u64) -> Self::Output {
-        MicroAlgos(self.0 - rhs)
+        self - Self(rhs)
     }
 }
 
@@ -79,7 +207,8 @@ impl Mul<u64> for MicroAlgos {
     type Output = Self;
 
     fn mul(self, rhs: u64) -> Self::Output {
-        MicroAlgos(self.0 * rhs)
+        self.checked_mul(rhs)
+            .unwrap_or_else(|| panic!("Multiplying {self} by {rhs} overflows!"))
     }
 }
 
@@ -102,8 +231,8 @@ mod tests {
 
     #[test]
     fn minimum_fee_should_be_correct() {
-        let result = MicroAlgos::minimum_fee().to_algos();
-        let expected_result = ALGORAND_MINIMUM_FEE * MICRO_ALGOS_MULTIPLIER;
+        let result = MicroAlgos::minimum_fee();
+        let expected_result = MicroAlgos::new(ALGORAND_MINIMUM_FEE);
         assert_eq!(result, expected_result);
     }
 
@@ -116,16 +245,157 @@ mod tests {
 
     #[test]
     fn should_fail_minimum_fee_check_if_amount_too_low() {
-        let num = (ALGORAND_MINIMUM_FEE * MICRO_ALGOS_MULTIPLIER) - 1;
+        let num = ALGORAND_MINIMUM_FEE - 1;
         let expected_error =
             format!("Fee is below the minimum algorand fee of {ALGORAND_MINIMUM_FEE} MicroAlgos!");
-        match MicroAlgos::from_algos(num)
-            .unwrap()
-            .check_if_satisfies_minimum_fee()
-        {
+        match MicroAlgos::new(num).check_if_satisfies_minimum_fee() {
             Ok(_) => panic!("Should not have succeeded!"),
             Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
             Err(_) => panic!("Wrong err received!"),
         };
     }
+
+    #[test]
+    fn should_convert_between_algos_and_micro_algos() {
+        let result = MicroAlgos::from_algos(5).unwrap();
+        assert_eq!(result, MicroAlgos::new(5_000_000));
+        assert_eq!(MicroAlgos::new(5_999_999).to_algos(), 5);
+    }
+
+    #[test]
+    fn should_fail_to_create_micro_algos_from_too_many_algos() {
+        let expected_error = format!(
+            "{} Algos overflows the maximum amount of MicroAlgos!",
+            u64::MAX
+        );
+        match MicroAlgos::from_algos(u64::MAX) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong err received!"),
+        };
+    }
+
+    #[test]
+    fn should_display_micro_algos_as_algos() {
+        assert_eq!(MicroAlgos::new(1_234_567).to_string(), "1.234567 ALGO");
+        assert_eq!(MicroAlgos::minimum_fee().to_string(), "0.001000 ALGO");
+    }
+
+    #[test]
+    fn should_parse_micro_algos_from_str() {
+        let cases = [
+            ("1.234567 ALGO", 1_234_567),
+            ("2 Algos", 2_000_000),
+            ("0.5algo", 500_000),
+            ("1500 µAlgo", 1_500),
+            ("1500 MicroAlgos", 1_500),
+            (" 7 uAlgo ", 7),
+        ];
+        for (s, micro_algos) in cases {
+            assert_eq!(
+                MicroAlgos::from_str(s).unwrap(),
+                MicroAlgos::new(micro_algos)
+            );
+        }
+    }
+
+    #[test]
+    fn should_round_trip_micro_algos_via_display() {
+        let micro_algos = MicroAlgos::new(1_234_567);
+        let result = MicroAlgos::from_str(&micro_algos.to_string()).unwrap();
+        assert_eq!(result, micro_algos);
+    }
+
+    #[test]
+    fn should_fail_to_parse_algos_with_too_many_decimal_places() {
+        let expected_error = "Cannot parse '1.2345678' since it has more than 6 decimal places!";
+        match MicroAlgos::from_str("1.2345678 ALGO") {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong err received!"),
+        };
+    }
+
+    #[test]
+    fn should_fail_to_parse_fractional_micro_algos() {
+        let expected_error = "Cannot parse '1.5' since it has more than 0 decimal places!";
+        match MicroAlgos::from_str("1.5 µAlgo") {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong err received!"),
+        };
+    }
+
+    #[test]
+    fn should_fail_to_parse_micro_algos_without_unit() {
+        let expected_error =
+            "Cannot parse '1500' as MicroAlgos - expected an amount followed by ALGO or µAlgo!";
+        match MicroAlgos::from_str("1500") {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong err received!"),
+        };
+    }
+
+    #[test]
+    fn should_do_checked_and_saturating_arithmetic() {
+        let max = MicroAlgos::new(u64::MAX);
+        let one = MicroAlgos::new(1);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(one.checked_sub(max), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(one.checked_add(one), Some(MicroAlgos::new(2)));
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(one.saturating_sub(max), MicroAlgos::new(0));
+        assert_eq!(max.saturating_mul(2), max);
+    }
+
+    #[test]
+    fn should_add_subtract_and_sum_micro_algos() {
+        let mut amount = MicroAlgos::new(1_000) + MicroAlgos::new(500);
+        amount -= MicroAlgos::new(200);
+        amount += MicroAlgos::new(100);
+        assert_eq!(amount, MicroAlgos::new(1_400));
+        let amounts = vec![MicroAlgos::new(1), MicroAlgos::new(2), MicroAlgos::new(3)];
+        assert_eq!(amounts.iter().sum::<MicroAlgos>(), MicroAlgos::new(6));
+        assert_eq!(amounts.into_iter().sum::<MicroAlgos>(), MicroAlgos::new(6));
+    }
+
+    #[test]
+    fn should_checked_sum_micro_algos() {
+        let amounts = [MicroAlgos::new(1), MicroAlgos::new(2), MicroAlgos::new(3)];
+        assert_eq!(MicroAlgos::checked_sum(amounts), Some(MicroAlgos::new(6)));
+        assert_eq!(MicroAlgos::checked_sum([]), Some(MicroAlgos::new(0)));
+    }
+
+    #[test]
+    fn should_fail_to_checked_sum_micro_algos_if_it_overflows() {
+        let amounts = [MicroAlgos::new(u64::MAX), MicroAlgos::new(1)];
+        assert_eq!(MicroAlgos::checked_sum(amounts), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows!")]
+    fn should_panic_if_adding_micro_algos_overflows() {
+        let _ = MicroAlgos::new(u64::MAX) + MicroAlgos::new(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "underflows!")]
+    fn should_panic_if_subtracting_micro_algos_underflows() {
+        let mut amount = MicroAlgos::new(1);
+        amount -= MicroAlgos::new(2);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows!")]
+    fn should_panic_if_multiplying_micro_algos_overflows() {
+        let _ = MicroAlgos::new(u64::MAX) * 2;
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows!")]
+    fn should_panic_if_summing_micro_algos_overflows() {
+        let _: MicroAlgos = [MicroAlgos::new(u64::MAX), MicroAlgos::new(1)].iter().sum();
+    }
 }
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_signature::AlgorandSignature,
+    algorand_traits::ToMsgPackBytes,
+    algorand_types::{Byte, Result},
+    crypto_utils::sha512_256_hash_bytes,
+};
+
+const MULTISIG_VERSION: u8 = 1;
+const MULTISIG_ADDRESS_PREFIX: &[u8] = b"MultisigAddr";
+
+/// ## Algorand Multisig Subsignature
+///
+/// A single participant's public key, along with their signature if they have signed.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandMultisigSubsig {
+    #[serde(rename = "pk")]
+    pub public_key: AlgorandAddress,
+
+    #[serde(rename = "s")]
+    pub signature: Option<AlgorandSignature>,
+}
+
+/// ## Algorand Multisig
+///
+/// A (possibly partially signed) multisignature, defined by its version, threshold and the
+/// ordered list of participants' public keys.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandMultisig {
+    #[serde(rename = "subsig")]
+    pub subsigs: Vec<AlgorandMultisigSubsig>,
+
+    #[serde(rename = "thr")]
+    pub threshold: u8,
+
+    #[serde(rename = "v")]
+    pub version: u8,
+}
+
+impl ToMsgPackBytes for AlgorandMultisig {}
+
+impl AlgorandMultisig {
+    /// ## New
+    ///
+    /// Create an unsigned multisig from the participants' addresses. The order of the addresses
+    /// matters, since it determines the resulting multisig address.
+    pub fn new(threshold: u8, addresses: &[AlgorandAddress]) -> Result<Self> {
+        if threshold == 0 || threshold as usize > addresses.len() {
+            Err(format!(
+                "Multisig threshold must be between 1 and the number of addresses ({}), got {threshold}!",
+                addresses.len()
+            )
+            .into())
+        } else {
+            Ok(Self {
+                threshold,
+                version: MULTISIG_VERSION,
+                subsigs: addresses
+                    .iter()
+                    .map(|address| AlgorandMultisigSubsig {
+                        public_key: *address,
+                        signature: None,
+                    })
+                    .collect(),
+            })
+        }
+    }
+
+    pub fn from_msg_pack_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(rmp_serde::from_slice(bytes)?)
+    }
+
+    /// ## To Address
+    ///
+    /// The address of a multisig account is the hash of its version, threshold and participants'
+    /// public keys, domain-separated with the `MultisigAddr` prefix.
+    pub fn to_address(&self) -> Result<AlgorandAddress> {
+        let bytes = [
+            MULTISIG_ADDRESS_PREFIX.to_vec(),
+            vec![self.version, self.threshold],
+            self.subsigs
+                .iter()
+                .flat_map(|subsig| subsig.public_key.to_bytes())
+                .collect(),
+        ]
+        .concat();
+        AlgorandAddress::from_bytes(&sha512_256_hash_bytes(&bytes))
+    }
+
+    pub fn num_signatures(&self) -> usize {
+        self.subsigs
+            .iter()
+            .filter(|subsig| subsig.signature.is_some())
+            .count()
+    }
+
+    /// ## Merge
+    ///
+    /// Combine the signatures of two partially signed copies of the same multisig.
+    pub fn merge(&self, other: &Self) -> Result<Self> {
+        if self.to_address()? != other.to_address()? {
+            return Err("Cannot merge signatures from different multisig accounts!".into());
+        };
+        let subsigs = self
+            .subsigs
+            .iter()
+            .zip(other.subsigs.iter())
+            .map(|(a, b)| match (&a.signature, &b.signature) {
+                (Some(x), Some(y)) if x != y => Err(format!(
+                    "Conflicting signatures for multisig participant {}!",
+                    a.public_key
+                )
+                .into()),
+                (Some(_), _) => Ok(a.clone()),
+                _ => Ok(b.clone()),
+            })
+            .collect::<Result<Vec<AlgorandMultisigSubsig>>>()?;
+        Ok(Self {
+            subsigs,
+            ..self.clone()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{algorand_errors::AlgorandError, test_utils::get_sample_algorand_keys};
+
+    fn get_sample_multisig() -> AlgorandMultisig {
+        let addresses = [
+            "DN7MBMCL5JQ3PFUQS7TMX5AH4EEKOBJVDUF4TCV6WERATKFLQF4MQUPZTA",
+            "BFRTECKTOOE7A5LHCF3TTEOH2A7BW46IYT2SX5VP6ANKEXHZYJY77SJTVM",
+            "47YPQTIGQEO7T4Y4RWDYWEKV6RTR2UNBQXBABEEGM72ESWDQNCQ52OPASU",
+        ]
+        .iter()
+        .map(|s| AlgorandAddress::from_str(s).unwrap())
+        .collect::<Vec<AlgorandAddress>>();
+        AlgorandMultisig::new(2, &addresses).unwrap()
+    }
+
+    #[test]
+    fn should_get_multisig_address() {
+        let result = get_sample_multisig().to_address().unwrap().to_string();
+        let expected_result = "RWJLJCMQAFZ2ATP2INM2GZTKNL6OULCCUBO5TQPXH3V2KR4AG7U5UA5JNM";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_create_multisig_with_bad_threshold() {
+        let expected_error =
+            "Multisig threshold must be between 1 and the number of addresses (1), got 2!";
+        match AlgorandMultisig::new(2, &[AlgorandAddress::default()]) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_serde_multisig_to_and_from_msg_pack_bytes() {
+        let mut multisig = get_sample_multisig();
+        multisig.subsigs[1].signature = Some(get_sample_algorand_keys().sign(b"msg"));
+        let bytes = multisig.to_msg_pack_bytes().unwrap();
+        let result = AlgorandMultisig::from_msg_pack_bytes(&bytes).unwrap();
+        assert_eq!(result, multisig);
+    }
+
+    #[test]
+    fn should_merge_multisigs() {
+        let signature = get_sample_algorand_keys().sign(b"msg");
+        let mut a = get_sample_multisig();
+        let mut b = get_sample_multisig();
+        a.subsigs[0].signature = Some(signature.clone());
+        b.subsigs[2].signature = Some(signature);
+        let result = a.merge(&b).unwrap();
+        assert_eq!(result.num_signatures(), 2);
+    }
+}
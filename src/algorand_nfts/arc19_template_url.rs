@@ -0,0 +1,316 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_errors::AlgorandError,
+    algorand_transactions::asset_parameters::AssetParameters,
+    algorand_types::{Byte, Result},
+    crypto_utils::{base32_decode, base32_encode_with_no_padding, base58_decode, base58_encode},
+};
+
+const TEMPLATE_PREFIX: &str = "template-ipfs://{ipfscid:";
+const IPFS_PREFIX: &str = "ipfs://";
+const RESERVE_FIELD: &str = "reserve";
+const SHA2_256_HASH_TYPE: &str = "sha2-256";
+const SHA2_256_MULTIHASH_PREFIX: [Byte; 2] = [0x12, 0x20];
+const CID_V1: Byte = 0x01;
+const CID_V1_BASE32_PREFIX: char = 'b';
+
+/// ## ARC-19 Codec
+///
+/// The multicodec of the content an ARC-19 CID points to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arc19Codec {
+    Raw,
+    DagPb,
+}
+
+impl Arc19Codec {
+    fn to_byte(self) -> Byte {
+        match self {
+            Self::Raw => 0x55,
+            Self::DagPb => 0x70,
+        }
+    }
+
+    fn from_byte(byte: Byte) -> Result<Self> {
+        match byte {
+            0x55 => Ok(Self::Raw),
+            0x70 => Ok(Self::DagPb),
+            _ => Err(format!("Unsupported ARC-19 multicodec: 0x{byte:02x}!").into()),
+        }
+    }
+}
+
+impl fmt::Display for Arc19Codec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Raw => write!(f, "raw"),
+            Self::DagPb => write!(f, "dag-pb"),
+        }
+    }
+}
+
+impl FromStr for Arc19Codec {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "raw" => Ok(Self::Raw),
+            "dag-pb" => Ok(Self::DagPb),
+            _ => Err(format!("Unsupported ARC-19 multicodec: '{s}'!").into()),
+        }
+    }
+}
+
+/// ## ARC-19 Template URL
+///
+/// An asset URL of the form `template-ipfs://{ipfscid:<version>:<codec>:reserve:sha2-256}`,
+/// optionally followed by a path, where the SHA-256 digest of the IPFS content is carried by the
+/// asset's reserve address. Since the reserve address may be changed by the asset's manager, this
+/// lets the asset's metadata be updated without changing its URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arc19TemplateUrl {
+    pub version: u8,
+    pub codec: Arc19Codec,
+    pub path: String,
+}
+
+impl Arc19TemplateUrl {
+    /// ## New
+    ///
+    /// Create a template URL, failing if the version is neither 0 nor 1, or if a version 0 CID is
+    /// paired with any codec other than `dag-pb`.
+    pub fn new(version: u8, codec: Arc19Codec, path: &str) -> Result<Self> {
+        match (version, codec) {
+            (0, Arc19Codec::DagPb) | (1, _) => Ok(Self {
+                version,
+                codec,
+                path: path.to_string(),
+            }),
+            (0, _) => Err("A version 0 CID must use the dag-pb multicodec!".into()),
+            _ => Err(format!("Unsupported CID version: {version}!").into()),
+        }
+    }
+
+    /// ## From CID
+    ///
+    /// Get the template URL and reserve address which together encode the given IPFS CID, which
+    /// must be either a base58 version 0 CID, or a base32 version 1 CID using a SHA-256 multihash.
+    pub fn from_cid(cid: &str) -> Result<(Self, AlgorandAddress)> {
+        let (version, codec, multihash) = if cid.starts_with("Qm") {
+            (0, Arc19Codec::DagPb, base58_decode(cid)?)
+        } else if let Some(encoded) = cid.strip_prefix(CID_V1_BASE32_PREFIX) {
+            let bytes = base32_decode(&encoded.to_uppercase())?;
+            match bytes.as_slice() {
+                [CID_V1, codec, multihash @ ..] => {
+                    (1, Arc19Codec::from_byte(*codec)?, multihash.to_vec())
+                },
+                _ => return Err(format!("Unsupported CID: {cid}!").into()),
+            }
+        } else {
+            return Err(format!("Unsupported CID: {cid}!").into());
+        };
+        match multihash.strip_prefix(&SHA2_256_MULTIHASH_PREFIX) {
+            Some(digest) if digest.len() == 32 => Ok((
+                Self::new(version, codec, "")?,
+                AlgorandAddress::from_bytes(digest)?,
+            )),
+            _ => Err(format!("CID {cid} does not use a SHA-256 multihash!").into()),
+        }
+    }
+
+    /// ## To CID
+    ///
+    /// Get the CID this template encodes given the asset's reserve address.
+    pub fn to_cid(&self, reserve_address: &AlgorandAddress) -> String {
+        let multihash = [
+            SHA2_256_MULTIHASH_PREFIX.to_vec(),
+            reserve_address.to_bytes(),
+        ]
+        .concat();
+        if self.version == 0 {
+            base58_encode(&multihash)
+        } else {
+            let bytes = [vec![CID_V1, self.codec.to_byte()], multihash].concat();
+            format!(
+                "{CID_V1_BASE32_PREFIX}{}",
+                base32_encode_with_no_padding(&bytes).to_lowercase()
+            )
+        }
+    }
+
+    /// ## Resolve
+    ///
+    /// Get the `ipfs://` URL this template points to given the asset's reserve address.
+    pub fn resolve(&self, reserve_address: &AlgorandAddress) -> String {
+        format!("{IPFS_PREFIX}{}{}", self.to_cid(reserve_address), self.path)
+    }
+}
+
+impl fmt::Display for Arc19TemplateUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{TEMPLATE_PREFIX}{}:{}:{RESERVE_FIELD}:{SHA2_256_HASH_TYPE}}}{}",
+            self.version, self.codec, self.path
+        )
+    }
+}
+
+impl FromStr for Arc19TemplateUrl {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (template, path) = s
+            .strip_prefix(TEMPLATE_PREFIX)
+            .and_then(|rest| rest.split_once('}'))
+            .ok_or_else(|| AlgorandError::Custom(format!("Not an ARC-19 template URL: {s}!")))?;
+        match template.split(':').collect::<Vec<&str>>().as_slice() {
+            [version, codec, RESERVE_FIELD, SHA2_256_HASH_TYPE] => Self::new(
+                version
+                    .parse()
+                    .map_err(|_| AlgorandError::Custom(format!("Unsupported CID version: {version}!")))?,
+                Arc19Codec::from_str(codec)?,
+                path,
+            ),
+            _ => Err(format!(
+                "ARC-19 template URL {s} must use the {RESERVE_FIELD} field and {SHA2_256_HASH_TYPE} hash!"
+            )
+            .into()),
+        }
+    }
+}
+
+impl AssetParameters {
+    /// ## Is ARC-19
+    ///
+    /// Whether these are the parameters of an ARC-19 asset, ie whether its URL is a template URL.
+    pub fn is_arc19(&self) -> bool {
+        self.asset_url
+            .as_ref()
+            .map(|url| url.starts_with(TEMPLATE_PREFIX))
+            .unwrap_or_default()
+    }
+
+    /// ## Resolve ARC-19 URL
+    ///
+    /// Get the `ipfs://` URL of an ARC-19 asset's metadata from its template URL and reserve
+    /// address.
+    pub fn resolve_arc19_url(&self) -> Result<String> {
+        match (&self.asset_url, &self.reserve_address) {
+            (Some(url), Some(reserve_address)) => {
+                Ok(Arc19TemplateUrl::from_str(url)?.resolve(reserve_address))
+            },
+            (None, _) => Err("Cannot resolve ARC-19 URL of an asset with no URL!".into()),
+            (_, None) => {
+                Err("Cannot resolve ARC-19 URL of an asset with no reserve address!".into())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_cid_v0() -> &'static str {
+        "Qmc9rnPbtNCBnVncAGo5AtfZymepPKNqnUNVTzmF87iVok"
+    }
+
+    fn get_sample_cid_v1() -> &'static str {
+        "bafybeiblnv3wmjvxenpvi2pms6fekdodp4yrpjjkrja7g6q22lz5mrhijq"
+    }
+
+    #[test]
+    fn should_get_template_url_and_reserve_from_cid_v0() {
+        let (template, reserve) = Arc19TemplateUrl::from_cid(get_sample_cid_v0()).unwrap();
+        assert_eq!(
+            template.to_string(),
+            "template-ipfs://{ipfscid:0:dag-pb:reserve:sha2-256}"
+        );
+        assert_eq!(
+            reserve.to_string(),
+            "ZU76CSK445KF7Y2ONSIVPAGGPC2JLDQRGQZRQUCYPZIFFHIOHMXUY32Q5E"
+        );
+        assert_eq!(template.to_cid(&reserve), get_sample_cid_v0());
+    }
+
+    #[test]
+    fn should_get_template_url_and_reserve_from_cid_v1() {
+        let (template, reserve) = Arc19TemplateUrl::from_cid(get_sample_cid_v1()).unwrap();
+        assert_eq!(
+            template.to_string(),
+            "template-ipfs://{ipfscid:1:dag-pb:reserve:sha2-256}"
+        );
+        assert_eq!(
+            reserve.to_string(),
+            "FNWXOZRGW4RV6VDJ5SLYURINYN7TCF5FFKFED432DLJPHVSE5BGC5RK5OQ"
+        );
+        assert_eq!(template.to_cid(&reserve), get_sample_cid_v1());
+    }
+
+    #[test]
+    fn should_encode_raw_codec_in_cid_v1() {
+        let (_, reserve) = Arc19TemplateUrl::from_cid(get_sample_cid_v0()).unwrap();
+        let template = Arc19TemplateUrl::new(1, Arc19Codec::Raw, "").unwrap();
+        let result = template.to_cid(&reserve);
+        let expected_result = "bafkreignh7qusxhhkrp6gttmsflybrtywsky4ejugmmfawd6kbjj2dr3f4";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_template_url_with_path() {
+        let url = "template-ipfs://{ipfscid:1:raw:reserve:sha2-256}/metadata.json#arc3";
+        let result = Arc19TemplateUrl::from_str(url).unwrap();
+        assert_eq!(result.version, 1);
+        assert_eq!(result.codec, Arc19Codec::Raw);
+        assert_eq!(result.path, "/metadata.json#arc3");
+        assert_eq!(result.to_string(), url);
+    }
+
+    #[test]
+    fn should_fail_to_parse_template_url_with_other_field() {
+        let url = "template-ipfs://{ipfscid:1:raw:manager:sha2-256}";
+        let expected_error =
+            format!("ARC-19 template URL {url} must use the reserve field and sha2-256 hash!");
+        match Arc19TemplateUrl::from_str(url) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_create_version_0_template_with_raw_codec() {
+        let expected_error = "A version 0 CID must use the dag-pb multicodec!";
+        match Arc19TemplateUrl::new(0, Arc19Codec::Raw, "") {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_resolve_arc19_url_of_asset_parameters() {
+        let (template, reserve) = Arc19TemplateUrl::from_cid(get_sample_cid_v1()).unwrap();
+        let params = AssetParameters::new(
+            None,
+            None,
+            Some(format!("{template}#arc3")),
+            None,
+            0,
+            false,
+            None,
+            None,
+            Some(reserve),
+            1,
+            None,
+        );
+        assert!(params.is_arc19());
+        assert!(params.is_arc3());
+        let result = params.resolve_arc19_url().unwrap();
+        let expected_result = format!("ipfs://{}#arc3", get_sample_cid_v1());
+        assert_eq!(result, expected_result);
+    }
+}
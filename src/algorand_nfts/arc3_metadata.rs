@@ -0,0 +1,332 @@
+use std::str::FromStr;
+
+use base64::decode as base64_decode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_errors::AlgorandError,
+    algorand_hash::AlgorandHash,
+    algorand_transactions::asset_parameters::AssetParameters,
+    algorand_types::{Byte, Bytes, Result},
+    crypto_utils::{sha256_hash_bytes, sha512_256_hash_bytes},
+};
+
+const ARC3_URL_SUFFIX: &str = "#arc3";
+const ARC3_ASSET_NAME: &str = "arc3";
+const ARC3_ASSET_NAME_SUFFIX: &str = "@arc3";
+const MAX_ASSET_URL_BYTES: usize = 96;
+
+/// ## ARC-3 Localization
+///
+/// Where to find localized versions of an ARC-3 asset's metadata, where `{locale}` in the URI is
+/// replaced by one of the given locales.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Arc3Localization {
+    pub uri: String,
+    pub default: String,
+    pub locales: Vec<String>,
+    pub integrity: Option<JsonValue>,
+}
+
+/// ## ARC-3 Metadata
+///
+/// The JSON metadata file of an ARC-3 NFT, which the asset's URL points to. Integrity fields are
+/// base64 encoded SHA-256 digests prefixed with `sha256-`, as per the subresource integrity spec.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Arc3Metadata {
+    pub name: Option<String>,
+    pub decimals: Option<u64>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub image_integrity: Option<String>,
+    pub image_mimetype: Option<String>,
+    pub background_color: Option<String>,
+    pub external_url: Option<String>,
+    pub external_url_integrity: Option<String>,
+    pub external_url_mimetype: Option<String>,
+    pub animation_url: Option<String>,
+    pub animation_url_integrity: Option<String>,
+    pub animation_url_mimetype: Option<String>,
+    pub properties: Option<JsonValue>,
+
+    /// Base64 encoded extra metadata, which is bound to the asset by its metadata hash.
+    pub extra_metadata: Option<String>,
+
+    pub localization: Option<Arc3Localization>,
+}
+
+impl FromStr for Arc3Metadata {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+}
+
+impl Arc3Metadata {
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    /// ## To Bytes
+    ///
+    /// Serialize the metadata into the contents of a JSON metadata file.
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// ## Integrity
+    ///
+    /// Get the integrity string of a file referenced by the metadata, eg its image.
+    pub fn integrity(file: &[Byte]) -> String {
+        format!("sha256-{}", base64::encode(sha256_hash_bytes(file)))
+    }
+
+    /// ## Hash Metadata File
+    ///
+    /// Calculate the `am` metadata hash of an asset from the exact bytes of its JSON metadata
+    /// file. This is the SHA-256 of the file, unless the metadata carries extra metadata, in which
+    /// case it's the SHA-512/256 of `arc0003/am`, followed by the SHA-512/256 of `arc0003/amj` and
+    /// the file, followed by the decoded extra metadata.
+    pub fn hash_metadata_file(file: &[Byte]) -> Result<AlgorandHash> {
+        let hash_bytes = match Self::from_bytes(file)?.extra_metadata {
+            None => sha256_hash_bytes(file),
+            Some(extra_metadata) => {
+                let json_hash = sha512_256_hash_bytes(&[b"arc0003/amj", file].concat());
+                sha512_256_hash_bytes(
+                    &[
+                        b"arc0003/am".as_slice(),
+                        &json_hash,
+                        &base64_decode(extra_metadata)?,
+                    ]
+                    .concat(),
+                )
+            },
+        };
+        AlgorandHash::from_slice(&hash_bytes)
+    }
+}
+
+impl AssetParameters {
+    /// ## Is ARC-3
+    ///
+    /// Whether these are the parameters of an ARC-3 asset, which is signalled either by the URL
+    /// ending in `#arc3`, or by the asset name being `arc3` or ending in `@arc3`.
+    pub fn is_arc3(&self) -> bool {
+        let url_has_suffix = self
+            .asset_url
+            .as_ref()
+            .map(|url| url.ends_with(ARC3_URL_SUFFIX))
+            .unwrap_or_default();
+        let name_has_suffix = self
+            .asset_name
+            .as_ref()
+            .map(|name| name == ARC3_ASSET_NAME || name.ends_with(ARC3_ASSET_NAME_SUFFIX))
+            .unwrap_or_default();
+        url_has_suffix || name_has_suffix
+    }
+
+    /// ## With ARC-3 Metadata
+    ///
+    /// Point these parameters at the given ARC-3 JSON metadata file hosted at the given URL,
+    /// appending `#arc3` to the URL unless the asset name already marks the asset as ARC-3, and
+    /// setting the metadata hash from the file. The result is validated before being returned.
+    pub fn with_arc3_metadata(mut self, url: &str, metadata_file: &[Byte]) -> Result<Self> {
+        self.asset_url = Some(url.to_string());
+        if !self.is_arc3() {
+            self.asset_url = Some(format!("{url}{ARC3_URL_SUFFIX}"));
+        };
+        self.metadata_hash = Some(Arc3Metadata::hash_metadata_file(metadata_file)?);
+        self.validate_arc3(metadata_file)?;
+        Ok(self)
+    }
+
+    /// ## Validate ARC-3
+    ///
+    /// Check these parameters describe an ARC-3 asset with the given JSON metadata file, failing
+    /// with every violation found.
+    pub fn validate_arc3(&self, metadata_file: &[Byte]) -> Result<()> {
+        let metadata = Arc3Metadata::from_bytes(metadata_file)?;
+        let mut violations = vec![];
+
+        match &self.asset_url {
+            None => violations.push("ARC-3 asset has no URL".to_string()),
+            Some(url) if url.len() > MAX_ASSET_URL_BYTES => violations.push(format!(
+                "Asset URL size of {} exceeds the maximum of {MAX_ASSET_URL_BYTES}",
+                url.len()
+            )),
+            Some(_) => {},
+        };
+
+        if !self.is_arc3() {
+            violations.push(format!(
+                "Asset URL does not end with '{ARC3_URL_SUFFIX}' and its name is neither '{ARC3_ASSET_NAME}' nor ends with '{ARC3_ASSET_NAME_SUFFIX}'"
+            ));
+        };
+
+        if let Some(decimals) = metadata.decimals {
+            if decimals != self.decimals {
+                violations.push(format!(
+                    "Metadata decimals of {decimals} do not match the asset's decimals of {}",
+                    self.decimals
+                ));
+            };
+        };
+
+        let expected_hash = Arc3Metadata::hash_metadata_file(metadata_file)?;
+        match self.metadata_hash {
+            Some(hash) if hash != expected_hash => violations.push(format!(
+                "Asset metadata hash {hash} does not match the metadata file's hash {expected_hash}"
+            )),
+            None if metadata.extra_metadata.is_some() => violations
+                .push("Asset has no metadata hash but its metadata has extra metadata".to_string()),
+            _ => {},
+        };
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(AlgorandError::ValidationErrors(violations))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_metadata_file() -> Bytes {
+        br#"{"name":"My Song","decimals":0,"description":"My first and best song!","image":"https://s3.amazonaws.com/your-bucket/song/cover/mysong.png"}"#.to_vec()
+    }
+
+    fn get_sample_asset_parameters() -> AssetParameters {
+        AssetParameters::new(
+            None,
+            Some("My Song".to_string()),
+            None,
+            None,
+            0,
+            false,
+            None,
+            None,
+            None,
+            1,
+            Some("SONG".to_string()),
+        )
+    }
+
+    #[test]
+    fn should_serialize_arc3_metadata_to_bytes() {
+        let metadata = Arc3Metadata {
+            name: Some("My Song".to_string()),
+            decimals: Some(0),
+            description: Some("My first and best song!".to_string()),
+            image: Some("https://s3.amazonaws.com/your-bucket/song/cover/mysong.png".to_string()),
+            ..Default::default()
+        };
+        let result = metadata.to_bytes().unwrap();
+        assert_eq!(result, get_sample_metadata_file());
+        assert_eq!(Arc3Metadata::from_bytes(&result).unwrap(), metadata);
+    }
+
+    #[test]
+    fn should_hash_metadata_file_with_sha256() {
+        let result = Arc3Metadata::hash_metadata_file(&get_sample_metadata_file()).unwrap();
+        let expected_result = "zNxBJOuoZkqAB5r31A7StKHdMkGxfTfRZyozfmpoc80=";
+        assert_eq!(result.to_string(), expected_result);
+    }
+
+    #[test]
+    fn should_hash_metadata_file_with_extra_metadata() {
+        let file = br#"{"name":"My Song","decimals":0,"extra_metadata":"AQID"}"#;
+        let result = Arc3Metadata::hash_metadata_file(file).unwrap();
+        let expected_result = "tSqu2HSPfvZeeZaRFEkhOYH5aCi9suCnp72Fnz6aVMs=";
+        assert_eq!(result.to_string(), expected_result);
+    }
+
+    #[test]
+    fn should_get_integrity_of_file() {
+        let result = Arc3Metadata::integrity(b"abc");
+        let expected_result = "sha256-ungWv48Bz+pBQUDeXa4iI7ADYaOWF3qctBD/YfIAFa0=";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_detect_arc3_assets() {
+        let mut params = get_sample_asset_parameters();
+        assert!(!params.is_arc3());
+        params.asset_name = Some("My Song@arc3".to_string());
+        assert!(params.is_arc3());
+        params.asset_name = None;
+        params.asset_url =
+            Some("ipfs://QmZ456QZ2busGXMpJPdVDWQyYCnKGvLKTf4cthkKpwSKMf#arc3".to_string());
+        assert!(params.is_arc3());
+    }
+
+    #[test]
+    fn should_set_arc3_metadata_on_asset_parameters() {
+        let url = "https://example.com/mysong.json";
+        let result = get_sample_asset_parameters()
+            .with_arc3_metadata(url, &get_sample_metadata_file())
+            .unwrap();
+        assert_eq!(
+            result.asset_url,
+            Some("https://example.com/mysong.json#arc3".to_string())
+        );
+        assert_eq!(
+            result.metadata_hash.unwrap().to_string(),
+            "zNxBJOuoZkqAB5r31A7StKHdMkGxfTfRZyozfmpoc80="
+        );
+    }
+
+    #[test]
+    fn should_not_append_url_suffix_when_name_marks_asset_as_arc3() {
+        let mut params = get_sample_asset_parameters();
+        params.asset_name = Some("arc3".to_string());
+        let url = "https://example.com/mysong.json";
+        let result = params
+            .with_arc3_metadata(url, &get_sample_metadata_file())
+            .unwrap();
+        assert_eq!(result.asset_url, Some(url.to_string()));
+    }
+
+    #[test]
+    fn should_collect_arc3_violations() {
+        let mut params = get_sample_asset_parameters();
+        params.asset_url = Some("https://example.com/mysong.json".to_string());
+        params.decimals = 2;
+        params.metadata_hash = Some(AlgorandHash::default());
+        let expected_violations = vec![
+            "Asset URL does not end with '#arc3' and its name is neither 'arc3' nor ends with '@arc3'".to_string(),
+            "Metadata decimals of 0 do not match the asset's decimals of 2".to_string(),
+            "Asset metadata hash AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA= does not match the metadata file's hash zNxBJOuoZkqAB5r31A7StKHdMkGxfTfRZyozfmpoc80=".to_string(),
+        ];
+        match params.validate_arc3(&get_sample_metadata_file()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::ValidationErrors(violations)) => {
+                assert_eq!(violations, expected_violations)
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_require_metadata_hash_when_extra_metadata_present() {
+        let mut params = get_sample_asset_parameters();
+        params.asset_url = Some("https://example.com/mysong.json#arc3".to_string());
+        let file = br#"{"name":"My Song","extra_metadata":"AQID"}"#;
+        let expected_violations =
+            vec!["Asset has no metadata hash but its metadata has extra metadata".to_string()];
+        match params.validate_arc3(file) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::ValidationErrors(violations)) => {
+                assert_eq!(violations, expected_violations)
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_transactions::{
+        transaction::AlgorandTransaction,
+        transaction_type::AlgorandTransactionType,
+    },
+    algorand_types::{Byte, Bytes, Result},
+};
+
+const ARC69_STANDARD: &str = "arc69";
+
+/// ## ARC-69 Attribute
+///
+/// A trait of an ARC-69 NFT, in the style of OpenSea attributes.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Arc69Attribute {
+    pub trait_type: String,
+    pub value: JsonValue,
+    pub display_type: Option<String>,
+}
+
+/// ## ARC-69 Metadata
+///
+/// The metadata of an ARC-69 NFT, which lives as JSON in the note field of the asset's most
+/// recent asset configuration transaction.
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Arc69Metadata {
+    pub standard: String,
+    pub description: Option<String>,
+    pub external_url: Option<String>,
+    pub media_url: Option<String>,
+    pub properties: Option<JsonValue>,
+    pub mime_type: Option<String>,
+    pub attributes: Option<Vec<Arc69Attribute>>,
+}
+
+impl Default for Arc69Metadata {
+    fn default() -> Self {
+        Self {
+            standard: ARC69_STANDARD.to_string(),
+            description: None,
+            external_url: None,
+            media_url: None,
+            properties: None,
+            mime_type: None,
+            attributes: None,
+        }
+    }
+}
+
+impl Arc69Metadata {
+    /// ## From Note
+    ///
+    /// Parse ARC-69 metadata from a transaction note, failing if it's not JSON declaring the
+    /// `arc69` standard.
+    pub fn from_note(note: &[Byte]) -> Result<Self> {
+        let metadata: Self = serde_json::from_slice(note)?;
+        if metadata.standard == ARC69_STANDARD {
+            Ok(metadata)
+        } else {
+            Err(format!(
+                "Expected ARC-69 metadata standard '{ARC69_STANDARD}', got '{}'!",
+                metadata.standard
+            )
+            .into())
+        }
+    }
+
+    /// ## To Note
+    ///
+    /// Serialize the metadata into the note of an asset configuration transaction.
+    pub fn to_note(&self) -> Result<Bytes> {
+        Ok(serde_json::to_vec(self)?)
+    }
+
+    /// ## From Transaction
+    ///
+    /// Parse ARC-69 metadata from the note of an asset configuration transaction.
+    pub fn from_transaction(tx: &AlgorandTransaction) -> Result<Self> {
+        match (&tx.txn_type, &tx.note) {
+            (Some(AlgorandTransactionType::AssetConfiguration), Some(note)) => {
+                Self::from_note(note)
+            },
+            (Some(AlgorandTransactionType::AssetConfiguration), None) => {
+                Err("Cannot get ARC-69 metadata from a transaction with no note!".into())
+            },
+            _ => Err(
+                "Cannot get ARC-69 metadata from a transaction which is not an asset configuration!"
+                    .into(),
+            ),
+        }
+    }
+
+    /// ## Latest From Transactions
+    ///
+    /// Get the given asset's current ARC-69 metadata from transactions given in the order they
+    /// were confirmed. This is the metadata of the latest configuration transaction of that asset
+    /// which carries valid ARC-69 metadata, if any.
+    pub fn latest_from_transactions(asset_id: u64, txs: &[AlgorandTransaction]) -> Option<Self> {
+        txs.iter()
+            .rev()
+            .filter(|tx| tx.configured_asset_id() == Some(asset_id))
+            .find_map(|tx| Self::from_transaction(tx).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::{
+        algorand_errors::AlgorandError,
+        algorand_transactions::test_utils::get_sample_txs_n,
+    };
+
+    #[test]
+    fn should_get_arc69_metadata_from_transaction() {
+        let tx = get_sample_txs_n(0).0[0].clone();
+        let result = Arc69Metadata::from_transaction(&tx).unwrap();
+        let expected_result = Arc69Metadata {
+            mime_type: Some("image/jpeg".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_get_arc69_metadata_with_properties_from_transaction() {
+        let tx = get_sample_txs_n(1).0[0].clone();
+        let result = Arc69Metadata::from_transaction(&tx).unwrap();
+        assert_eq!(
+            result.external_url,
+            Some("https://algopatties.com".to_string())
+        );
+        assert_eq!(result.properties.unwrap()["Patty"], json!("Patty"));
+    }
+
+    #[test]
+    fn should_get_latest_arc69_metadata_from_transactions() {
+        let txs = get_sample_txs_n(1).0;
+        let result = Arc69Metadata::latest_from_transactions(637824870, &txs).unwrap();
+        assert_eq!(result.mime_type, Some("image/png".to_string()));
+        assert_eq!(
+            result.external_url,
+            Some("https://ipfs.io/ipfs/QmSqzynE5wjRRdZmPah7KVaWfN6SNoBpnZ1FAhKY32mopx".to_string())
+        );
+    }
+
+    #[test]
+    fn should_only_get_latest_arc69_metadata_of_given_asset() {
+        let txs = get_sample_txs_n(1).0;
+        let result = Arc69Metadata::latest_from_transactions(637824800, &txs).unwrap();
+        assert_eq!(
+            result.external_url,
+            Some("https://algopatties.com".to_string())
+        );
+        assert_eq!(Arc69Metadata::latest_from_transactions(1337, &txs), None);
+    }
+
+    #[test]
+    fn should_round_trip_arc69_metadata_via_note() {
+        let metadata = Arc69Metadata {
+            description: Some("A test NFT".to_string()),
+            attributes: Some(vec![Arc69Attribute {
+                trait_type: "Background".to_string(),
+                value: json!("Red"),
+                display_type: None,
+            }]),
+            ..Default::default()
+        };
+        let note = metadata.to_note().unwrap();
+        let expected_note = r#"{"standard":"arc69","description":"A test NFT","attributes":[{"trait_type":"Background","value":"Red"}]}"#;
+        assert_eq!(String::from_utf8(note.clone()).unwrap(), expected_note);
+        assert_eq!(Arc69Metadata::from_note(&note).unwrap(), metadata);
+    }
+
+    #[test]
+    fn should_fail_to_get_arc69_metadata_of_other_standard() {
+        let expected_error = "Expected ARC-69 metadata standard 'arc69', got 'arc3'!";
+        match Arc69Metadata::from_note(br#"{"standard":"arc3"}"#) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_get_arc69_metadata_from_non_acfg_transaction() {
+        let tx = get_sample_txs_n(4).0[6].clone();
+        let expected_error =
+            "Cannot get ARC-69 metadata from a transaction which is not an asset configuration!";
+        match Arc69Metadata::from_transaction(&tx) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
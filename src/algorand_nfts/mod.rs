@@ -0,0 +1,3 @@
+pub(crate) mod arc19_template_url;
+pub(crate) mod arc3_metadata;
+pub(crate) mod arc69_metadata;
@@ -0,0 +1,264 @@
+use std::collections::BTreeMap;
+
+use base64::{decode as base64_decode, encode as base64_encode};
+use serde::{Deserialize, Serialize};
+use serde_bytes::ByteBuf;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_state_proofs::sumhash::sumhash512_hash_bytes,
+    algorand_transactions::transactions_merkle_tree::AlgorandTransactionsMerkleTree,
+    algorand_types::{Byte, Bytes, Result},
+    crypto_utils::{sha256_hash_bytes, sha512_256_hash_bytes},
+    predicates::{is_empty_vec, is_zero},
+};
+
+const MERKLE_ARRAY_NODE_PREFIX: [u8; 2] = *b"MA";
+pub(crate) const MAX_ENCODED_TREE_DEPTH: u64 = 16;
+
+/// ## Algorand Hash Factory
+///
+/// The hash function a Merkle array is built with, where `0` is SHA-512/256, `1` is the
+/// subset-sum hash and `2` is SHA-256.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandHashFactory {
+    #[serde(rename = "t", default, skip_serializing_if = "is_zero")]
+    pub hash_type: u64,
+}
+
+impl AlgorandHashFactory {
+    pub(crate) fn hash_bytes(&self, bytes: &[Byte]) -> Result<Bytes> {
+        match self.hash_type {
+            0 => Ok(sha512_256_hash_bytes(bytes)),
+            1 => Ok(sumhash512_hash_bytes(bytes)),
+            2 => Ok(sha256_hash_bytes(bytes)),
+            hash_type => Err(format!("Unknown Merkle array hash type: {hash_type}!").into()),
+        }
+    }
+
+    fn get_digest_size(&self) -> Result<usize> {
+        match self.hash_type {
+            0 | 2 => Ok(32),
+            1 => Ok(64),
+            hash_type => Err(format!("Unknown Merkle array hash type: {hash_type}!").into()),
+        }
+    }
+}
+
+pub(crate) fn is_default_hash_factory(hash_factory: &AlgorandHashFactory) -> bool {
+    hash_factory.hash_type == 0
+}
+
+/// ## Merkle Array Proof
+///
+/// A proof of some of the elements of a Merkle array, made up of the hashes needed to rebuild
+/// the path from each of those elements to the root, in the order they are needed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleArrayProof {
+    #[serde(
+        rename = "hsh",
+        default,
+        skip_serializing_if = "is_default_hash_factory"
+    )]
+    pub hash_factory: AlgorandHashFactory,
+
+    #[serde(rename = "pth", default, skip_serializing_if = "Vec::is_empty")]
+    pub path: Vec<ByteBuf>,
+
+    #[serde(rename = "td", default, skip_serializing_if = "is_zero")]
+    pub tree_depth: u64,
+}
+
+impl MerkleArrayProof {
+    /// ## To Fixed Length Bytes
+    ///
+    /// The fixed length representation of a proof of a single element, used when the proof is
+    /// itself committed to, as it is in a state proof's signature commitment. It's the tree
+    /// depth, followed by a zeroed digest for each level the tree is shallower than the maximum,
+    /// followed by the path.
+    pub(crate) fn to_fixed_length_bytes(&self) -> Result<Bytes> {
+        if self.tree_depth > MAX_ENCODED_TREE_DEPTH {
+            return Err(
+                format!("Merkle array tree depth {} is too large!", self.tree_depth).into(),
+            );
+        };
+        let zero_digests = vec![
+            0u8;
+            self.hash_factory.get_digest_size()?
+                * (MAX_ENCODED_TREE_DEPTH - self.tree_depth) as usize
+        ];
+        Ok([
+            vec![self.tree_depth as Byte],
+            zero_digests,
+            self.path.iter().flat_map(|hash| hash.to_vec()).collect(),
+        ]
+        .concat())
+    }
+
+    fn hash_node(&self, left: &[Byte], right: &[Byte]) -> Result<Bytes> {
+        self.hash_factory
+            .hash_bytes(&[&MERKLE_ARRAY_NODE_PREFIX[..], left, right].concat())
+    }
+
+    // NOTE: Each element is keyed by its index in the vector, and is the prefixed representation
+    // of it which is hashed to get its leaf. Since a vector commitment places each element at the
+    // bit-reversed index of its leaf, siblings are taken from the elements being proven wherever
+    // possible, and from the proof's path otherwise.
+    pub(crate) fn to_vector_commitment_root(
+        &self,
+        elements: &BTreeMap<u64, Bytes>,
+    ) -> Result<Option<Bytes>> {
+        if self.tree_depth >= u64::BITS as u64 {
+            return Err(
+                format!("Merkle array tree depth {} is too large!", self.tree_depth).into(),
+            );
+        };
+        if elements.is_empty() {
+            return if self.path.is_empty() {
+                Ok(None)
+            } else {
+                Err("Non-empty Merkle array proof for an empty set of elements!".into())
+            };
+        };
+        let mut layer = elements
+            .iter()
+            .map(|(index, element)| {
+                if index >> self.tree_depth != 0 {
+                    return Err(format!(
+                        "Vector commitment index {index} is out of range for a tree depth of {}!",
+                        self.tree_depth
+                    )
+                    .into());
+                };
+                Ok((
+                    AlgorandTransactionsMerkleTree::reverse_bits(
+                        *index as usize,
+                        self.tree_depth as usize,
+                    ) as u64,
+                    self.hash_factory.hash_bytes(element)?,
+                ))
+            })
+            .collect::<Result<Vec<(u64, Bytes)>>>()?;
+        layer.sort_by_key(|(position, _)| *position);
+        let mut path = self.path.iter();
+        let mut depth = 0;
+        while path.len() > 0 || layer.len() > 1 {
+            if depth >= MAX_ENCODED_TREE_DEPTH {
+                return Err("Merkle array proof path is too long!".into());
+            };
+            let mut next_layer = vec![];
+            let mut i = 0;
+            while i < layer.len() {
+                let (position, hash) = &layer[i];
+                let sibling = if i + 1 < layer.len() && layer[i + 1].0 == position ^ 1 {
+                    i += 1;
+                    layer[i].1.clone()
+                } else {
+                    match path.next() {
+                        Some(hash) => hash.to_vec(),
+                        None => return Err("Merkle array proof path is too short!".into()),
+                    }
+                };
+                next_layer.push((
+                    position / 2,
+                    if position & 1 == 0 {
+                        self.hash_node(hash, &sibling)?
+                    } else {
+                        self.hash_node(&sibling, hash)?
+                    },
+                ));
+                i += 1;
+            }
+            layer = next_layer;
+            depth += 1;
+        }
+        if layer[0].0 == 0 {
+            Ok(Some(layer[0].1.clone()))
+        } else {
+            Err("Merkle array proof does not reach the root!".into())
+        }
+    }
+
+    /// ## Verify Vector Commitment
+    ///
+    /// Verify the proof of the given elements, keyed by their indices, against the root of a
+    /// vector commitment. Proofs of no elements are trivially valid.
+    pub(crate) fn verify_vector_commitment(
+        &self,
+        root: &[Byte],
+        elements: &BTreeMap<u64, Bytes>,
+    ) -> Result<()> {
+        match self.to_vector_commitment_root(elements)? {
+            Some(hash) if hash != root => Err("Merkle array proof does not match the root!".into()),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn from_json(json: &MerkleArrayProofJson) -> Result<Self> {
+        Ok(Self {
+            hash_factory: AlgorandHashFactory {
+                hash_type: json
+                    .hash_factory
+                    .as_ref()
+                    .and_then(|hash_factory| hash_factory.hash_type)
+                    .unwrap_or_default(),
+            },
+            path: match &json.path {
+                Some(path) => path
+                    .iter()
+                    .map(|base64_str| Ok(ByteBuf::from(base64_decode(base64_str)?)))
+                    .collect::<Result<Vec<ByteBuf>>>()?,
+                None => vec![],
+            },
+            tree_depth: json.tree_depth.unwrap_or_default(),
+        })
+    }
+
+    pub fn to_json(&self) -> MerkleArrayProofJson {
+        MerkleArrayProofJson {
+            hash_factory: Some(AlgorandHashFactoryJson {
+                hash_type: Some(self.hash_factory.hash_type),
+            }),
+            path: Some(self.path.iter().map(base64_encode).collect()),
+            tree_depth: Some(self.tree_depth),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandHashFactoryJson {
+    #[serde(rename = "hash-type")]
+    pub hash_type: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleArrayProofJson {
+    #[serde(rename = "hash-factory")]
+    pub hash_factory: Option<AlgorandHashFactoryJson>,
+
+    #[serde(skip_serializing_if = "is_empty_vec")]
+    pub path: Option<Vec<String>>,
+
+    #[serde(rename = "tree-depth")]
+    pub tree_depth: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_omit_default_hash_factory_when_encoding_to_msg_pack() {
+        let proof = MerkleArrayProof {
+            tree_depth: 1,
+            path: vec![ByteBuf::from(vec![0xff])],
+            ..Default::default()
+        };
+        let bytes = rmp_serde::to_vec_named(&proof).unwrap();
+        assert_eq!(hex::encode(&bytes), "82a370746891c401ffa2746401");
+        let result: MerkleArrayProof = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(result, proof);
+    }
+}
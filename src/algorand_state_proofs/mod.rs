@@ -0,0 +1,5 @@
+pub(crate) mod merkle_array_proof;
+pub(crate) mod state_proof;
+pub(crate) mod state_proof_message;
+pub(crate) mod state_proof_verifier;
+pub(crate) mod sumhash;
@@ -0,0 +1,489 @@
+use std::collections::BTreeMap;
+
+use base64::{decode as base64_decode, encode as base64_encode};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_falcon::{
+        falcon_public_key::FalconPublicKey,
+        falcon_signature::FalconCompressedSignature,
+    },
+    algorand_state_proofs::merkle_array_proof::{MerkleArrayProof, MerkleArrayProofJson},
+    algorand_types::{Byte, Bytes, Result},
+    predicates::{is_empty_vec, is_zero},
+};
+
+const STATE_PROOF_PARTICIPANT_PREFIX: [u8; 3] = *b"spp";
+const STATE_PROOF_SIGNATURE_PREFIX: [u8; 3] = *b"sps";
+const MERKLE_SIGNATURE_EPHEMERAL_KEY_PREFIX: [u8; 2] = *b"KP";
+const MERKLE_SIGNATURE_SCHEME_ID: u16 = 0;
+
+fn maybe_base64_decode(s: &Option<String>) -> Result<Bytes> {
+    match s {
+        Some(base64_str) => Ok(base64_decode(base64_str)?),
+        None => Ok(vec![]),
+    }
+}
+
+fn maybe_get_proof(json: &Option<MerkleArrayProofJson>) -> Result<MerkleArrayProof> {
+    match json {
+        Some(json) => MerkleArrayProof::from_json(json),
+        None => Ok(MerkleArrayProof::default()),
+    }
+}
+
+/// ## Algorand Falcon Verifying Key
+///
+/// The Falcon public key a participant's ephemeral signature is verified with.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandFalconVerifyingKey {
+    #[serde(
+        rename = "k",
+        with = "serde_bytes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub public_key: Bytes,
+}
+
+/// ## Algorand Merkle Signature
+///
+/// A Falcon signature made with one of a participant's ephemeral keys, along with that key and a
+/// proof of its inclusion in the participant's commitment to all of their ephemeral keys.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandMerkleSignature {
+    #[serde(rename = "idx", default, skip_serializing_if = "is_zero")]
+    pub vector_commitment_index: u64,
+
+    #[serde(rename = "prf")]
+    pub proof: MerkleArrayProof,
+
+    #[serde(
+        rename = "sig",
+        with = "serde_bytes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub falcon_signature: Bytes,
+
+    #[serde(rename = "vkey")]
+    pub verifying_key: AlgorandFalconVerifyingKey,
+}
+
+/// ## Algorand Sig Slot Commit
+///
+/// A signature revealed in a state proof, along with the total weight of the signers which come
+/// before it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandSigSlotCommit {
+    #[serde(rename = "l", default, skip_serializing_if = "is_zero")]
+    pub lower_sig_weight: u64,
+
+    #[serde(rename = "s")]
+    pub signature: AlgorandMerkleSignature,
+}
+
+/// ## Merkle Signature Verifier
+///
+/// A participant's commitment to their ephemeral keys, each of which is valid for `key_lifetime`
+/// rounds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleSignatureVerifier {
+    #[serde(
+        rename = "cmt",
+        with = "serde_bytes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub commitment: Bytes,
+
+    #[serde(rename = "lf", default, skip_serializing_if = "is_zero")]
+    pub key_lifetime: u64,
+}
+
+/// ## Algorand Participant
+///
+/// An account which may sign a state proof, along with its weight, ie its online stake.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandParticipant {
+    #[serde(rename = "p")]
+    pub verifier: MerkleSignatureVerifier,
+
+    #[serde(rename = "w", default, skip_serializing_if = "is_zero")]
+    pub weight: u64,
+}
+
+/// ## Algorand Reveal
+///
+/// A participant and their signature, as revealed in a state proof.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandReveal {
+    #[serde(rename = "p")]
+    pub participant: AlgorandParticipant,
+
+    #[serde(rename = "s")]
+    pub sig_slot: AlgorandSigSlotCommit,
+}
+
+/// ## State Proof
+///
+/// A compact proof that participants holding enough of the online stake signed a state proof
+/// message. It commits to every participant's signature, revealing only those at the positions
+/// chosen by the proof's coin flips, along with proofs of those signatures and participants.
+/// Reveals are keyed by position in the voters commitment.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateProof {
+    #[serde(rename = "P")]
+    pub part_proofs: MerkleArrayProof,
+
+    #[serde(rename = "S")]
+    pub sig_proofs: MerkleArrayProof,
+
+    #[serde(
+        rename = "c",
+        with = "serde_bytes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub sig_commit: Bytes,
+
+    #[serde(rename = "pr", default, skip_serializing_if = "Vec::is_empty")]
+    pub positions_to_reveal: Vec<u64>,
+
+    #[serde(rename = "r", default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub reveals: BTreeMap<u64, AlgorandReveal>,
+
+    #[serde(rename = "v", default, skip_serializing_if = "is_zero")]
+    pub salt_version: u64,
+
+    #[serde(rename = "w", default, skip_serializing_if = "is_zero")]
+    pub signed_weight: u64,
+}
+
+impl AlgorandFalconVerifyingKey {
+    /// ## Verify Bytes
+    ///
+    /// Verify a compressed deterministic Falcon-1024 signature of the given message under this
+    /// public key.
+    pub fn verify_bytes(&self, message: &[Byte], falcon_signature: &[Byte]) -> Result<()> {
+        FalconCompressedSignature::from_bytes(falcon_signature)?
+            .verify(&FalconPublicKey::from_bytes(&self.public_key)?, message)
+    }
+}
+
+impl AlgorandMerkleSignature {
+    /// ## Get Salt Version
+    ///
+    /// Get the salt version of the Falcon signature, which is its second byte.
+    pub fn get_salt_version(&self) -> Byte {
+        self.falcon_signature.get(1).copied().unwrap_or_default()
+    }
+
+    // NOTE: The signature is converted to Falcon's fixed length CT format so that every
+    // signature slot in a state proof's signature commitment is the same size.
+    fn to_fixed_length_bytes(&self) -> Result<Bytes> {
+        Ok([
+            MERKLE_SIGNATURE_SCHEME_ID.to_le_bytes().to_vec(),
+            FalconCompressedSignature::from_bytes(&self.falcon_signature)?.to_ct_bytes()?,
+            self.verifying_key.public_key.clone(),
+            self.vector_commitment_index.to_le_bytes().to_vec(),
+            self.proof.to_fixed_length_bytes()?,
+        ]
+        .concat())
+    }
+}
+
+impl AlgorandSigSlotCommit {
+    /// ## To Hashable Bytes
+    ///
+    /// The `sps` prefixed representation of the slot which is committed to in a state proof's
+    /// signature commitment. Slots whose signature can't be encoded, such as those of
+    /// participants who didn't sign, are represented by the prefix alone.
+    pub(crate) fn to_hashable_bytes(&self) -> Bytes {
+        match self.signature.to_fixed_length_bytes() {
+            Ok(signature_bytes) => [
+                STATE_PROOF_SIGNATURE_PREFIX.to_vec(),
+                self.lower_sig_weight.to_le_bytes().to_vec(),
+                signature_bytes,
+            ]
+            .concat(),
+            Err(_) => STATE_PROOF_SIGNATURE_PREFIX.to_vec(),
+        }
+    }
+}
+
+impl MerkleSignatureVerifier {
+    /// ## Verify Ephemeral Key
+    ///
+    /// Verify that the signature's Falcon key is the ephemeral key the participant committed to
+    /// for the given round.
+    pub fn verify_ephemeral_key(
+        &self,
+        round: u64,
+        signature: &AlgorandMerkleSignature,
+    ) -> Result<()> {
+        if self.key_lifetime == 0 {
+            return Err("Merkle signature verifier has a key lifetime of zero!".into());
+        };
+        let ephemeral_key = [
+            MERKLE_SIGNATURE_EPHEMERAL_KEY_PREFIX.to_vec(),
+            MERKLE_SIGNATURE_SCHEME_ID.to_le_bytes().to_vec(),
+            round.to_le_bytes().to_vec(),
+            signature.verifying_key.public_key.clone(),
+        ]
+        .concat();
+        signature.proof.verify_vector_commitment(
+            &self.commitment,
+            &BTreeMap::from([(signature.vector_commitment_index, ephemeral_key)]),
+        )
+    }
+}
+
+impl AlgorandParticipant {
+    /// ## To Hashable Bytes
+    ///
+    /// The `spp` prefixed representation of the participant which is committed to in a voters
+    /// commitment.
+    pub(crate) fn to_hashable_bytes(&self) -> Bytes {
+        [
+            STATE_PROOF_PARTICIPANT_PREFIX.to_vec(),
+            self.weight.to_le_bytes().to_vec(),
+            self.verifier.key_lifetime.to_le_bytes().to_vec(),
+            self.verifier.commitment.clone(),
+        ]
+        .concat()
+    }
+}
+
+impl StateProof {
+    pub fn from_json(json: &StateProofJson) -> Result<Self> {
+        Ok(Self {
+            part_proofs: maybe_get_proof(&json.part_proofs)?,
+            sig_proofs: maybe_get_proof(&json.sig_proofs)?,
+            sig_commit: maybe_base64_decode(&json.sig_commit)?,
+            positions_to_reveal: json.positions_to_reveal.clone().unwrap_or_default(),
+            reveals: json
+                .reveals
+                .iter()
+                .flatten()
+                .map(|reveal| {
+                    Ok((
+                        reveal.position.unwrap_or_default(),
+                        AlgorandReveal::from_json(reveal)?,
+                    ))
+                })
+                .collect::<Result<BTreeMap<u64, AlgorandReveal>>>()?,
+            salt_version: json.salt_version.unwrap_or_default(),
+            signed_weight: json.signed_weight.unwrap_or_default(),
+        })
+    }
+
+    pub fn to_json(&self) -> StateProofJson {
+        StateProofJson {
+            part_proofs: Some(self.part_proofs.to_json()),
+            positions_to_reveal: Some(self.positions_to_reveal.clone()),
+            reveals: Some(
+                self.reveals
+                    .iter()
+                    .map(|(position, reveal)| reveal.to_json(*position))
+                    .collect(),
+            ),
+            salt_version: Some(self.salt_version),
+            sig_commit: Some(base64_encode(&self.sig_commit)),
+            sig_proofs: Some(self.sig_proofs.to_json()),
+            signed_weight: Some(self.signed_weight),
+        }
+    }
+}
+
+impl AlgorandReveal {
+    pub fn from_json(json: &AlgorandRevealJson) -> Result<Self> {
+        let participant = json.participant.clone().unwrap_or_default();
+        let verifier = participant.verifier.unwrap_or_default();
+        let sig_slot = json.sig_slot.clone().unwrap_or_default();
+        let signature = sig_slot.signature.unwrap_or_default();
+        Ok(Self {
+            participant: AlgorandParticipant {
+                verifier: MerkleSignatureVerifier {
+                    commitment: maybe_base64_decode(&verifier.commitment)?,
+                    key_lifetime: verifier.key_lifetime.unwrap_or_default(),
+                },
+                weight: participant.weight.unwrap_or_default(),
+            },
+            sig_slot: AlgorandSigSlotCommit {
+                lower_sig_weight: sig_slot.lower_sig_weight.unwrap_or_default(),
+                signature: AlgorandMerkleSignature {
+                    vector_commitment_index: signature.merkle_array_index.unwrap_or_default(),
+                    proof: maybe_get_proof(&signature.proof)?,
+                    falcon_signature: maybe_base64_decode(&signature.falcon_signature)?,
+                    verifying_key: AlgorandFalconVerifyingKey {
+                        public_key: maybe_base64_decode(&signature.verifying_key)?,
+                    },
+                },
+            },
+        })
+    }
+
+    pub fn to_json(&self, position: u64) -> AlgorandRevealJson {
+        let signature = &self.sig_slot.signature;
+        AlgorandRevealJson {
+            participant: Some(AlgorandParticipantJson {
+                verifier: Some(MerkleSignatureVerifierJson {
+                    commitment: Some(base64_encode(&self.participant.verifier.commitment)),
+                    key_lifetime: Some(self.participant.verifier.key_lifetime),
+                }),
+                weight: Some(self.participant.weight),
+            }),
+            position: Some(position),
+            sig_slot: Some(AlgorandSigSlotCommitJson {
+                lower_sig_weight: Some(self.sig_slot.lower_sig_weight),
+                signature: Some(AlgorandMerkleSignatureJson {
+                    falcon_signature: Some(base64_encode(&signature.falcon_signature)),
+                    merkle_array_index: Some(signature.vector_commitment_index),
+                    proof: Some(signature.proof.to_json()),
+                    verifying_key: Some(base64_encode(&signature.verifying_key.public_key)),
+                }),
+            }),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandMerkleSignatureJson {
+    #[serde(rename = "falcon-signature")]
+    pub falcon_signature: Option<String>,
+
+    #[serde(rename = "merkle-array-index")]
+    pub merkle_array_index: Option<u64>,
+
+    pub proof: Option<MerkleArrayProofJson>,
+
+    #[serde(rename = "verifying-key")]
+    pub verifying_key: Option<String>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandSigSlotCommitJson {
+    #[serde(rename = "lower-sig-weight")]
+    pub lower_sig_weight: Option<u64>,
+
+    pub signature: Option<AlgorandMerkleSignatureJson>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleSignatureVerifierJson {
+    pub commitment: Option<String>,
+
+    #[serde(rename = "key-lifetime")]
+    pub key_lifetime: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandParticipantJson {
+    pub verifier: Option<MerkleSignatureVerifierJson>,
+
+    pub weight: Option<u64>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandRevealJson {
+    pub participant: Option<AlgorandParticipantJson>,
+
+    pub position: Option<u64>,
+
+    #[serde(rename = "sig-slot")]
+    pub sig_slot: Option<AlgorandSigSlotCommitJson>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateProofJson {
+    #[serde(rename = "part-proofs")]
+    pub part_proofs: Option<MerkleArrayProofJson>,
+
+    #[serde(rename = "positions-to-reveal", skip_serializing_if = "is_empty_vec")]
+    pub positions_to_reveal: Option<Vec<u64>>,
+
+    #[serde(skip_serializing_if = "is_empty_vec")]
+    pub reveals: Option<Vec<AlgorandRevealJson>>,
+
+    #[serde(rename = "salt-version")]
+    pub salt_version: Option<u64>,
+
+    #[serde(rename = "sig-commit")]
+    pub sig_commit: Option<String>,
+
+    #[serde(rename = "sig-proofs")]
+    pub sig_proofs: Option<MerkleArrayProofJson>,
+
+    #[serde(rename = "signed-weight")]
+    pub signed_weight: Option<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_blocks::test_utils::get_sample_state_proof_tx,
+        algorand_transactions::transaction::AlgorandTransaction,
+    };
+
+    #[test]
+    fn should_get_state_proof_and_message_from_tx_json() {
+        let tx = get_sample_state_proof_tx();
+        let message = tx.state_proof_message.unwrap();
+        assert_eq!(message.first_attested_round, 23595265);
+        assert_eq!(message.last_attested_round, 23595520);
+        assert_eq!(message.ln_proven_weight, 2240612);
+        assert_eq!(
+            message.get_block_headers_commitment().unwrap().to_string(),
+            "vR/kHzLC5+CWwOZiL3iMl7RaWj+QeZn0mr+qQWV6a1s="
+        );
+        assert_eq!(message.voters_commitment.len(), 64);
+        let state_proof = tx.state_proof.unwrap();
+        assert_eq!(state_proof.signed_weight, 2146717432601858);
+        assert_eq!(state_proof.part_proofs.tree_depth, 9);
+        assert_eq!(state_proof.part_proofs.hash_factory.hash_type, 1);
+        let reveal = &state_proof.reveals[&1];
+        assert_eq!(reveal.participant.weight, 96925792273583);
+        assert_eq!(reveal.participant.verifier.key_lifetime, 256);
+        assert_eq!(reveal.sig_slot.lower_sig_weight, 99502994378912);
+        assert_eq!(reveal.sig_slot.signature.vector_commitment_index, 920);
+        assert_eq!(reveal.sig_slot.signature.proof.tree_depth, 13);
+        assert_eq!(
+            reveal.sig_slot.signature.verifying_key.public_key.len(),
+            1793
+        );
+    }
+
+    #[test]
+    fn should_calculate_state_proof_tx_id() {
+        let tx = get_sample_state_proof_tx();
+        let result = tx.to_id().unwrap();
+        let expected_result = "GCJDIODM4VBNT5QVCKZVMKDPS3G7IFXDE5PCV3EW66T4XULWKCIQ";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serde_state_proof_tx_to_and_from_msg_pack() {
+        let tx = get_sample_state_proof_tx();
+        let bytes = rmp_serde::to_vec_named(&tx).unwrap();
+        let result: AlgorandTransaction = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(result.state_proof, tx.state_proof);
+        assert_eq!(result.state_proof_message, tx.state_proof_message);
+        assert_eq!(result.to_id().unwrap(), tx.to_id().unwrap());
+    }
+
+    #[test]
+    fn should_serde_state_proof_to_and_from_json() {
+        let state_proof = get_sample_state_proof_tx().state_proof.unwrap();
+        let result = StateProof::from_json(&state_proof.to_json()).unwrap();
+        assert_eq!(result, state_proof);
+    }
+}
@@ -0,0 +1,159 @@
+use base64::{decode as base64_decode, encode as base64_encode};
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_hash::AlgorandHash,
+    algorand_types::{Bytes, Result},
+    crypto_utils::sha256_hash_bytes,
+    predicates::is_zero,
+};
+
+const STATE_PROOF_MESSAGE_PREFIX: [u8; 3] = *b"spm";
+
+/// ## State Proof Message
+///
+/// The message a state proof attests to, committing to the light block headers of each round
+/// in its interval, and to the voters who may sign the next state proof along with the natural
+/// log of their total weight.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateProofMessage {
+    #[serde(rename = "P", default, skip_serializing_if = "is_zero")]
+    pub ln_proven_weight: u64,
+
+    #[serde(
+        rename = "b",
+        with = "serde_bytes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub block_headers_commitment: Bytes,
+
+    #[serde(rename = "f", default, skip_serializing_if = "is_zero")]
+    pub first_attested_round: u64,
+
+    #[serde(rename = "l", default, skip_serializing_if = "is_zero")]
+    pub last_attested_round: u64,
+
+    #[serde(
+        rename = "v",
+        with = "serde_bytes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub voters_commitment: Bytes,
+}
+
+impl StateProofMessage {
+    /// ## To Bytes
+    ///
+    /// Convert the message to msgpack-ed bytes.
+    pub fn to_bytes(&self) -> Result<Bytes> {
+        Ok(rmp_serde::to_vec_named(&self)?)
+    }
+
+    /// ## Hash
+    ///
+    /// The SHA-256 hash of the `spm` prefixed message, which is what a state proof's signers
+    /// sign.
+    pub fn hash(&self) -> Result<AlgorandHash> {
+        AlgorandHash::from_slice(&sha256_hash_bytes(
+            &[STATE_PROOF_MESSAGE_PREFIX.to_vec(), self.to_bytes()?].concat(),
+        ))
+    }
+
+    /// ## Get Block Headers Commitment
+    ///
+    /// Get the vector commitment to the light block headers of the rounds the message attests
+    /// to, against which light block header proofs are validated.
+    pub fn get_block_headers_commitment(&self) -> Result<AlgorandHash> {
+        AlgorandHash::from_slice(&self.block_headers_commitment)
+    }
+
+    pub fn from_json(json: &StateProofMessageJson) -> Result<Self> {
+        Ok(Self {
+            ln_proven_weight: json.ln_proven_weight.unwrap_or_default(),
+            block_headers_commitment: match &json.block_headers_commitment {
+                Some(base64_str) => base64_decode(base64_str)?,
+                None => vec![],
+            },
+            first_attested_round: json.first_attested_round.unwrap_or_default(),
+            last_attested_round: json.last_attested_round.unwrap_or_default(),
+            voters_commitment: match &json.voters_commitment {
+                Some(base64_str) => base64_decode(base64_str)?,
+                None => vec![],
+            },
+        })
+    }
+
+    pub fn to_json(&self) -> StateProofMessageJson {
+        StateProofMessageJson {
+            block_headers_commitment: Some(base64_encode(&self.block_headers_commitment)),
+            first_attested_round: Some(self.first_attested_round),
+            last_attested_round: Some(self.last_attested_round),
+            ln_proven_weight: Some(self.ln_proven_weight),
+            voters_commitment: Some(base64_encode(&self.voters_commitment)),
+        }
+    }
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateProofMessageJson {
+    #[serde(rename = "block-headers-commitment")]
+    pub block_headers_commitment: Option<String>,
+
+    #[serde(rename = "first-attested-round")]
+    pub first_attested_round: Option<u64>,
+
+    #[serde(rename = "latest-attested-round")]
+    pub last_attested_round: Option<u64>,
+
+    #[serde(rename = "ln-proven-weight")]
+    pub ln_proven_weight: Option<u64>,
+
+    #[serde(rename = "voters-commitment")]
+    pub voters_commitment: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_sample_message() -> StateProofMessage {
+        StateProofMessage {
+            ln_proven_weight: 2240612,
+            block_headers_commitment: vec![0xbb; 32],
+            first_attested_round: 23595265,
+            last_attested_round: 23595520,
+            voters_commitment: vec![0xcc; 64],
+        }
+    }
+
+    #[test]
+    fn should_encode_message_to_canonical_msg_pack() {
+        let result = hex::encode(get_sample_message().to_bytes().unwrap());
+        let expected_result = format!(
+            "85a150ce00223064a162c420{}a166ce01680901a16cce01680a00a176c440{}",
+            "bb".repeat(32),
+            "cc".repeat(64)
+        );
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_hash_message_with_prefix() {
+        let message = get_sample_message();
+        let result = message.hash().unwrap().to_bytes();
+        let expected_result =
+            sha256_hash_bytes(&[b"spm".to_vec(), message.to_bytes().unwrap()].concat());
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_serde_message_to_and_from_json() {
+        let message = get_sample_message();
+        let result = StateProofMessage::from_json(&message.to_json()).unwrap();
+        assert_eq!(result, message);
+    }
+}
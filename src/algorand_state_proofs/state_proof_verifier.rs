@@ -0,0 +1,448 @@
+use std::collections::BTreeMap;
+
+use num_bigint::BigUint;
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+    Shake256Reader,
+};
+
+use crate::{
+    algorand_blocks::state_proof_tracking::StateProofTracking,
+    algorand_consensus::consensus_params::ConsensusParams,
+    algorand_errors::AlgorandError,
+    algorand_falcon::falcon_verifier::AlgorandFalconVerifier,
+    algorand_state_proofs::{state_proof::StateProof, state_proof_message::StateProofMessage},
+    algorand_types::{Byte, Bytes, Result},
+};
+
+const MAX_REVEALS: usize = 640;
+const MAX_TREE_DEPTH: u64 = 20;
+const LN_PRECISION_BITS: u32 = 16;
+const LN_2_INT_APPROXIMATION: u64 = 45427;
+const COIN_GENERATOR_VERSION: Byte = 0;
+const STATE_PROOF_COIN_PREFIX: [u8; 3] = *b"spc";
+
+/// ## Ln Int Approximation
+///
+/// The natural log of the given value, scaled by 2^16 and rounded up, which is how a state
+/// proof's proven weight is represented.
+pub fn ln_int_approximation(value: u64) -> Result<u64> {
+    if value == 0 {
+        return Err("Cannot approximate the natural log of zero!".into());
+    };
+    Ok(((value as f64).ln() * (1u64 << LN_PRECISION_BITS) as f64).ceil() as u64)
+}
+
+// NOTE: Checks that the signed weight & number of reveals give the required bits of security
+// against the proven weight, ie that
+//   nr * (x + w * y) >= (strength_target * ln(2) + nr * ln(proven_weight)) * y
+// where, for d the floor of the log2 of the signed weight,
+//   y = signed_weight^2 + 2^(d + 2) * signed_weight + 2^2d,
+//   x = 3 * 2^16 * (signed_weight^2 - 2^2d),
+//   w = d * ln(2),
+// with the natural logs scaled by 2^16 as per `ln_int_approximation`.
+fn verify_weights(
+    signed_weight: u64,
+    ln_proven_weight: u64,
+    number_of_reveals: usize,
+    strength_target: u64,
+) -> Result<()> {
+    if number_of_reveals > MAX_REVEALS {
+        return Err(format!(
+            "State proof has {number_of_reveals} reveals, more than the maximum of {MAX_REVEALS}!"
+        )
+        .into());
+    };
+    if signed_weight == 0 {
+        return Err("State proof has a signed weight of zero!".into());
+    };
+    let d = signed_weight.ilog2() as u64;
+    let signed_weight_squared = BigUint::from(signed_weight).pow(2);
+    let two_to_the_2d = BigUint::from(1u8) << (2 * d);
+    let y = (BigUint::from(signed_weight) << (d + 2)) + &signed_weight_squared + &two_to_the_2d;
+    let x = ((signed_weight_squared - two_to_the_2d) * 3u8) << LN_PRECISION_BITS;
+    let w = BigUint::from(d * LN_2_INT_APPROXIMATION);
+    let number_of_reveals = BigUint::from(number_of_reveals);
+    let lhs = &number_of_reveals * (x + w * &y);
+    let rhs = (BigUint::from(strength_target) * LN_2_INT_APPROXIMATION
+        + number_of_reveals * ln_proven_weight)
+        * y;
+    if lhs >= rhs {
+        Ok(())
+    } else {
+        Err("State proof's signed weight is insufficient!".into())
+    }
+}
+
+// NOTE: Draws coins uniformly from [0, signed_weight) by rejection sampling little-endian u64s
+// read from SHAKE256 over the `spc` prefixed seed, which commits to everything the coins must be
+// unpredictable to.
+struct CoinGenerator {
+    reader: Shake256Reader,
+    signed_weight: u64,
+    threshold: u128,
+}
+
+impl CoinGenerator {
+    fn new(
+        participants_commitment: &[Byte],
+        ln_proven_weight: u64,
+        state_proof: &StateProof,
+        message_hash: &[Byte],
+    ) -> Self {
+        let mut shake = Shake256::default();
+        shake.update(
+            &[
+                STATE_PROOF_COIN_PREFIX.to_vec(),
+                vec![COIN_GENERATOR_VERSION],
+                participants_commitment.to_vec(),
+                ln_proven_weight.to_le_bytes().to_vec(),
+                state_proof.sig_commit.clone(),
+                state_proof.signed_weight.to_le_bytes().to_vec(),
+                message_hash.to_vec(),
+            ]
+            .concat(),
+        );
+        let signed_weight = state_proof.signed_weight;
+        Self {
+            reader: shake.finalize_xof(),
+            signed_weight,
+            threshold: ((1u128 << 64) / signed_weight as u128) * signed_weight as u128,
+        }
+    }
+
+    fn get_next_coin(&mut self) -> u64 {
+        loop {
+            let mut bytes = [0u8; 8];
+            self.reader.read(&mut bytes);
+            let value = u64::from_le_bytes(bytes);
+            if (value as u128) < self.threshold {
+                return value % self.signed_weight;
+            }
+        }
+    }
+}
+
+/// ## State Proof Verifier
+///
+/// Verifies state proofs signed by the voters of a trusted commitment, whose total weight it must
+/// prove the signatures of as the natural log of that weight, per `ln_int_approximation`.
+///
+/// The first verifier of a light client is made from the `StateProofTracking` of a trusted block
+/// header, namely that of the round a state proof interval before the first state proof to be
+/// verified attests to. Thereafter, each attested message commits to the voters of the next state
+/// proof, so verifiers can be chained from them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateProofVerifier {
+    pub participants_commitment: Bytes,
+    pub ln_proven_weight: u64,
+    pub strength_target: u64,
+}
+
+impl StateProofVerifier {
+    pub fn new(
+        participants_commitment: &[Byte],
+        ln_proven_weight: u64,
+        strength_target: u64,
+    ) -> Self {
+        Self {
+            participants_commitment: participants_commitment.to_vec(),
+            ln_proven_weight,
+            strength_target,
+        }
+    }
+
+    fn check_state_proofs_enabled(params: &ConsensusParams) -> Result<()> {
+        if params.state_proof_interval == 0 {
+            Err("State proofs are not enabled in the given consensus params!".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ## From State Proof Tracking
+    ///
+    /// Make a verifier from the state proof tracking of a trusted block header, under the
+    /// consensus params that header was made with.
+    pub fn from_state_proof_tracking(
+        tracking: &StateProofTracking,
+        params: &ConsensusParams,
+    ) -> Result<Self> {
+        Self::check_state_proofs_enabled(params)?;
+        let voters_commitment = match &tracking.voters_commitment {
+            Some(commitment) if !commitment.is_empty() => commitment,
+            _ => return Err("State proof tracking has no voters commitment!".into()),
+        };
+        let online_total_weight = tracking.online_total_weight.unwrap_or_default();
+        let proven_weight =
+            (online_total_weight as u128 * params.state_proof_weight_threshold as u128) >> 32;
+        Ok(Self::new(
+            voters_commitment,
+            ln_int_approximation(proven_weight as u64)?,
+            params.state_proof_strength_target,
+        ))
+    }
+
+    /// ## From Attested Message
+    ///
+    /// Make a verifier for the next state proof from a message attested to by the current one.
+    pub fn from_attested_message(
+        message: &StateProofMessage,
+        params: &ConsensusParams,
+    ) -> Result<Self> {
+        Self::check_state_proofs_enabled(params)?;
+        Ok(Self::new(
+            &message.voters_commitment,
+            message.ln_proven_weight,
+            params.state_proof_strength_target,
+        ))
+    }
+
+    fn verify_coins(&self, state_proof: &StateProof, message_hash: &[Byte]) -> Result<()> {
+        let mut coin_generator = CoinGenerator::new(
+            &self.participants_commitment,
+            self.ln_proven_weight,
+            state_proof,
+            message_hash,
+        );
+        state_proof
+            .positions_to_reveal
+            .iter()
+            .try_for_each(|position| {
+                let reveal = state_proof
+                    .reveals
+                    .get(position)
+                    .ok_or_else(|| format!("State proof has no reveal at position {position}!"))?;
+                let coin = coin_generator.get_next_coin();
+                let lower_sig_weight = reveal.sig_slot.lower_sig_weight;
+                let upper_sig_weight =
+                    lower_sig_weight.saturating_add(reveal.participant.weight);
+                if lower_sig_weight <= coin && coin < upper_sig_weight {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "State proof coin {coin} is not in the range [{lower_sig_weight}, {upper_sig_weight}) of the reveal at position {position}!"
+                    )
+                    .into())
+                }
+            })
+    }
+
+    /// ## Verify
+    ///
+    /// Verify that the state proof proves enough of the voters' weight signed the given message,
+    /// returning the message as attested to if so.
+    ///
+    /// Each revealed signature must be a valid Falcon signature of the message's hash, made with
+    /// the ephemeral key the participant committed to for the message's last attested round.
+    /// Both the signatures & participants are checked against the state proof's signature
+    /// commitment & the trusted voters commitment respectively. Finally, the reveals must be those
+    /// chosen by the coin flips the state proof commits to.
+    pub fn verify(
+        &self,
+        state_proof: &StateProof,
+        message: &StateProofMessage,
+    ) -> Result<StateProofMessage> {
+        [&state_proof.sig_proofs, &state_proof.part_proofs]
+            .iter()
+            .try_for_each(|proof| {
+                if proof.tree_depth > MAX_TREE_DEPTH {
+                    Err(format!(
+                        "State proof tree depth {} exceeds the maximum of {MAX_TREE_DEPTH}!",
+                        proof.tree_depth
+                    ))
+                } else {
+                    Ok(())
+                }
+            })?;
+        verify_weights(
+            state_proof.signed_weight,
+            self.ln_proven_weight,
+            state_proof.positions_to_reveal.len(),
+            self.strength_target,
+        )?;
+        if let Some((position, _)) = state_proof.reveals.iter().find(|(_, reveal)| {
+            reveal.sig_slot.signature.get_salt_version() as u64 != state_proof.salt_version
+        }) {
+            return Err(format!(
+                "Salt version of the signature at position {position} does not match the state proof's!"
+            )
+            .into());
+        };
+        let round = message.last_attested_round;
+        let message_hash = message.hash()?.to_bytes();
+        let mut signatures = BTreeMap::new();
+        let mut participants = BTreeMap::new();
+        state_proof
+            .reveals
+            .iter()
+            .try_for_each(|(position, reveal)| {
+                AlgorandFalconVerifier::from(&reveal.participant.verifier).verify(
+                    round,
+                    &message_hash,
+                    &reveal.sig_slot.signature,
+                )?;
+                signatures.insert(*position, reveal.sig_slot.to_hashable_bytes());
+                participants.insert(*position, reveal.participant.to_hashable_bytes());
+                Ok::<(), AlgorandError>(())
+            })?;
+        state_proof
+            .sig_proofs
+            .verify_vector_commitment(&state_proof.sig_commit, &signatures)
+            .map_err(|_| "State proof's signatures do not match its signature commitment!")?;
+        state_proof
+            .part_proofs
+            .verify_vector_commitment(&self.participants_commitment, &participants)
+            .map_err(|_| "State proof's participants do not match the voters commitment!")?;
+        self.verify_coins(state_proof, &message_hash)?;
+        Ok(message.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_blocks::test_utils::{
+        get_sample_block_header_n,
+        get_sample_state_proof_tx,
+    };
+
+    // NOTE: The sample state proof attests to rounds 23595265 to 23595520, whose voters were
+    // committed to by the header of round 23595008. That header isn't amongst the sample blocks,
+    // so this is the commitment its participant proofs lead to, & the natural log of its voters'
+    // proven weight.
+    const SAMPLE_PARTICIPANTS_COMMITMENT: &str = "a829268a27917161bb51d60eead3fa4c46c866b41cee724eb6666fe0bcfe9ad7c3d82a72e3130e3351f74abcca97fa47a7122958c89ac7dfe11e27312651fb62";
+    const SAMPLE_LN_PROVEN_WEIGHT: u64 = 2240612;
+
+    fn get_sample_verifier() -> StateProofVerifier {
+        StateProofVerifier::new(
+            &hex::decode(SAMPLE_PARTICIPANTS_COMMITMENT).unwrap(),
+            SAMPLE_LN_PROVEN_WEIGHT,
+            ConsensusParams::v35().state_proof_strength_target,
+        )
+    }
+
+    fn assert_custom_error(result: Result<StateProofMessage>, expected_error: &str) {
+        match result {
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            _ => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_get_ln_int_approximation() {
+        assert_eq!(ln_int_approximation(1).unwrap(), 0);
+        assert_eq!(ln_int_approximation(2).unwrap(), LN_2_INT_APPROXIMATION);
+    }
+
+    #[test]
+    fn should_fail_to_get_ln_int_approximation_of_zero() {
+        assert!(ln_int_approximation(0).is_err());
+    }
+
+    #[test]
+    fn should_verify_state_proof() {
+        let tx = get_sample_state_proof_tx();
+        let message = tx.state_proof_message.unwrap();
+        let result = get_sample_verifier()
+            .verify(&tx.state_proof.unwrap(), &message)
+            .unwrap();
+        assert_eq!(result, message);
+    }
+
+    #[test]
+    fn should_fail_to_verify_state_proof_against_wrong_voters_commitment() {
+        let tx = get_sample_state_proof_tx();
+        let mut verifier = get_sample_verifier();
+        verifier.participants_commitment[0] ^= 1;
+        assert_custom_error(
+            verifier.verify(&tx.state_proof.unwrap(), &tx.state_proof_message.unwrap()),
+            "State proof's participants do not match the voters commitment!",
+        );
+    }
+
+    #[test]
+    fn should_fail_to_verify_state_proof_for_different_message() {
+        let tx = get_sample_state_proof_tx();
+        let mut message = tx.state_proof_message.unwrap();
+        message.ln_proven_weight += 1;
+        assert_custom_error(
+            get_sample_verifier().verify(&tx.state_proof.unwrap(), &message),
+            "Falcon signature verification failed!",
+        );
+    }
+
+    #[test]
+    fn should_fail_to_verify_state_proof_with_insufficient_signed_weight() {
+        let tx = get_sample_state_proof_tx();
+        let mut verifier = get_sample_verifier();
+        verifier.ln_proven_weight = ln_int_approximation(u64::MAX).unwrap();
+        assert_custom_error(
+            verifier.verify(&tx.state_proof.unwrap(), &tx.state_proof_message.unwrap()),
+            "State proof's signed weight is insufficient!",
+        );
+    }
+
+    #[test]
+    fn should_fail_to_verify_state_proof_with_max_signed_weight() {
+        let tx = get_sample_state_proof_tx();
+        let mut state_proof = tx.state_proof.unwrap();
+        state_proof.signed_weight = u64::MAX;
+        let result = get_sample_verifier().verify(&state_proof, &tx.state_proof_message.unwrap());
+        match result {
+            Err(AlgorandError::Custom(error)) => assert!(error.starts_with("State proof coin")),
+            _ => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_verify_state_proof_with_tampered_signature() {
+        let tx = get_sample_state_proof_tx();
+        let mut state_proof = tx.state_proof.unwrap();
+        let position = *state_proof.reveals.keys().next().unwrap();
+        state_proof
+            .reveals
+            .get_mut(&position)
+            .unwrap()
+            .sig_slot
+            .lower_sig_weight += 1;
+        assert_custom_error(
+            get_sample_verifier().verify(&state_proof, &tx.state_proof_message.unwrap()),
+            "State proof's signatures do not match its signature commitment!",
+        );
+    }
+
+    #[test]
+    fn should_get_verifier_from_state_proof_tracking() {
+        let header = get_sample_block_header_n(18);
+        let params = header.consensus_params().unwrap();
+        let tracking = &header.state_proof_tracking.unwrap()[&0];
+        let result = StateProofVerifier::from_state_proof_tracking(tracking, &params).unwrap();
+        assert_eq!(
+            &result.participants_commitment,
+            tracking.voters_commitment.as_ref().unwrap()
+        );
+        assert_eq!(result.ln_proven_weight, 2190314);
+        assert_eq!(result.strength_target, 256);
+    }
+
+    #[test]
+    fn should_fail_to_get_verifier_if_state_proofs_are_disabled() {
+        let header = get_sample_block_header_n(18);
+        let tracking = &header.state_proof_tracking.unwrap()[&0];
+        assert!(
+            StateProofVerifier::from_state_proof_tracking(tracking, &ConsensusParams::v33())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_get_verifier_from_attested_message() {
+        let message = get_sample_state_proof_tx().state_proof_message.unwrap();
+        let result =
+            StateProofVerifier::from_attested_message(&message, &ConsensusParams::v35()).unwrap();
+        assert_eq!(result.participants_commitment, message.voters_commitment);
+        assert_eq!(result.ln_proven_weight, message.ln_proven_weight);
+    }
+}
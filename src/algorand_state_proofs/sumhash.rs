@@ -0,0 +1,165 @@
+use sha3::{
+    digest::{ExtendableOutput, Update, XofReader},
+    Shake256,
+};
+
+use crate::algorand_types::{Byte, Bytes};
+
+const SUMHASH_SEED: [u8; 8] = *b"Algorand";
+const SUMHASH_DIGEST_SIZE: usize = 64;
+const SUMHASH_BLOCK_SIZE: usize = 64;
+const SUMHASH_MATRIX_ROWS: usize = SUMHASH_DIGEST_SIZE / 8;
+const SUMHASH_MATRIX_COLUMNS: usize = (SUMHASH_DIGEST_SIZE + SUMHASH_BLOCK_SIZE) * 8;
+const SUMHASH_COMPRESSOR_INPUT_SIZE: usize = SUMHASH_MATRIX_COLUMNS / 8;
+
+lazy_static! {
+    // NOTE: For each row of the matrix and each byte of the compressor's input, holds the sum of
+    // the row's entries selected by every possible value of that byte, so that compressing a block
+    // takes one lookup per row per input byte rather than one addition per bit.
+    static ref SUMHASH_LOOKUP_TABLE: Vec<[u64; 256]> = {
+        let matrix = get_sumhash_matrix();
+        matrix
+            .iter()
+            .flat_map(|row| {
+                row.chunks(8).map(|entries| {
+                    let mut sums = [0u64; 256];
+                    (1..256).for_each(|byte: usize| {
+                        let lowest_bit = byte.trailing_zeros() as usize;
+                        sums[byte] = sums[byte & (byte - 1)].wrapping_add(entries[lowest_bit]);
+                    });
+                    sums
+                })
+            })
+            .collect()
+    };
+}
+
+// NOTE: The matrix is generated by reading little-endian u64s, row by row, from SHAKE256 over
+// the little-endian u16 sizes of the digest, the rows & the columns, followed by the seed.
+fn get_sumhash_matrix() -> Vec<Vec<u64>> {
+    let mut shake = Shake256::default();
+    [
+        SUMHASH_DIGEST_SIZE,
+        SUMHASH_MATRIX_ROWS,
+        SUMHASH_MATRIX_COLUMNS,
+    ]
+    .iter()
+    .for_each(|size| shake.update(&(*size as u16).to_le_bytes()));
+    shake.update(&SUMHASH_SEED);
+    let mut reader = shake.finalize_xof();
+    (0..SUMHASH_MATRIX_ROWS)
+        .map(|_| {
+            (0..SUMHASH_MATRIX_COLUMNS)
+                .map(|_| {
+                    let mut entry = [0u8; 8];
+                    reader.read(&mut entry);
+                    u64::from_le_bytes(entry)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn compress(chain_value: &mut [Byte; SUMHASH_DIGEST_SIZE], block: &[Byte]) {
+    let input = [chain_value.as_slice(), block].concat();
+    let sums = (0..SUMHASH_MATRIX_ROWS)
+        .map(|row| {
+            input.iter().enumerate().fold(0u64, |sum, (i, byte)| {
+                sum.wrapping_add(
+                    SUMHASH_LOOKUP_TABLE[row * SUMHASH_COMPRESSOR_INPUT_SIZE + i][*byte as usize],
+                )
+            })
+        })
+        .collect::<Vec<u64>>();
+    sums.iter().enumerate().for_each(|(row, sum)| {
+        chain_value[row * 8..(row + 1) * 8].copy_from_slice(&sum.to_le_bytes())
+    });
+}
+
+/// ## Sumhash512
+///
+/// The subset-sum hash Algorand's state proofs commit to their participants & signatures with.
+/// It's a Merkle-Damgård construction over a zeroed IV, whose compression function sums the
+/// entries of a matrix derived from the `Algorand` seed, selected by the bits of its input.
+pub fn sumhash512_hash_bytes(bytes: &[Byte]) -> Bytes {
+    let length = bytes.len();
+    let remainder = length % SUMHASH_BLOCK_SIZE;
+    let padding_length = if remainder < SUMHASH_BLOCK_SIZE - 16 {
+        SUMHASH_BLOCK_SIZE - 16 - remainder
+    } else {
+        2 * SUMHASH_BLOCK_SIZE - 16 - remainder
+    };
+    let mut padding = vec![0u8; padding_length];
+    padding[0] = 0x01;
+    let padded = [
+        bytes,
+        &padding,
+        &((length as u64) << 3).to_le_bytes(),
+        &[0u8; 8],
+    ]
+    .concat();
+    let mut chain_value = [0u8; SUMHASH_DIGEST_SIZE];
+    padded
+        .chunks(SUMHASH_BLOCK_SIZE)
+        .for_each(|block| compress(&mut chain_value, block));
+    chain_value.to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::algorand_blocks::test_utils::get_sample_state_proof_tx;
+
+    // NOTE: The signatures commitment of the mainnet state proof in round 23595666 is a sumhash512
+    // vector commitment, so hashing its revealed signatures and folding them along its proof
+    // must reproduce it.
+    #[test]
+    fn should_hash_to_signatures_commitment_of_mainnet_state_proof() {
+        let state_proof = get_sample_state_proof_tx().state_proof.unwrap();
+        let signatures = state_proof
+            .reveals
+            .iter()
+            .map(|(position, reveal)| (*position, reveal.sig_slot.to_hashable_bytes()))
+            .collect::<BTreeMap<u64, Bytes>>();
+        let result = state_proof
+            .sig_proofs
+            .to_vector_commitment_root(&signatures)
+            .unwrap()
+            .unwrap();
+        let expected_result = state_proof.sig_commit;
+        assert_eq!(expected_result.len(), 64);
+        assert_eq!(hex::encode(result), hex::encode(expected_result));
+    }
+
+    #[test]
+    fn should_not_hash_to_signatures_commitment_if_a_signature_differs() {
+        let state_proof = get_sample_state_proof_tx().state_proof.unwrap();
+        let mut signatures = state_proof
+            .reveals
+            .iter()
+            .map(|(position, reveal)| (*position, reveal.sig_slot.to_hashable_bytes()))
+            .collect::<BTreeMap<u64, Bytes>>();
+        signatures.values_mut().next().unwrap()[8] ^= 1;
+        let result = state_proof
+            .sig_proofs
+            .to_vector_commitment_root(&signatures)
+            .unwrap()
+            .unwrap();
+        assert_ne!(result, state_proof.sig_commit);
+    }
+
+    #[test]
+    fn should_get_64_byte_digest_for_inputs_either_side_of_padding_boundary() {
+        (0..130).for_each(|length| assert_eq!(sumhash512_hash_bytes(&vec![7; length]).len(), 64));
+    }
+
+    #[test]
+    fn should_get_different_digests_for_inputs_differing_in_length_only() {
+        assert_ne!(
+            sumhash512_hash_bytes(&[0; 47]),
+            sumhash512_hash_bytes(&[0; 48])
+        );
+    }
+}
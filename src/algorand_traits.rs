@@ -20,3 +20,17 @@ pub trait ToMsgPackBytes {
 pub trait ToApplicationArg {
     fn to_application_arg(&self) -> AlgorandApplicationArg;
 }
+
+/// To Asset Base Units
+///
+/// A trait for amounts of an asset which may be sent in a transaction, ie raw base units or an
+/// `AssetAmount`.
+pub trait ToAssetBaseUnits {
+    fn to_asset_base_units(&self) -> u64;
+}
+
+impl ToAssetBaseUnits for u64 {
+    fn to_asset_base_units(&self) -> u64 {
+        *self
+    }
+}
@@ -5,17 +5,16 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 
 use crate::{
-    algorand_blocks::block::AlgorandBlock,
+    algorand_blocks::{block::AlgorandBlock, light_block_header::AlgorandLightBlockHeader},
     algorand_errors::AlgorandError,
     algorand_hash::AlgorandHash,
+    algorand_transactions::{
+        transaction::AlgorandTransaction,
+        transactions_merkle_tree::{AlgorandMerkleHashType, AlgorandTransactionsMerkleTree},
+    },
     algorand_types::{Bytes, Result},
-    crypto_utils::sha512_256_hash_bytes,
 };
 
-// NOTE: These prefixes are used to domain-separate the various hashes used in the protocol.
-const MERKLE_ARRAY_ELEMTENT_PREFIX: [u8; 2] = *b"MA";
-const TRANSACTION_MERKLE_LEAF_PREFIX: [u8; 2] = *b"TL";
-
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AlgorandTransactionProofJson {
     proof: String,
@@ -114,33 +113,35 @@ impl AlgorandTransactionProof {
         (current_index - (current_index % 2)) / 2
     }
 
-    fn calculate_leaf_hash(&self, tx_id: &AlgorandHash) -> Bytes {
-        sha512_256_hash_bytes(
-            &[
-                TRANSACTION_MERKLE_LEAF_PREFIX.into(),
-                tx_id.to_bytes(),
-                self.stib_hash.clone(),
-            ]
-            .concat(),
-        )
+    /// ## Get Hash Type
+    ///
+    /// Get the hash function the proof was made with, failing if it's not one the protocol uses.
+    pub fn get_hash_type(&self) -> Result<AlgorandMerkleHashType> {
+        AlgorandMerkleHashType::from_str(&self.hash_type)
     }
 
     fn to_root_hash(&self, tx_id: &AlgorandHash) -> Result<AlgorandHash> {
-        let leaf_hash = self.calculate_leaf_hash(tx_id);
+        let hash_type = self.get_hash_type()?;
+        let leaf_hash = AlgorandTransactionsMerkleTree::hash_leaf(
+            hash_type,
+            &tx_id.to_bytes(),
+            &self.stib_hash,
+        );
+        let position = AlgorandTransactionsMerkleTree::position(
+            hash_type,
+            self.index as usize,
+            self.tree_depth as usize,
+        ) as u64;
         AlgorandHash::from_slice(
             &self
                 .proof
                 .iter()
-                .fold((leaf_hash, self.index), |(hash, index), hash_from_proof| {
-                    let mut bytes_to_hash = vec![MERKLE_ARRAY_ELEMTENT_PREFIX.to_vec()];
-                    if index % 2 == 0 {
-                        bytes_to_hash.push(hash);
-                        bytes_to_hash.push(hash_from_proof.to_vec());
+                .fold((leaf_hash, position), |(hash, index), hash_from_proof| {
+                    let next_hash = if index % 2 == 0 {
+                        AlgorandTransactionsMerkleTree::hash_node(hash_type, &hash, hash_from_proof)
                     } else {
-                        bytes_to_hash.push(hash_from_proof.to_vec());
-                        bytes_to_hash.push(hash);
+                        AlgorandTransactionsMerkleTree::hash_node(hash_type, hash_from_proof, &hash)
                     };
-                    let next_hash = sha512_256_hash_bytes(&bytes_to_hash.concat());
                     let next_index = Self::calculate_next_leaf_index(index);
                     (next_hash, next_index)
                 })
@@ -153,14 +154,49 @@ impl AlgorandTransactionProof {
             .map(|ref root_hash| root_hash == txn_root)
     }
 
+    /// ## Validate
+    ///
+    /// Validate the proof against the block's transactions root of the proof's hash type, ie
+    /// the `txn` root for SHA-512/256 proofs and the `txn256` root for SHA-256 proofs.
     pub fn validate(&self, block: &AlgorandBlock) -> Result<()> {
-        // TODO test
-        if self.is_valid(
-            &block
-                .get_transaction_at_index(self.index as usize)?
-                .to_raw_tx_id()?,
-            &block.get_transactions_root()?,
-        )? {
+        let tx = block.get_transaction_at_index(self.index as usize)?;
+        let (tx_id, txn_root) = match self.get_hash_type()? {
+            AlgorandMerkleHashType::Sha512_256 => {
+                (tx.to_raw_tx_id()?, block.get_transactions_root()?)
+            },
+            AlgorandMerkleHashType::Sha256 => (
+                tx.to_raw_tx_id_sha256()?,
+                block.get_transactions_root_sha256()?,
+            ),
+        };
+        if self.is_valid(&tx_id, &txn_root)? {
+            Ok(())
+        } else {
+            Err("Invalid proof!".into())
+        }
+    }
+
+    /// ## Validate With Light Block Header
+    ///
+    /// Validate a SHA-256 proof of the given transaction against the `txn256` root of a light
+    /// block header, which may itself be proven against a state proof, such that the block the
+    /// transaction is in need not be trusted.
+    pub fn validate_with_light_block_header(
+        &self,
+        tx: &AlgorandTransaction,
+        header: &AlgorandLightBlockHeader,
+    ) -> Result<()> {
+        let hash_type = self.get_hash_type()?;
+        if hash_type != AlgorandMerkleHashType::Sha256 {
+            return Err(
+                format!("Cannot validate {hash_type} proof against a light block header!").into(),
+            );
+        };
+        let txn_root = match header.transactions_root_sha256 {
+            Some(root) => root,
+            None => return Err("No SHA-256 tx root in light block header!".into()),
+        };
+        if self.is_valid(&tx.to_raw_tx_id_sha256()?, &txn_root)? {
             Ok(())
         } else {
             Err("Invalid proof!".into())
@@ -173,7 +209,11 @@ mod tests {
     use base64::decode as base64_decode;
 
     use super::*;
-    use crate::{algorand_hash::AlgorandHash, crypto_utils::base32_decode};
+    use crate::{
+        algorand_blocks::test_utils::get_sample_block_msg_pack_bytes,
+        algorand_hash::AlgorandHash,
+        crypto_utils::base32_decode,
+    };
 
     fn get_sample_proof_string() -> String {
         // NOTE: Gotten via: curl -s "https://algoexplorerapi.io/v2/blocks/20261491/transactions/UFZTMQWJ3N6LWGMMSF7EJENOQKYYUDC7A2346TR3L7AYTBRCAPZQ/proof" | jq
@@ -323,5 +363,93 @@ mod tests {
     }
 
     #[test]
-    fn should_validate_proof_1() {}
+    fn should_validate_sha256_proofs_against_block() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        (0..8).for_each(|i| {
+            let proof = block.prove_transaction_sha256(i).unwrap();
+            assert_eq!(proof.hash_type, "sha256");
+            assert_eq!(proof.tree_depth, 3);
+            proof.validate(&block).unwrap();
+        });
+    }
+
+    #[test]
+    fn should_serde_sha256_proof_to_and_from_str() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let proof = block.prove_transaction_sha256(5).unwrap();
+        let result = AlgorandTransactionProof::from_str(&proof.to_string()).unwrap();
+        assert_eq!(result, proof);
+        assert!(result.validate(&block).is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_validate_sha256_proof_against_sha512_256_root() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let mut proof = block.prove_transaction_sha256(1).unwrap();
+        proof.hash_type = "sha512_256".to_string();
+        assert!(proof.validate(&block).is_err());
+    }
+
+    #[test]
+    fn should_reject_proof_with_unknown_hash_type() {
+        let mut proof = get_sample_proof();
+        proof.hash_type = "sha3_256".to_string();
+        let tx_id = AlgorandHash::from_slice(
+            &base32_decode("UFZTMQWJ3N6LWGMMSF7EJENOQKYYUDC7A2346TR3L7AYTBRCAPZQ").unwrap(),
+        )
+        .unwrap();
+        let expected_error = "Unsupported Merkle hash type: 'sha3_256'!";
+        match proof.is_valid(&tx_id, &AlgorandHash::default()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_validate_sha256_proof_with_light_block_header() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let header = AlgorandLightBlockHeader::from(&block.block_header);
+        let tx = block.get_transaction_at_index(4).unwrap();
+        let proof = block.prove_transaction_sha256(4).unwrap();
+        assert!(proof.validate_with_light_block_header(&tx, &header).is_ok());
+        let other_tx = block.get_transaction_at_index(5).unwrap();
+        assert!(proof
+            .validate_with_light_block_header(&other_tx, &header)
+            .is_err());
+    }
+
+    #[test]
+    fn should_fail_to_validate_sha512_256_proof_with_light_block_header() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let header = AlgorandLightBlockHeader::from(&block.block_header);
+        let tx = block.get_transaction_at_index(4).unwrap();
+        let proof = block.prove_transaction(4).unwrap();
+        let expected_error = "Cannot validate sha512_256 proof against a light block header!";
+        match proof.validate_with_light_block_header(&tx, &header) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_validate_proof_against_block() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let proof = block.prove_transaction(2).unwrap();
+        assert!(proof.validate(&block).is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_validate_proof_of_other_tx() {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        let mut proof = block.prove_transaction(2).unwrap();
+        proof.index = 3;
+        let expected_error = "Invalid proof!";
+        match proof.validate(&block) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
 }
@@ -10,10 +10,11 @@ use crate::{
     algorand_hash::AlgorandHash,
     algorand_micro_algos::MicroAlgos,
     algorand_transactions::{
+        suggested_params::SuggestedParams,
         transaction::AlgorandTransaction,
-        transaction_type::AlgorandTransactionType,
+        transaction_builder::TransactionBuilder,
     },
-    algorand_types::Result,
+    algorand_types::{Bytes, Result},
 };
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -32,6 +33,7 @@ pub enum OnCompletion {
     Delete,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for OnCompletion {
     fn default() -> Self {
         Self::Noop
@@ -137,6 +139,11 @@ pub struct ApplicationTransactionJson {
     #[serde(rename = "clear-state-program")]
     pub clear_state_program: Option<String>,
 
+    /// `apep` Number of additional pages allocated to the application's approval and clear state
+    /// programs.
+    #[serde(rename = "extra-program-pages")]
+    pub extra_program_pages: Option<u64>,
+
     /// `apfa` Lists the applications in addition to the application-id whose global states may be
     /// accessed by this application's approval-program and clear-state-program. The access is
     /// read-only.
@@ -176,6 +183,7 @@ impl ApplicationTransactionJson {
             && self.application_id.is_none()
             && self.approval_program.is_none()
             && self.clear_state_program.is_none()
+            && self.extra_program_pages.is_none()
             && self.foreign_apps.is_none()
             && self.foreign_assets.is_none()
             && self.global_state_schema.is_none()
@@ -193,6 +201,20 @@ impl ApplicationTransactionJson {
         }
     }
 
+    pub fn maybe_get_approval_program(&self) -> Result<Option<Bytes>> {
+        match &self.approval_program {
+            None => Ok(None),
+            Some(encoded_str) => Ok(Some(base64_decode(encoded_str)?)),
+        }
+    }
+
+    pub fn maybe_get_clear_state_program(&self) -> Result<Option<Bytes>> {
+        match &self.clear_state_program {
+            None => Ok(None),
+            Some(encoded_str) => Ok(Some(base64_decode(encoded_str)?)),
+        }
+    }
+
     pub fn maybe_get_accounts(&self) -> Result<Vec<AlgorandAddress>> {
         match &self.accounts {
             None => Ok(vec![]),
@@ -216,20 +238,27 @@ impl AlgorandTransaction {
         genesis_hash: &AlgorandHash,
         last_valid_round: Option<u64>,
     ) -> Result<AlgorandTransaction> {
-        Ok(Self {
-            sender: Some(*sender),
-            genesis_hash: Some(*genesis_hash),
-            application_id: Some(application_id),
-            on_completion: Some(OnCompletion::Optin.to_u64()),
-            first_valid_round: Some(first_valid_round),
-            fee: Some(fee.check_if_satisfies_minimum_fee()?.0),
-            txn_type: Some(AlgorandTransactionType::ApplicationCall),
-            last_valid_round: Some(Self::calculate_last_valid_round(
-                first_valid_round,
-                last_valid_round,
-            )?),
-            ..Default::default()
-        })
+        TransactionBuilder::application_call(*sender, application_id)
+            .on_completion(OnCompletion::Optin)
+            .fee(*fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(*genesis_hash)
+            .build()
+    }
+
+    /// ## Application Opt In With Params
+    ///
+    /// Create an application opt-in transaction, taking the fee, validity window and genesis
+    /// information from the given suggested params.
+    pub fn application_opt_in_with_params(
+        application_id: u64,
+        sender: &AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<AlgorandTransaction> {
+        TransactionBuilder::application_call(*sender, application_id)
+            .on_completion(OnCompletion::Optin)
+            .suggested_params(params)
+            .build()
     }
 
     /// ## Application Noop call
@@ -243,6 +272,7 @@ impl AlgorandTransaction {
     /// * `foreign_apps` - A vector containing the application the smart contract may interact with
     /// * `foreign_assets` - A vector containing the ASAs the smart contract may retrieve
     ///   information from
+    #[allow(clippy::too_many_arguments)]
     pub fn application_call_noop(
         application_id: u64,
         fee: MicroAlgos,
@@ -255,32 +285,64 @@ impl AlgorandTransaction {
         foreign_apps: Option<Vec<u64>>,
         foreign_assets: Option<Vec<u64>>,
     ) -> Result<AlgorandTransaction> {
-        Ok(Self {
-            sender: Some(sender),
-            genesis_hash: Some(genesis_hash),
-            application_id: Some(application_id),
-            first_valid_round: Some(first_valid_round),
-            fee: Some(fee.check_if_satisfies_minimum_fee()?.0),
-            txn_type: Some(AlgorandTransactionType::ApplicationCall),
-            last_valid_round: Some(Self::calculate_last_valid_round(
-                first_valid_round,
-                last_valid_round,
-            )?),
-            application_args,
-            accounts,
-            foreign_apps,
-            foreign_assets,
-            ..Default::default()
-        })
+        let mut builder = TransactionBuilder::application_call(sender, application_id)
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash);
+        if let Some(application_args) = application_args {
+            builder = builder.application_args(application_args)
+        };
+        if let Some(accounts) = accounts {
+            builder = builder.accounts(accounts)
+        };
+        if let Some(foreign_apps) = foreign_apps {
+            builder = builder.foreign_apps(foreign_apps)
+        };
+        if let Some(foreign_assets) = foreign_assets {
+            builder = builder.foreign_assets(foreign_assets)
+        };
+        builder.build()
+    }
+
+    /// ## Application Noop Call With Params
+    ///
+    /// Create a Noop application call, taking the fee, validity window and genesis information
+    /// from the given suggested params.
+    pub fn application_call_noop_with_params(
+        application_id: u64,
+        sender: AlgorandAddress,
+        application_args: Option<Vec<AlgorandApplicationArg>>,
+        accounts: Option<Vec<AlgorandAddress>>,
+        foreign_apps: Option<Vec<u64>>,
+        foreign_assets: Option<Vec<u64>>,
+        params: &SuggestedParams,
+    ) -> Result<AlgorandTransaction> {
+        let mut builder =
+            TransactionBuilder::application_call(sender, application_id).suggested_params(params);
+        if let Some(application_args) = application_args {
+            builder = builder.application_args(application_args)
+        };
+        if let Some(accounts) = accounts {
+            builder = builder.accounts(accounts)
+        };
+        if let Some(foreign_apps) = foreign_apps {
+            builder = builder.foreign_apps(foreign_apps)
+        };
+        if let Some(foreign_assets) = foreign_assets {
+            builder = builder.foreign_assets(foreign_assets)
+        };
+        builder.build()
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::vec_init_then_push)]
 mod tests {
     use std::str::FromStr;
 
     use super::*;
     use crate::{
+        algorand_blocks::test_utils::get_sample_block_n,
         algorand_hash::AlgorandHash,
         test_utils::{get_sample_algorand_address, get_sample_algorand_keys},
     };
@@ -616,4 +678,12 @@ mod tests {
         let expected_result = "82a3736967c440d6c55d1cdaa0f7556506699f67c0730f82baf09fe2f06ecd175631a672aab5a8f12c037906a3358f3a482c6b29601971a72a8e608c818e1121abc3d701769001a374786e8aa46170616192c418323032322d30352d32305431323a35333a35392e3030305ac40800000000499602d2a46170617492c42032a7dbdfcde7695d91ac438152fc908617ffbf9db94f843c250268e6fe21a0a0c420c3a3d439ff6c61367a15c4d2872ffebac9a6c7a03c273812bc64de26726b76d5a46170666191ce075bcd15a461706964ce0565c844a3666565cd03e8a26676ce014ad773a26768c4204863b518a4b3c84ec810f22d4f1081cb0f71f059a7ac20dec62f7f70e5093a22a26c76ce014adb5ba3736e64c42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da474797065a46170706c";
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_calculate_id_of_application_create_transaction() {
+        let tx = get_sample_block_n(1).get_transaction_at_index(58).unwrap();
+        let result = tx.to_id().unwrap();
+        let expected_result = "YYTRXJKAMFHXOYK4HK53SEZWCKJHMTQQPUGCWHEQU5TU2AHLZDHA";
+        assert_eq!(result, expected_result);
+    }
 }
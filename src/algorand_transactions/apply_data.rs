@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_applications::algorand_eval_delta::AlgorandEvalDelta,
+    predicates::is_zero_option,
+};
+
+/// ## Algorand Apply Data
+///
+/// The effects of applying a transaction which are recorded alongside it in the block, such as
+/// the amounts paid out when closing accounts, the rewards distributed and the eval delta of an
+/// application call.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlgorandApplyData {
+    #[serde(rename = "aca", skip_serializing_if = "is_zero_option")]
+    pub asset_closing_amount: Option<u64>,
+
+    /// ## Application ID
+    ///
+    /// The ID of the application created by the transaction, if any.
+    #[serde(rename = "apid", skip_serializing_if = "is_zero_option")]
+    pub application_id: Option<u64>,
+
+    #[serde(rename = "ca", skip_serializing_if = "is_zero_option")]
+    pub closing_amount: Option<u64>,
+
+    /// ## Config Asset
+    ///
+    /// The ID of the asset created by the transaction, if any.
+    #[serde(rename = "caid", skip_serializing_if = "is_zero_option")]
+    pub config_asset: Option<u64>,
+
+    #[serde(rename = "dt")]
+    pub eval_delta: Option<AlgorandEvalDelta>,
+
+    #[serde(rename = "rc", skip_serializing_if = "is_zero_option")]
+    pub close_rewards: Option<u64>,
+
+    #[serde(rename = "rr", skip_serializing_if = "is_zero_option")]
+    pub receiver_rewards: Option<u64>,
+
+    #[serde(rename = "rs", skip_serializing_if = "is_zero_option")]
+    pub sender_rewards: Option<u64>,
+}
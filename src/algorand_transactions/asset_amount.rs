@@ -0,0 +1,379 @@
+use std::{cmp::Ordering, fmt};
+
+use crate::{
+    algorand_traits::ToAssetBaseUnits,
+    algorand_transactions::asset_parameters::AssetParameters,
+    algorand_types::Result,
+};
+
+/// The most decimals an asset may have, per the consensus params.
+pub(crate) const MAX_ASSET_DECIMALS: u64 = 19;
+
+/// ## Parse Decimal Str
+///
+/// Parse a non-negative decimal string such as `1.5` into base units given the number of
+/// decimals, failing if it has more decimal places than that or overflows a `u64`.
+pub(crate) fn parse_decimal_str(s: &str, decimals: u64) -> Result<u64> {
+    let is_digits = |x: &str| !x.is_empty() && x.chars().all(|c| c.is_ascii_digit());
+    let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+    if !is_digits(whole) || (s.contains('.') && !is_digits(fraction)) {
+        return Err(format!("Cannot parse '{s}' as a decimal amount!").into());
+    };
+    if fraction.len() as u64 > decimals {
+        return Err(format!(
+            "Cannot parse '{s}' since it has more than {decimals} decimal places!"
+        )
+        .into());
+    };
+    let overflow_error = || format!("Cannot parse '{s}' since it overflows a u64!");
+    let padded_fraction = format!("{fraction:0<width$}", width = decimals as usize);
+    let whole_base_units = whole
+        .parse::<u64>()
+        .ok()
+        .and_then(|x| x.checked_mul(10u64.pow(decimals as u32)))
+        .ok_or_else(overflow_error)?;
+    let fraction_base_units = if padded_fraction.is_empty() {
+        0
+    } else {
+        padded_fraction
+            .parse::<u64>()
+            .map_err(|_| overflow_error())?
+    };
+    Ok(whole_base_units
+        .checked_add(fraction_base_units)
+        .ok_or_else(overflow_error)?)
+}
+
+/// ## Format Decimal
+///
+/// Format an amount of base units as a decimal string with exactly the given number of decimal
+/// places.
+pub(crate) fn format_decimal(base_units: u64, decimals: u64) -> String {
+    if decimals == 0 {
+        return base_units.to_string();
+    };
+    let divisor = 10u128.pow(decimals as u32);
+    format!(
+        "{}.{:0width$}",
+        base_units as u128 / divisor,
+        base_units as u128 % divisor,
+        width = decimals as usize
+    )
+}
+
+/// ## Asset Amount
+///
+/// An amount of an asset tied to the number of decimals the asset has, such that it can be
+/// shown to and accepted from users as a decimal amount, whilst being sent in base units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetAmount {
+    base_units: u64,
+    decimals: u64,
+}
+
+// NOTE: Amounts with different decimals are amounts of different assets, so are incomparable.
+impl PartialOrd for AssetAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self.decimals == other.decimals {
+            self.base_units.partial_cmp(&other.base_units)
+        } else {
+            None
+        }
+    }
+}
+
+impl AssetAmount {
+    fn check_decimals(decimals: u64) -> Result<()> {
+        if decimals > MAX_ASSET_DECIMALS {
+            Err(format!(
+                "An asset cannot have {decimals} decimals, the maximum is {MAX_ASSET_DECIMALS}!"
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// ## From Base Units
+    ///
+    /// Create an amount from a number of base units of an asset with the given decimals.
+    pub fn from_base_units(base_units: u64, decimals: u64) -> Result<Self> {
+        Self::check_decimals(decimals)?;
+        Ok(Self {
+            base_units,
+            decimals,
+        })
+    }
+
+    /// ## From Decimal Str
+    ///
+    /// Parse a decimal string such as `12.5` into an amount of an asset with the given decimals.
+    pub fn from_decimal_str(s: &str, decimals: u64) -> Result<Self> {
+        Self::check_decimals(decimals)?;
+        Self::from_base_units(parse_decimal_str(s.trim(), decimals)?, decimals)
+    }
+
+    pub fn base_units(&self) -> u64 {
+        self.base_units
+    }
+
+    pub fn decimals(&self) -> u64 {
+        self.decimals
+    }
+
+    fn check_same_decimals(&self, other: &Self) -> Result<()> {
+        if self.decimals == other.decimals {
+            Ok(())
+        } else {
+            Err(format!(
+                "Cannot combine asset amounts with {} and {} decimals!",
+                self.decimals, other.decimals
+            )
+            .into())
+        }
+    }
+
+    /// ## Checked Add
+    ///
+    /// Add another amount of the same asset, failing on overflow or if the decimals differ.
+    pub fn checked_add(&self, other: &Self) -> Result<Self> {
+        self.check_same_decimals(other)?;
+        match self.base_units.checked_add(other.base_units) {
+            Some(base_units) => Self::from_base_units(base_units, self.decimals),
+            None => Err(format!("Adding {other} to {self} overflows!").into()),
+        }
+    }
+
+    /// ## Checked Sub
+    ///
+    /// Subtract another amount of the same asset, failing on underflow or if the decimals differ.
+    pub fn checked_sub(&self, other: &Self) -> Result<Self> {
+        self.check_same_decimals(other)?;
+        match self.base_units.checked_sub(other.base_units) {
+            Some(base_units) => Self::from_base_units(base_units, self.decimals),
+            None => Err(format!("Subtracting {other} from {self} underflows!").into()),
+        }
+    }
+
+    /// ## Checked Mul
+    ///
+    /// Multiply the amount by a whole number, failing on overflow.
+    pub fn checked_mul(&self, multiplier: u64) -> Result<Self> {
+        match self.base_units.checked_mul(multiplier) {
+            Some(base_units) => Self::from_base_units(base_units, self.decimals),
+            None => Err(format!("Multiplying {self} by {multiplier} overflows!").into()),
+        }
+    }
+}
+
+impl fmt::Display for AssetAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_decimal(self.base_units, self.decimals))
+    }
+}
+
+impl From<AssetAmount> for u64 {
+    fn from(amount: AssetAmount) -> Self {
+        amount.base_units
+    }
+}
+
+impl ToAssetBaseUnits for AssetAmount {
+    fn to_asset_base_units(&self) -> u64 {
+        self.base_units
+    }
+}
+
+impl AssetParameters {
+    /// ## Amount
+    ///
+    /// Get an amount of this asset from a number of base units.
+    pub fn amount(&self, base_units: u64) -> Result<AssetAmount> {
+        AssetAmount::from_base_units(base_units, self.decimals)
+    }
+
+    /// ## Parse Amount
+    ///
+    /// Parse a decimal string such as `12.5` into an amount of this asset.
+    pub fn parse_amount(&self, s: &str) -> Result<AssetAmount> {
+        AssetAmount::from_decimal_str(s, self.decimals)
+    }
+
+    /// ## Total Amount
+    ///
+    /// Get the total supply of this asset.
+    pub fn total_amount(&self) -> Result<AssetAmount> {
+        self.amount(self.total_base_units)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_errors::AlgorandError;
+
+    #[test]
+    fn should_parse_decimal_str_into_base_units() {
+        let result = AssetAmount::from_decimal_str("12.5", 6).unwrap();
+        assert_eq!(result.base_units(), 12_500_000);
+        assert_eq!(result.decimals(), 6);
+    }
+
+    #[test]
+    fn should_parse_whole_amount_of_asset_with_no_decimals() {
+        let result = AssetAmount::from_decimal_str(" 42 ", 0).unwrap();
+        assert_eq!(result.base_units(), 42);
+    }
+
+    #[test]
+    fn should_parse_amount_with_max_decimals() {
+        let result = AssetAmount::from_decimal_str("1.0000000000000000001", 19).unwrap();
+        assert_eq!(result.base_units(), 10_000_000_000_000_000_001);
+    }
+
+    #[test]
+    fn should_fail_to_parse_amount_with_too_many_decimal_places() {
+        let expected_error = "Cannot parse '1.2345' since it has more than 3 decimal places!";
+        match AssetAmount::from_decimal_str("1.2345", 3) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_malformed_amounts() {
+        for s in ["", "-1", "+1", "1.", ".5", "1.2.3", "1e6", "one"] {
+            let expected_error = format!("Cannot parse '{s}' as a decimal amount!");
+            match AssetAmount::from_decimal_str(s, 6) {
+                Ok(_) => panic!("Should not have succeeded!"),
+                Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+                Err(_) => panic!("Wrong error received!"),
+            }
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_amount_overflowing_u64() {
+        let expected_error = "Cannot parse '18446744073709.551616' since it overflows a u64!";
+        match AssetAmount::from_decimal_str("18446744073709.551616", 6) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_create_amount_with_too_many_decimals() {
+        let expected_error = "An asset cannot have 20 decimals, the maximum is 19!";
+        match AssetAmount::from_base_units(1, 20) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_format_amount_with_decimals() {
+        assert_eq!(
+            AssetAmount::from_base_units(12_500_000, 6)
+                .unwrap()
+                .to_string(),
+            "12.500000"
+        );
+        assert_eq!(
+            AssetAmount::from_base_units(5, 2).unwrap().to_string(),
+            "0.05"
+        );
+        assert_eq!(
+            AssetAmount::from_base_units(42, 0).unwrap().to_string(),
+            "42"
+        );
+        assert_eq!(
+            AssetAmount::from_base_units(u64::MAX, 19)
+                .unwrap()
+                .to_string(),
+            "1.8446744073709551615"
+        );
+    }
+
+    #[test]
+    fn should_add_and_subtract_amounts() {
+        let a = AssetAmount::from_decimal_str("1.5", 2).unwrap();
+        let b = AssetAmount::from_decimal_str("0.25", 2).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap().to_string(), "1.75");
+        assert_eq!(a.checked_sub(&b).unwrap().to_string(), "1.25");
+        assert_eq!(b.checked_mul(3).unwrap().to_string(), "0.75");
+    }
+
+    #[test]
+    fn should_fail_to_subtract_larger_amount() {
+        let a = AssetAmount::from_decimal_str("0.25", 2).unwrap();
+        let b = AssetAmount::from_decimal_str("1.5", 2).unwrap();
+        let expected_error = "Subtracting 1.50 from 0.25 underflows!";
+        match a.checked_sub(&b) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_add_amount_overflowing_u64() {
+        let a = AssetAmount::from_base_units(u64::MAX, 0).unwrap();
+        let b = AssetAmount::from_base_units(1, 0).unwrap();
+        let expected_error = format!("Adding 1 to {} overflows!", u64::MAX);
+        match a.checked_add(&b) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_combine_amounts_with_different_decimals() {
+        let a = AssetAmount::from_base_units(1, 2).unwrap();
+        let b = AssetAmount::from_base_units(1, 6).unwrap();
+        let expected_error = "Cannot combine asset amounts with 2 and 6 decimals!";
+        match a.checked_add(&b) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_compare_amounts_with_same_decimals() {
+        let smaller = AssetAmount::from_base_units(1, 2).unwrap();
+        let larger = AssetAmount::from_base_units(2, 2).unwrap();
+        assert!(smaller < larger);
+        assert_eq!(smaller.partial_cmp(&smaller), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn should_not_compare_amounts_with_different_decimals() {
+        let a = AssetAmount::from_base_units(1, 2).unwrap();
+        let b = AssetAmount::from_base_units(100, 0).unwrap();
+        assert_eq!(a.partial_cmp(&b), None);
+        assert!(!a.lt(&b) && !a.gt(&b) && a != b);
+    }
+
+    #[test]
+    fn should_get_amounts_of_asset() {
+        let params = AssetParameters::new(
+            None,
+            None,
+            None,
+            None,
+            6,
+            false,
+            None,
+            None,
+            None,
+            1_000_000_000,
+            None,
+        );
+        assert_eq!(params.total_amount().unwrap().to_string(), "1000.000000");
+        assert_eq!(params.parse_amount("0.000001").unwrap().base_units(), 1);
+    }
+}
@@ -4,12 +4,14 @@ use serde_with::skip_serializing_none;
 
 use crate::{
     algorand_address::AlgorandAddress,
-    algorand_constants::ALGORAND_MAX_NUM_ROUNDS,
     algorand_hash::AlgorandHash,
     algorand_micro_algos::MicroAlgos,
     algorand_transactions::{
         asset_parameters::{AssetParameters, AssetParametersJson},
+        asset_reconfiguration::AssetReconfiguration,
+        suggested_params::SuggestedParams,
         transaction::AlgorandTransaction,
+        transaction_builder::TransactionBuilder,
         transaction_type::AlgorandTransactionType,
     },
     algorand_types::Result,
@@ -42,32 +44,96 @@ impl AlgorandTransaction {
         last_valid_round: Option<u64>,
         asset_parameters: AssetParameters,
     ) -> Result<Self> {
-        let calculated_last_valid_round =
-            Self::calculate_last_valid_round(first_valid_round, last_valid_round)?;
-        if calculated_last_valid_round > first_valid_round + ALGORAND_MAX_NUM_ROUNDS {
-            return Err(format!(
-                "Last valid round of {calculated_last_valid_round} is > {ALGORAND_MAX_NUM_ROUNDS} away from first valid round of {first_valid_round}!"
-            )
-            .into());
-        };
-        Ok(Self {
-            sender: Some(sender),
-            genesis_hash: Some(genesis_hash),
-            asset_parameters: Some(asset_parameters),
-            first_valid_round: Some(first_valid_round),
-            fee: Some(fee.check_if_satisfies_minimum_fee()?.0),
-            last_valid_round: Some(calculated_last_valid_round),
-            txn_type: Some(AlgorandTransactionType::AssetConfiguration),
-            ..Default::default()
-        })
+        TransactionBuilder::asset_configuration(sender, asset_parameters)
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash)
+            .build()
+    }
+
+    /// ## New Asset Configuration Transaction With Params
+    ///
+    /// Create an asset configuration transaction, taking the fee, validity window and genesis
+    /// information from the given suggested params.
+    pub fn new_asset_configuration_tx_with_params(
+        sender: AlgorandAddress,
+        asset_parameters: AssetParameters,
+        params: &SuggestedParams,
+    ) -> Result<Self> {
+        TransactionBuilder::asset_configuration(sender, asset_parameters)
+            .suggested_params(params)
+            .build()
+    }
+
+    /// ## Asset Reconfigure
+    ///
+    /// Create a transaction changing the role addresses of an asset with the given current
+    /// parameters. Any role not explicitly changed keeps its current address, and clearing a role
+    /// is refused unless the changes explicitly allow it.
+    #[allow(clippy::too_many_arguments)]
+    pub fn asset_reconfigure(
+        asset_id: u64,
+        fee: MicroAlgos,
+        first_valid_round: u64,
+        sender: AlgorandAddress,
+        current_asset_parameters: &AssetParameters,
+        changes: &AssetReconfiguration,
+        genesis_hash: AlgorandHash,
+        last_valid_round: Option<u64>,
+    ) -> Result<Self> {
+        TransactionBuilder::asset_reconfigure(sender, asset_id, current_asset_parameters, changes)?
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash)
+            .build()
+    }
+
+    /// ## Asset Reconfigure With Params
+    ///
+    /// Create a transaction changing the role addresses of an asset, taking the fee, validity
+    /// window and genesis information from the given suggested params.
+    pub fn asset_reconfigure_with_params(
+        asset_id: u64,
+        sender: AlgorandAddress,
+        current_asset_parameters: &AssetParameters,
+        changes: &AssetReconfiguration,
+        params: &SuggestedParams,
+    ) -> Result<Self> {
+        TransactionBuilder::asset_reconfigure(sender, asset_id, current_asset_parameters, changes)?
+            .suggested_params(params)
+            .build()
+    }
+
+    /// ## Configured Asset ID
+    ///
+    /// Get the ID of the asset an asset configuration transaction configures. A transaction
+    /// creating an asset carries no asset ID, so that of the created asset is used, which is only
+    /// known for transactions fetched from a node or the indexer.
+    pub fn configured_asset_id(&self) -> Option<u64> {
+        match self.txn_type {
+            Some(AlgorandTransactionType::AssetConfiguration) => self
+                .asset_id
+                .filter(|asset_id| *asset_id != 0)
+                .or(self.created_asset_index)
+                .or_else(|| self.apply_data.as_ref().and_then(|x| x.config_asset)),
+            _ => None,
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
+
     use super::*;
     use crate::{
+        algorand_constants::ALGORAND_MAINNET_GENESIS_ID,
+        algorand_errors::AlgorandError,
         algorand_traits::ToMsgPackBytes,
+        algorand_transactions::{
+            asset_reconfiguration::AssetRoleChange,
+            test_utils::get_sample_asset_reconfiguration_tx,
+        },
         test_utils::{get_sample_algorand_address, get_sample_algorand_keys},
     };
 
@@ -106,4 +172,89 @@ mod tests {
         let expected_result = "82a3736967c440c5fdf6bff79e8e2e73c71cc4e512a3290a8fdaadc17d66a909d0893955e830a6acc239faba4b2374668f7b30da2c4636246b998ae85299e63e308947c3dbff0ca374786e87a4617061728aa2616dc420c061c4d8fc1dbdded2d7604be4568e3f6d041987ac37bde4b620b5ab39248adfa2616eaa5465737420546f6b656ea26175aa676f6f676c652e636f6da163c42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da2646312a166c42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da16dc42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da172c42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da174ce000f4240a2756ea474544b4ea3666565cd03e8a26676cd03e8a26768c420c061c4d8fc1dbdded2d7604be4568e3f6d041987ac37bde4b620b5ab39248adfa26c76cd07d0a3736e64c42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da474797065a461636667";
         assert_eq!(result, expected_result);
     }
+
+    #[test]
+    fn should_get_id_of_asset_reconfiguration_tx() {
+        let tx = get_sample_asset_reconfiguration_tx();
+        let result = tx.to_id().unwrap();
+        let expected_result = "VXSNM3GES4RGMD7DK4PJ5TPH7MXFQDYKENQWKTWBGLPNXJRAGO4Q";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_create_asset_reconfiguration_tx() {
+        let sample_tx = get_sample_asset_reconfiguration_tx();
+        let manager = sample_tx.sender.unwrap();
+        let current_asset_parameters = AssetParameters::new(
+            None,
+            None,
+            None,
+            None,
+            0,
+            false,
+            None,
+            Some(manager),
+            Some(manager),
+            1,
+            None,
+        );
+        let changes = AssetReconfiguration::default().with_reserve(AssetRoleChange::Set(
+            AlgorandAddress::from_str("LCD6BFL4VEC7SLWQDX5UK7ITZUU4F55AYFV4CFTMKCPASYXCRIQKUIGHEM")
+                .unwrap(),
+        ));
+        let mut result = AlgorandTransaction::asset_reconfigure(
+            808963320,
+            MicroAlgos::minimum_fee(),
+            34866581,
+            manager,
+            &current_asset_parameters,
+            &changes,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            Some(34867581),
+        )
+        .unwrap();
+        result.genesis_id = Some(ALGORAND_MAINNET_GENESIS_ID.to_string());
+        result.note = sample_tx.note.clone();
+        assert_eq!(
+            result.to_id().unwrap(),
+            "VXSNM3GES4RGMD7DK4PJ5TPH7MXFQDYKENQWKTWBGLPNXJRAGO4Q"
+        );
+    }
+
+    #[test]
+    fn should_fail_to_reconfigure_asset_from_non_manager() {
+        let manager = get_sample_algorand_address();
+        let sender =
+            AlgorandAddress::from_str("IL5YIRUX577LJ5FVOMATHTB6XR7KQDJTGWG24VLDGFHU2NOWKH67UL2ULI")
+                .unwrap();
+        let current_asset_parameters = AssetParameters::new(
+            None,
+            None,
+            None,
+            None,
+            0,
+            false,
+            None,
+            Some(manager),
+            None,
+            1,
+            None,
+        );
+        let expected_error =
+            format!("Cannot reconfigure asset 1337 from {sender} since its manager is {manager}!");
+        match AlgorandTransaction::asset_reconfigure(
+            1337,
+            MicroAlgos::minimum_fee(),
+            1000,
+            sender,
+            &current_asset_parameters,
+            &AssetReconfiguration::default(),
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            None,
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
 }
@@ -3,8 +3,9 @@ use crate::{
     algorand_hash::AlgorandHash,
     algorand_micro_algos::MicroAlgos,
     algorand_transactions::{
+        suggested_params::SuggestedParams,
         transaction::AlgorandTransaction,
-        transaction_type::AlgorandTransactionType,
+        transaction_builder::TransactionBuilder,
     },
     algorand_types::Result,
 };
@@ -23,18 +24,25 @@ impl AlgorandTransaction {
         genesis_hash: AlgorandHash,
         last_valid_round: Option<u64>,
     ) -> Result<Self> {
-        let calculated_last_valid_round =
-            Self::calculate_last_valid_round(first_valid_round, last_valid_round)?;
-        Ok(Self {
-            sender: Some(sender),
-            asset_id: Some(asset_id),
-            genesis_hash: Some(genesis_hash),
-            first_valid_round: Some(first_valid_round),
-            fee: Some(fee.check_if_satisfies_minimum_fee()?.0),
-            last_valid_round: Some(calculated_last_valid_round),
-            txn_type: Some(AlgorandTransactionType::AssetConfiguration),
-            ..Default::default()
-        })
+        TransactionBuilder::asset_destroy(sender, asset_id)
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash)
+            .build()
+    }
+
+    /// ## New Asset Destroy Transaction With Params
+    ///
+    /// Create an asset destroy transaction, taking the fee, validity window and genesis
+    /// information from the given suggested params.
+    pub fn new_asset_destroy_tx_with_params(
+        asset_id: u64,
+        sender: AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<Self> {
+        TransactionBuilder::asset_destroy(sender, asset_id)
+            .suggested_params(params)
+            .build()
     }
 }
 
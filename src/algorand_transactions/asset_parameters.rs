@@ -15,13 +15,13 @@ use crate::{
 #[skip_serializing_none]
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct AssetParameters {
-    #[serde(rename(serialize = "am"))]
+    #[serde(rename = "am")]
     pub metadata_hash: Option<AlgorandHash>,
 
-    #[serde(rename(serialize = "an"))]
+    #[serde(rename = "an")]
     pub asset_name: Option<String>,
 
-    #[serde(rename(serialize = "au"))]
+    #[serde(rename = "au")]
     pub asset_url: Option<String>,
 
     /// ## Clawback Address
@@ -30,16 +30,16 @@ pub struct AssetParameters {
     /// any asset holder (assuming they have opted-in). Use this if you need the option to revoke
     /// assets from an account (like if they breach certain contractual obligations tied to holding
     /// the asset). In traditional finance, this sort of transaction is referred to as a clawback.
-    #[serde(rename(serialize = "c"))]
+    #[serde(rename = "c")]
     pub clawback_address: Option<AlgorandAddress>,
 
-    #[serde(rename(serialize = "dc"), skip_serializing_if = "is_zero")]
+    #[serde(rename = "dc", default, skip_serializing_if = "is_zero")]
     pub decimals: u64,
 
     /// ## Default Frozen
     ///
     /// Whether the asset is created in a froze state.
-    #[serde(rename(serialize = "df"), skip_serializing_if = "is_false")]
+    #[serde(rename = "df", skip_serializing_if = "is_false")]
     pub default_frozen: Option<bool>,
 
     /// ## Freeze Address
@@ -50,14 +50,14 @@ pub struct AssetParameters {
     /// stock, to investigate suspected criminal activity or to deny-list certain accounts. If the
     /// DefaultFrozen state is set to True, you can use the unfreeze action to authorize certain
     /// accounts to trade the asset (such as after passing KYC/AML checks).
-    #[serde(rename(serialize = "f"))]
+    #[serde(rename = "f")]
     pub freeze_address: Option<AlgorandAddress>,
 
     /// ## Manager Address
     ///
     /// The manager account is the only account that can authorize transactions to re-configure or
     /// destroy an asset.
-    #[serde(rename(serialize = "m"))]
+    #[serde(rename = "m")]
     pub manager_address: Option<AlgorandAddress>,
 
     /// ## Reserve Address
@@ -67,17 +67,18 @@ pub struct AssetParameters {
     /// units of the asset. If you specify a new reserve address, you must make sure the new
     /// account has opted into the asset and then issue a transaction to transfer all assets to the
     /// new reserve.
-    #[serde(rename(serialize = "r"))]
+    #[serde(rename = "r")]
     pub reserve_address: Option<AlgorandAddress>,
 
-    #[serde(rename(serialize = "t"))]
+    #[serde(rename = "t", default, skip_serializing_if = "is_zero")]
     pub total_base_units: u64,
 
-    #[serde(rename(serialize = "un"))]
+    #[serde(rename = "un")]
     pub unit_name: Option<String>,
 }
 
 impl AssetParameters {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         metadata_hash: Option<AlgorandHash>,
         asset_name: Option<String>,
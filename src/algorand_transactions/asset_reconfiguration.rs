@@ -0,0 +1,232 @@
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_transactions::asset_parameters::AssetParameters,
+    algorand_types::Result,
+};
+
+/// ## Asset Role Change
+///
+/// How a reconfiguration changes one of an asset's role addresses. Note that a cleared role can
+/// never be set again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AssetRoleChange {
+    /// Keep the current address, if any.
+    #[default]
+    Keep,
+
+    /// Set the role to the given address.
+    Set(AlgorandAddress),
+
+    /// Clear the role for good.
+    Clear,
+}
+
+impl AssetRoleChange {
+    fn apply(&self, current_address: Option<AlgorandAddress>) -> Option<AlgorandAddress> {
+        match self {
+            Self::Keep => current_address,
+            Self::Set(address) => Some(*address),
+            Self::Clear => None,
+        }
+    }
+}
+
+/// ## Asset Reconfiguration
+///
+/// The changes to make to an asset's role addresses, where any role not explicitly changed keeps
+/// its current address. Clearing a role is refused unless explicitly allowed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AssetReconfiguration {
+    pub manager: AssetRoleChange,
+    pub reserve: AssetRoleChange,
+    pub freeze: AssetRoleChange,
+    pub clawback: AssetRoleChange,
+    pub allow_clearing_roles: bool,
+}
+
+impl AssetReconfiguration {
+    /// ## With Manager
+    ///
+    /// Set how the asset's manager address is to change.
+    pub fn with_manager(mut self, change: AssetRoleChange) -> Self {
+        self.manager = change;
+        self
+    }
+
+    /// ## With Reserve
+    ///
+    /// Set how the asset's reserve address is to change.
+    pub fn with_reserve(mut self, change: AssetRoleChange) -> Self {
+        self.reserve = change;
+        self
+    }
+
+    /// ## With Freeze
+    ///
+    /// Set how the asset's freeze address is to change.
+    pub fn with_freeze(mut self, change: AssetRoleChange) -> Self {
+        self.freeze = change;
+        self
+    }
+
+    /// ## With Clawback
+    ///
+    /// Set how the asset's clawback address is to change.
+    pub fn with_clawback(mut self, change: AssetRoleChange) -> Self {
+        self.clawback = change;
+        self
+    }
+
+    /// ## Allowing Role Clearing
+    ///
+    /// Allow this reconfiguration to clear roles for good.
+    pub fn allowing_role_clearing(mut self) -> Self {
+        self.allow_clearing_roles = true;
+        self
+    }
+}
+
+impl AssetParameters {
+    /// ## Reconfigure
+    ///
+    /// Get the parameters to send in a transaction reconfiguring an asset with these current
+    /// parameters. Only the role addresses of an asset may be changed, so only those are set,
+    /// with any role not explicitly changed keeping its current address. Fails if a role which
+    /// is currently set would be cleared without that being allowed, or if every role would end
+    /// up cleared, since that is indistinguishable from destroying the asset.
+    pub fn reconfigure(&self, changes: &AssetReconfiguration) -> Result<Self> {
+        let roles = [
+            ("manager", self.manager_address, changes.manager),
+            ("reserve", self.reserve_address, changes.reserve),
+            ("freeze", self.freeze_address, changes.freeze),
+            ("clawback", self.clawback_address, changes.clawback),
+        ];
+        let cleared_roles = roles
+            .iter()
+            .filter(|(_, address, change)| address.is_some() && change.apply(*address).is_none())
+            .map(|(role, ..)| *role)
+            .collect::<Vec<&str>>();
+        if !cleared_roles.is_empty() && !changes.allow_clearing_roles {
+            return Err(format!(
+                "Refusing to clear the asset's {} address(es) unless clearing roles is allowed!",
+                cleared_roles.join(", ")
+            )
+            .into());
+        };
+        let [manager, reserve, freeze, clawback] =
+            roles.map(|(_, address, change)| change.apply(address));
+        // NOTE: A reconfiguration with no role addresses encodes as empty parameters, which the
+        // protocol treats as destroying the asset.
+        if [manager, reserve, freeze, clawback]
+            .iter()
+            .all(Option::is_none)
+        {
+            return Err(
+                "Refusing to leave the asset with no role addresses since that would destroy it - use `asset_destroy` for that instead!"
+                    .into(),
+            );
+        };
+        Ok(Self::new(
+            None, None, None, clawback, 0, false, freeze, manager, reserve, 0, None,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{algorand_errors::AlgorandError, test_utils::get_sample_algorand_address};
+
+    fn get_sample_asset_parameters() -> AssetParameters {
+        let address = get_sample_algorand_address();
+        AssetParameters::new(
+            None,
+            Some("Test Token".to_string()),
+            None,
+            Some(address),
+            6,
+            false,
+            Some(address),
+            Some(address),
+            Some(address),
+            1_000_000,
+            Some("tTKN".to_string()),
+        )
+    }
+
+    #[test]
+    fn should_keep_unchanged_roles_when_reconfiguring() {
+        let new_reserve =
+            AlgorandAddress::from_str("IL5YIRUX577LJ5FVOMATHTB6XR7KQDJTGWG24VLDGFHU2NOWKH67UL2ULI")
+                .unwrap();
+        let changes =
+            AssetReconfiguration::default().with_reserve(AssetRoleChange::Set(new_reserve));
+        let result = get_sample_asset_parameters().reconfigure(&changes).unwrap();
+        let address = get_sample_algorand_address();
+        let expected_result = AssetParameters::new(
+            None,
+            None,
+            None,
+            Some(address),
+            0,
+            false,
+            Some(address),
+            Some(address),
+            Some(new_reserve),
+            0,
+            None,
+        );
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_refuse_to_clear_roles_unless_allowed() {
+        let changes = AssetReconfiguration::default()
+            .with_freeze(AssetRoleChange::Clear)
+            .with_clawback(AssetRoleChange::Clear);
+        let expected_error =
+            "Refusing to clear the asset's freeze, clawback address(es) unless clearing roles is allowed!";
+        match get_sample_asset_parameters().reconfigure(&changes) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_clear_roles_when_allowed() {
+        let changes = AssetReconfiguration::default()
+            .with_clawback(AssetRoleChange::Clear)
+            .allowing_role_clearing();
+        let result = get_sample_asset_parameters().reconfigure(&changes).unwrap();
+        assert_eq!(result.clawback_address, None);
+        assert_eq!(result.manager_address, Some(get_sample_algorand_address()));
+    }
+
+    #[test]
+    fn should_not_count_already_cleared_role_as_cleared() {
+        let mut current_parameters = get_sample_asset_parameters();
+        current_parameters.clawback_address = None;
+        let changes = AssetReconfiguration::default().with_clawback(AssetRoleChange::Clear);
+        let result = current_parameters.reconfigure(&changes).unwrap();
+        assert_eq!(result.clawback_address, None);
+    }
+
+    #[test]
+    fn should_refuse_to_clear_every_role_even_when_allowed() {
+        let changes = AssetReconfiguration::default()
+            .with_manager(AssetRoleChange::Clear)
+            .with_reserve(AssetRoleChange::Clear)
+            .with_freeze(AssetRoleChange::Clear)
+            .with_clawback(AssetRoleChange::Clear)
+            .allowing_role_clearing();
+        let expected_error = "Refusing to leave the asset with no role addresses since that would destroy it - use `asset_destroy` for that instead!";
+        match get_sample_asset_parameters().reconfigure(&changes) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
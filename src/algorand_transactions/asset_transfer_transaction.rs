@@ -7,9 +7,11 @@ use crate::{
     algorand_address::AlgorandAddress,
     algorand_hash::AlgorandHash,
     algorand_micro_algos::MicroAlgos,
+    algorand_traits::ToAssetBaseUnits,
     algorand_transactions::{
+        suggested_params::SuggestedParams,
         transaction::AlgorandTransaction,
-        transaction_type::AlgorandTransactionType,
+        transaction_builder::TransactionBuilder,
     },
     algorand_types::{Bytes, Result},
 };
@@ -82,10 +84,11 @@ impl AlgorandTransaction {
     ///
     /// Assets can be transferred between accounts that have opted-in to receiving the asset. These
     /// are analogous to standard payment transactions but for Algorand Standard Assets.
+    #[allow(clippy::too_many_arguments)]
     pub fn asset_transfer(
         asset_id: u64,
         fee: MicroAlgos,
-        asset_amount: u64,
+        asset_amount: impl ToAssetBaseUnits,
         note: Option<Bytes>,
         first_valid_round: u64,
         sender: AlgorandAddress,
@@ -93,22 +96,38 @@ impl AlgorandTransaction {
         last_valid_round: Option<u64>,
         asset_receiver: AlgorandAddress,
     ) -> Result<AlgorandTransaction> {
-        Ok(Self {
-            note,
-            sender: Some(sender),
-            genesis_hash: Some(genesis_hash),
-            asset_amount: Some(asset_amount),
-            transfer_asset_id: Some(asset_id),
-            asset_receiver: Some(asset_receiver),
-            first_valid_round: Some(first_valid_round),
-            fee: Some(fee.check_if_satisfies_minimum_fee()?.0),
-            txn_type: Some(AlgorandTransactionType::AssetTransfer),
-            last_valid_round: Some(Self::calculate_last_valid_round(
-                first_valid_round,
-                last_valid_round,
-            )?),
-            ..Default::default()
-        })
+        let builder =
+            TransactionBuilder::asset_transfer(sender, asset_id, asset_amount, asset_receiver)
+                .fee(fee)
+                .validity_window(first_valid_round, last_valid_round)
+                .genesis_hash(genesis_hash);
+        match note {
+            Some(note) => builder.note(note),
+            None => builder,
+        }
+        .build()
+    }
+
+    /// ## Asset Transfer With Params
+    ///
+    /// Create an asset transfer transaction, taking the fee, validity window and genesis
+    /// information from the given suggested params.
+    pub fn asset_transfer_with_params(
+        asset_id: u64,
+        asset_amount: impl ToAssetBaseUnits,
+        note: Option<Bytes>,
+        sender: AlgorandAddress,
+        asset_receiver: AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<AlgorandTransaction> {
+        let builder =
+            TransactionBuilder::asset_transfer(sender, asset_id, asset_amount, asset_receiver)
+                .suggested_params(params);
+        match note {
+            Some(note) => builder.note(note),
+            None => builder,
+        }
+        .build()
     }
 
     /// Asset Opt In
@@ -125,21 +144,124 @@ impl AlgorandTransaction {
         genesis_hash: AlgorandHash,
         last_valid_round: Option<u64>,
     ) -> Result<AlgorandTransaction> {
-        Ok(Self {
-            asset_amount: None,
-            sender: Some(sender),
-            asset_receiver: Some(sender),
-            genesis_hash: Some(genesis_hash),
-            transfer_asset_id: Some(asset_id),
-            first_valid_round: Some(first_valid_round),
-            fee: Some(fee.check_if_satisfies_minimum_fee()?.0),
-            txn_type: Some(AlgorandTransactionType::AssetTransfer),
-            last_valid_round: Some(Self::calculate_last_valid_round(
-                first_valid_round,
-                last_valid_round,
-            )?),
-            ..Default::default()
-        })
+        TransactionBuilder::asset_opt_in(sender, asset_id)
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash)
+            .build()
+    }
+
+    /// ## Asset Opt In With Params
+    ///
+    /// Create an asset opt-in transaction, taking the fee, validity window and genesis
+    /// information from the given suggested params.
+    pub fn asset_opt_in_with_params(
+        asset_id: u64,
+        sender: AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<AlgorandTransaction> {
+        TransactionBuilder::asset_opt_in(sender, asset_id)
+            .suggested_params(params)
+            .build()
+    }
+
+    /// ## Asset Clawback
+    ///
+    /// Revoke an amount of an asset from an account, sending it to the given receiver. The
+    /// transaction is sent and signed by the asset's clawback address, with the revoked account
+    /// set as the asset sender.
+    #[allow(clippy::too_many_arguments)]
+    pub fn asset_clawback(
+        asset_id: u64,
+        fee: MicroAlgos,
+        asset_amount: impl ToAssetBaseUnits,
+        first_valid_round: u64,
+        clawback_address: AlgorandAddress,
+        revoked_account: AlgorandAddress,
+        asset_receiver: AlgorandAddress,
+        genesis_hash: AlgorandHash,
+        last_valid_round: Option<u64>,
+    ) -> Result<AlgorandTransaction> {
+        TransactionBuilder::asset_clawback(
+            clawback_address,
+            asset_id,
+            asset_amount,
+            revoked_account,
+            asset_receiver,
+        )
+        .fee(fee)
+        .validity_window(first_valid_round, last_valid_round)
+        .genesis_hash(genesis_hash)
+        .build()
+    }
+
+    /// ## Asset Clawback With Params
+    ///
+    /// Revoke an amount of an asset from an account, taking the fee, validity window and genesis
+    /// information from the given suggested params.
+    pub fn asset_clawback_with_params(
+        asset_id: u64,
+        asset_amount: impl ToAssetBaseUnits,
+        clawback_address: AlgorandAddress,
+        revoked_account: AlgorandAddress,
+        asset_receiver: AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<AlgorandTransaction> {
+        TransactionBuilder::asset_clawback(
+            clawback_address,
+            asset_id,
+            asset_amount,
+            revoked_account,
+            asset_receiver,
+        )
+        .suggested_params(params)
+        .build()
+    }
+
+    /// ## Asset Close Out
+    ///
+    /// Create a transaction which closes the sender's holding of an asset, sweeping all of its
+    /// remaining balance of the asset to the given address, which must differ from the sender, and
+    /// opting the sender out of the asset.
+    pub fn asset_close_out(
+        asset_id: u64,
+        fee: MicroAlgos,
+        first_valid_round: u64,
+        sender: AlgorandAddress,
+        close_to: AlgorandAddress,
+        genesis_hash: AlgorandHash,
+        last_valid_round: Option<u64>,
+    ) -> Result<AlgorandTransaction> {
+        TransactionBuilder::asset_close_out(sender, asset_id, close_to)
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash)
+            .build()
+    }
+
+    /// ## Asset Close Out With Params
+    ///
+    /// Create a transaction which closes the sender's holding of an asset, taking the fee,
+    /// validity window and genesis information from the given suggested params.
+    pub fn asset_close_out_with_params(
+        asset_id: u64,
+        sender: AlgorandAddress,
+        close_to: AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<AlgorandTransaction> {
+        TransactionBuilder::asset_close_out(sender, asset_id, close_to)
+            .suggested_params(params)
+            .build()
+    }
+
+    /// ## Asset Closed Amount
+    ///
+    /// Get the amount of the asset swept to the close target by a confirmed transaction which
+    /// closed the sender's holding of it, on top of the amount transferred to the receiver. This
+    /// is only known for transactions fetched from a node or the indexer, so is `None` for any
+    /// other.
+    pub fn asset_closed_amount(&self) -> Option<u64> {
+        self.asset_close_to.and(self.asset_close_amount)
     }
 }
 
@@ -149,7 +271,16 @@ mod tests {
 
     use super::*;
     use crate::{
+        algorand_blocks::test_utils::get_sample_block_n,
+        algorand_constants::ALGORAND_MAINNET_GENESIS_ID,
+        algorand_errors::AlgorandError,
         algorand_hash::AlgorandHash,
+        algorand_traits::ToMsgPackBytes,
+        algorand_transactions::{
+            asset_amount::AssetAmount,
+            signing_options::SigningOptions,
+            test_utils::get_sample_txs_n,
+        },
         test_utils::{get_sample_algorand_address, get_sample_algorand_keys},
     };
 
@@ -193,7 +324,9 @@ mod tests {
                 .unwrap(),
         )
         .unwrap();
-        let signed_tx = tx.sign(&get_sample_algorand_keys()).unwrap();
+        let keys = get_sample_algorand_keys();
+        let options = SigningOptions::default().with_auth_address(keys.to_address().unwrap());
+        let signed_tx = tx.sign_with_options(&keys, &options).unwrap();
         let result = signed_tx.to_hex().unwrap();
         let expected_result = "83a473676e72c42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da3736967c440df49d27ce4b5436e7bee58ffcdbe7ff2fb87b56c96309c997f9b525dcac33a78df3992c0ab4ce92b5da3bd7933b7318fdd15e92043bc6d120047e108e437ab07a374786e89a461616d74ce05f5e639a461726376c42032a7dbdfcde7695d91ac438152fc908617ffbf9db94f843c250268e6fe21a0a0a3666565cd03e8a26676ce01121609a26768c420c061c4d8fc1dbdded2d7604be4568e3f6d041987ac37bde4b620b5ab39248adfa26c76ce011219f1a3736e64c420349567ad1fc2fee2eb65e927d5fcf22ad695ecbd1d1df01f4782dd27707189e3a474797065a56178666572a478616964ce1b9cddb0";
         assert_eq!(result, expected_result);
@@ -206,4 +339,160 @@ mod tests {
             "GBTFK2AEQMC6N262N2ESOJMQUM2QIYYVMBBGE4JHL6ORAFWPZXVQ"
         );
     }
+
+    #[test]
+    fn should_create_asset_close_out_tx() {
+        let sample_tx = get_sample_txs_n(4).0[5].clone();
+        let tx = AlgorandTransaction::asset_close_out(
+            1106508879,
+            MicroAlgos::minimum_fee(),
+            29285108,
+            sample_tx.sender.unwrap(),
+            sample_tx.asset_close_to.unwrap(),
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            Some(29286108),
+        )
+        .unwrap();
+        let mut result = tx.assign_group_id(sample_tx.group.unwrap());
+        result.genesis_id = Some(ALGORAND_MAINNET_GENESIS_ID.to_string());
+        let expected_result = "L7VP2FK2PV5UTAPSF5VGK2BTMR2BCEGV7JFNNQNDX2Q26X4H3SCQ";
+        assert_eq!(result.to_id().unwrap(), expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_close_out_asset_to_sender() {
+        let sender = get_sample_algorand_address();
+        let expected_error = format!("Cannot close asset holding of {sender} to itself!");
+        match AlgorandTransaction::asset_close_out(
+            1337,
+            MicroAlgos::minimum_fee(),
+            1000,
+            sender,
+            sender,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            None,
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_get_asset_closed_amount_of_confirmed_tx() {
+        let txs = get_sample_txs_n(4).0;
+        assert_eq!(txs[5].asset_closed_amount(), Some(1));
+        assert_eq!(txs[6].asset_closed_amount(), None);
+    }
+
+    #[test]
+    fn should_not_get_asset_closed_amount_of_unconfirmed_closing_tx() {
+        let tx = AlgorandTransaction {
+            asset_close_amount: None,
+            ..get_sample_txs_n(4).0[5].clone()
+        };
+        assert!(tx.asset_close_to.is_some());
+        assert_eq!(tx.asset_closed_amount(), None);
+    }
+
+    fn get_sample_clawback_tx() -> AlgorandTransaction {
+        AlgorandTransaction::asset_clawback(
+            456173130,
+            MicroAlgos::minimum_fee(),
+            1,
+            17962570,
+            AlgorandAddress::from_str("TS7B643HETGC6BFAYST2QOMKCJLJGNBS4QPLJ3AUVWRQAH33FXTQMNOHAU")
+                .unwrap(),
+            AlgorandAddress::from_str("TNGK4OY6TSUOOS2PSVI3CKCUTVIIJDLRNR7QRTLPFSXADQBAWL7YZEL5H4")
+                .unwrap(),
+            AlgorandAddress::from_str("IWSQCEHQ6GYARCKS6O6NCBBWJXVRDAB3PSICU2DIIF53H3DQZVBH7WPT4Y")
+                .unwrap(),
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn should_encode_asset_clawback_tx() {
+        let result = hex::encode(get_sample_clawback_tx().to_msg_pack_bytes().unwrap());
+        let expected_result = "8aa461616d7401a461726376c42045a50110f0f1b0088952f3bcd104364deb11803b7c902a6868417bb3ec70cd42a461736e64c4209b4cae3b1e9ca8e74b4f9551b128549d50848d716c7f08cd6f2cae01c020b2ffa3666565cd03e8a26676ce0112164aa26768c420c061c4d8fc1dbdded2d7604be4568e3f6d041987ac37bde4b620b5ab39248adfa26c76ce01121a32a3736e64c4209cbe1f736724cc2f04a0c4a7a8398a1256933432e41eb4ec14ada3001f7b2de7a474797065a56178666572a478616964ce1b30a64a";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_encode_asset_clawback_tx_matching_mainnet_tx() {
+        let sample_tx = get_sample_block_n(2).transactions.unwrap().0[23].clone();
+        let mut tx = get_sample_clawback_tx().assign_group_id(sample_tx.group.unwrap());
+        tx.genesis_id = Some(ALGORAND_MAINNET_GENESIS_ID.to_string());
+        let result = hex::encode(tx.to_msg_pack_bytes().unwrap());
+        let expected_result = "8ca461616d7401a461726376c42045a50110f0f1b0088952f3bcd104364deb11803b7c902a6868417bb3ec70cd42a461736e64c4209b4cae3b1e9ca8e74b4f9551b128549d50848d716c7f08cd6f2cae01c020b2ffa3666565cd03e8a26676ce0112164aa367656eac6d61696e6e65742d76312e30a26768c420c061c4d8fc1dbdded2d7604be4568e3f6d041987ac37bde4b620b5ab39248adfa3677270c420ec7c6b549354d019e6f4e14b36fa66d931b9af3669998c724139deeb047c4d38a26c76ce01121a32a3736e64c4209cbe1f736724cc2f04a0c4a7a8398a1256933432e41eb4ec14ada3001f7b2de7a474797065a56178666572a478616964ce1b30a64a";
+        assert_eq!(result, expected_result);
+        assert_eq!(
+            hex::encode(sample_tx.to_msg_pack_bytes().unwrap()),
+            expected_result
+        );
+        assert_eq!(
+            tx.to_id().unwrap(),
+            "LV43R2ECCP4UECDNMK54X4LRGKDRK6JOGBOYYQNQTGOSDR6P563Q"
+        );
+    }
+
+    #[test]
+    fn should_build_asset_clawback_tx_with_params() {
+        let params = SuggestedParams::new(
+            MicroAlgos(0),
+            17962570,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        );
+        let sample_tx = get_sample_clawback_tx();
+        let result = AlgorandTransaction::asset_clawback_with_params(
+            456173130,
+            1,
+            sample_tx.sender.unwrap(),
+            sample_tx.asset_sender.unwrap(),
+            sample_tx.asset_receiver.unwrap(),
+            &params,
+        )
+        .unwrap();
+        assert_eq!(result, sample_tx);
+    }
+
+    #[test]
+    fn should_build_asset_clawback_tx_with_asset_amount() {
+        let params = SuggestedParams::new(
+            MicroAlgos(0),
+            17962570,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        );
+        let sample_tx = get_sample_clawback_tx();
+        let result = AlgorandTransaction::asset_clawback_with_params(
+            456173130,
+            AssetAmount::from_decimal_str("0.000001", 6).unwrap(),
+            sample_tx.sender.unwrap(),
+            sample_tx.asset_sender.unwrap(),
+            sample_tx.asset_receiver.unwrap(),
+            &params,
+        )
+        .unwrap();
+        assert_eq!(result, sample_tx);
+    }
+
+    #[test]
+    fn should_create_asset_transfer_tx_with_asset_amount() {
+        let address = get_sample_algorand_address();
+        let result = AlgorandTransaction::asset_transfer(
+            463265200,
+            MicroAlgos(1000),
+            AssetAmount::from_decimal_str("100.001337", 6).unwrap(),
+            None,
+            17_962_505,
+            address,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            None,
+            address,
+        )
+        .unwrap();
+        assert_eq!(result.asset_amount, Some(100001337));
+    }
 }
@@ -22,6 +22,9 @@ pub struct KeyRegTransactionJson {
 
     #[serde(rename = "selection-participation-key")]
     pub selection_participation_key: Option<String>,
+
+    #[serde(rename = "state-proof-key")]
+    pub state_proof_key: Option<String>,
 }
 
 impl KeyRegTransactionJson {
@@ -32,7 +35,6 @@ impl KeyRegTransactionJson {
             && self.vote_key_dilution.is_none()
             && self.vote_participation_key.is_none()
             && self.selection_participation_key.is_none()
+            && self.state_proof_key.is_none()
     }
 }
-
-// TODO A fxn to make this tx type?
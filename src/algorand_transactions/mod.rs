@@ -1,16 +1,26 @@
 pub(crate) mod algorand_transaction_proof;
-mod application_transaction;
+pub(crate) mod application_transaction;
+pub(crate) mod apply_data;
+pub(crate) mod asset_amount;
 mod asset_config_transaction;
 mod asset_destroy_transaction;
 mod asset_freeze_transaction;
 pub(crate) mod asset_parameters;
+pub(crate) mod asset_reconfiguration;
 mod asset_transfer_transaction;
 mod key_reg_transaction;
 mod pay_transaction;
 mod signature_json;
-mod test_utils;
+pub(crate) mod signing_options;
+mod state_proof_transaction;
+pub(crate) mod suggested_params;
+pub(crate) mod test_utils;
 pub(crate) mod transaction;
+pub(crate) mod transaction_builder;
+pub(crate) mod transaction_fees;
 pub(crate) mod transaction_json;
 pub mod transaction_type;
+mod transaction_validation;
 pub(crate) mod transactions;
 pub(crate) mod transactions_group;
+pub(crate) mod transactions_merkle_tree;
@@ -8,8 +8,9 @@ use crate::{
     algorand_hash::AlgorandHash,
     algorand_micro_algos::MicroAlgos,
     algorand_transactions::{
+        suggested_params::SuggestedParams,
         transaction::AlgorandTransaction,
-        transaction_type::AlgorandTransactionType,
+        transaction_builder::TransactionBuilder,
     },
     algorand_types::{Bytes, Result},
 };
@@ -53,6 +54,7 @@ impl AlgorandTransaction {
     /// ## New Payment Transaction
     ///
     /// Create a new, simple payment transaction with an optional note.
+    #[allow(clippy::too_many_arguments)]
     pub fn new_payment_tx(
         amount: u64,
         fee: MicroAlgos,
@@ -63,21 +65,79 @@ impl AlgorandTransaction {
         genesis_hash: AlgorandHash,
         last_valid_round: Option<u64>,
     ) -> Result<Self> {
-        Ok(Self {
-            note,
-            sender: Some(sender),
-            amount: Some(amount),
-            receiver: Some(receiver),
-            genesis_hash: Some(genesis_hash),
-            first_valid_round: Some(first_valid_round),
-            txn_type: Some(AlgorandTransactionType::Pay),
-            fee: Some(fee.check_if_satisfies_minimum_fee()?.0),
-            last_valid_round: Some(Self::calculate_last_valid_round(
-                first_valid_round,
-                last_valid_round,
-            )?),
-            ..Default::default()
-        })
+        let builder = TransactionBuilder::payment(sender, receiver, amount)
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash);
+        match note {
+            Some(note) => builder.note(note),
+            None => builder,
+        }
+        .build()
+    }
+
+    /// ## New Payment Transaction With Params
+    ///
+    /// Create a new, simple payment transaction with an optional note, taking the fee, validity
+    /// window and genesis information from the given suggested params.
+    pub fn new_payment_tx_with_params(
+        amount: u64,
+        note: Option<Bytes>,
+        sender: AlgorandAddress,
+        receiver: AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<Self> {
+        let builder =
+            TransactionBuilder::payment(sender, receiver, amount).suggested_params(params);
+        match note {
+            Some(note) => builder.note(note),
+            None => builder,
+        }
+        .build()
+    }
+
+    /// ## New Close Account Transaction
+    ///
+    /// Create a transaction which closes the sender's account, sweeping all of its remaining
+    /// microAlgos to the given address, which must differ from the sender.
+    pub fn new_close_account_tx(
+        fee: MicroAlgos,
+        first_valid_round: u64,
+        sender: AlgorandAddress,
+        close_to: AlgorandAddress,
+        genesis_hash: AlgorandHash,
+        last_valid_round: Option<u64>,
+    ) -> Result<Self> {
+        TransactionBuilder::close_account(sender, close_to)
+            .fee(fee)
+            .validity_window(first_valid_round, last_valid_round)
+            .genesis_hash(genesis_hash)
+            .build()
+    }
+
+    /// ## New Close Account Transaction With Params
+    ///
+    /// Create a transaction which closes the sender's account, taking the fee, validity window and
+    /// genesis information from the given suggested params.
+    pub fn new_close_account_tx_with_params(
+        sender: AlgorandAddress,
+        close_to: AlgorandAddress,
+        params: &SuggestedParams,
+    ) -> Result<Self> {
+        TransactionBuilder::close_account(sender, close_to)
+            .suggested_params(params)
+            .build()
+    }
+
+    /// ## Closed Amount
+    ///
+    /// Get the amount of microAlgos swept to the close target by a confirmed transaction which
+    /// closed the sender's account, on top of the amount paid to the receiver. This is only known
+    /// for transactions fetched from a node or the indexer, so is `None` for any other.
+    pub fn closed_amount(&self) -> Option<MicroAlgos> {
+        self.close_remainder_to
+            .and(self.close_amount)
+            .map(MicroAlgos::new)
     }
 }
 
@@ -85,9 +145,13 @@ impl AlgorandTransaction {
 mod tests {
     use super::*;
     use crate::{
+        algorand_constants::ALGORAND_MAINNET_GENESIS_ID,
         algorand_errors::AlgorandError,
         algorand_traits::ToMsgPackBytes,
-        algorand_transactions::test_utils::get_sample_pay_tx,
+        algorand_transactions::{
+            signing_options::SigningOptions,
+            test_utils::{get_sample_pay_tx, get_sample_txs_n},
+        },
         test_utils::get_sample_algorand_keys,
     };
 
@@ -155,7 +219,8 @@ mod tests {
     fn should_sign_tx() {
         let tx = get_sample_pay_tx();
         let keys = get_sample_algorand_keys();
-        let signed_tx = tx.sign(&keys).unwrap();
+        let options = SigningOptions::default().with_auth_address(keys.to_address().unwrap());
+        let signed_tx = tx.sign_with_options(&keys, &options).unwrap();
         let result = hex::encode(signed_tx.to_msg_pack_bytes().unwrap());
         let expected_result = "83a473676e72c42090826960db089ee5636266600d56a9f41f5d037e5c90a18007e384fc1558603da3736967c4402e222c86ac989bc5ba5e1e19a5020a3e28fe295818648e4b0e845b772b2220334969530b6236f902efbec584aed004526be0c662f8a2d3083563ec5a4c28bb00a374786e88a3616d74ce000f4779a3666565cd03e8a26676cd03e8a26768c420c061c4d8fc1dbdded2d7604be4568e3f6d041987ac37bde4b620b5ab39248adfa26c76cd07d0a3726376c4203516382099d0a8f0c8eabc6d4b7efb58b0be7008e98d239dd0199490674e0372a3736e64c420e23319d1dfd271db1f8752ea384948a975594617fa0b546d44740e768a14b899a474797065a3706179";
         assert_eq!(result, expected_result);
@@ -164,4 +229,100 @@ mod tests {
             "4J3U5D7WUZN235TPZKPBKEGZTQC4DEXINFCZZIDTL3LRF562ZUXQ"
         );
     }
+
+    #[test]
+    fn should_build_pay_tx_with_params() {
+        let sample_tx = get_sample_pay_tx();
+        let params = SuggestedParams::new(
+            MicroAlgos(0),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+        .with_flat_fee(MicroAlgos::minimum_fee());
+        let result = AlgorandTransaction::new_payment_tx_with_params(
+            1001337,
+            None,
+            sample_tx.sender.unwrap(),
+            sample_tx.receiver.unwrap(),
+            &params,
+        )
+        .unwrap();
+        assert_eq!(result, sample_tx);
+    }
+
+    #[test]
+    fn should_set_genesis_id_from_params() {
+        let sample_tx = get_sample_pay_tx();
+        let params = SuggestedParams::new(
+            MicroAlgos(0),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+        .with_genesis_id(ALGORAND_MAINNET_GENESIS_ID);
+        let result = AlgorandTransaction::new_payment_tx_with_params(
+            1001337,
+            None,
+            sample_tx.sender.unwrap(),
+            sample_tx.receiver.unwrap(),
+            &params,
+        )
+        .unwrap();
+        assert_eq!(
+            result.genesis_id,
+            Some(ALGORAND_MAINNET_GENESIS_ID.to_string())
+        );
+        assert!(hex::encode(result.to_msg_pack_bytes().unwrap()).contains(&hex::encode(b"\xa3gen")));
+    }
+
+    #[test]
+    fn should_create_close_account_tx() {
+        let sample_tx = get_sample_txs_n(4).0[6].clone();
+        let tx = AlgorandTransaction::new_close_account_tx(
+            MicroAlgos::minimum_fee(),
+            29285108,
+            sample_tx.sender.unwrap(),
+            sample_tx.close_remainder_to.unwrap(),
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            Some(29286108),
+        )
+        .unwrap();
+        let mut result = tx.assign_group_id(sample_tx.group.unwrap());
+        result.genesis_id = Some(ALGORAND_MAINNET_GENESIS_ID.to_string());
+        let expected_result = "JOSKWFOAUVOYCMRTGBKV6L5U767WA4UHD5XERYSUNCC2QP7I5NGQ";
+        assert_eq!(result.to_id().unwrap(), expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_close_account_to_sender() {
+        let sender = get_sample_pay_tx().sender.unwrap();
+        let expected_error = format!("Cannot close account {sender} to itself!");
+        match AlgorandTransaction::new_close_account_tx(
+            MicroAlgos::minimum_fee(),
+            1000,
+            sender,
+            sender,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+            None,
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_get_closed_amount_of_confirmed_tx() {
+        let txs = get_sample_txs_n(4).0;
+        assert_eq!(txs[6].closed_amount(), Some(MicroAlgos::new(247000)));
+        assert_eq!(get_sample_pay_tx().closed_amount(), None);
+    }
+
+    #[test]
+    fn should_not_get_closed_amount_of_unconfirmed_closing_tx() {
+        let tx = AlgorandTransaction {
+            close_remainder_to: get_sample_pay_tx().receiver,
+            ..get_sample_pay_tx()
+        };
+        assert_eq!(tx.closed_amount(), None);
+    }
 }
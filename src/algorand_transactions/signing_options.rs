@@ -0,0 +1,163 @@
+use crate::algorand_address::AlgorandAddress;
+
+/// ## Signing Options
+///
+/// Options for signing a transaction. By default the sender's own key must sign, and any
+/// transaction which would rekey the sender's account is refused.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SigningOptions {
+    /// The address the sender's account has been rekeyed to, if any, whose key must sign instead
+    /// of the sender's.
+    pub auth_address: Option<AlgorandAddress>,
+
+    /// Whether to sign a transaction which rekeys the sender's account.
+    pub allow_rekey: bool,
+}
+
+impl SigningOptions {
+    /// ## With Auth Address
+    ///
+    /// Sign on behalf of a sender whose account has been rekeyed to the given address.
+    pub fn with_auth_address(mut self, auth_address: AlgorandAddress) -> Self {
+        self.auth_address = Some(auth_address);
+        self
+    }
+
+    /// ## Allowing Rekey
+    ///
+    /// Sign the transaction even if it rekeys the sender's account.
+    pub fn allowing_rekey(mut self) -> Self {
+        self.allow_rekey = true;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_errors::AlgorandError,
+        algorand_hash::AlgorandHash,
+        algorand_micro_algos::MicroAlgos,
+        algorand_transactions::{
+            test_utils::get_sample_pay_tx,
+            transaction::AlgorandTransaction,
+            transaction_builder::TransactionBuilder,
+        },
+        test_utils::get_sample_algorand_keys,
+    };
+
+    fn get_sample_rekey_tx(
+        account: AlgorandAddress,
+        auth_address: AlgorandAddress,
+    ) -> AlgorandTransaction {
+        TransactionBuilder::rekey_account(account, auth_address)
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_sign_tx_with_senders_keys() {
+        let keys = get_sample_algorand_keys();
+        let tx = AlgorandTransaction {
+            sender: Some(keys.to_address().unwrap()),
+            ..get_sample_pay_tx()
+        };
+        let result = tx.sign(&keys).unwrap();
+        assert_eq!(result.signer, None);
+    }
+
+    #[test]
+    fn should_fail_to_sign_tx_with_keys_not_matching_sender() {
+        let keys = get_sample_algorand_keys();
+        let tx = get_sample_pay_tx();
+        let expected_error = format!(
+            "Cannot sign with keys for {} since the transaction must be authorized by {}!",
+            keys.to_address().unwrap(),
+            tx.sender.unwrap()
+        );
+        match tx.sign(&keys) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_sign_tx_on_behalf_of_rekeyed_account() {
+        let keys = get_sample_algorand_keys();
+        let auth_address = keys.to_address().unwrap();
+        let tx = get_sample_pay_tx();
+        let options = SigningOptions::default().with_auth_address(auth_address);
+        let result = tx.sign_with_options(&keys, &options).unwrap();
+        assert_eq!(result.signer, Some(auth_address));
+    }
+
+    #[test]
+    fn should_fail_to_sign_with_keys_not_matching_auth_address() {
+        let keys = get_sample_algorand_keys();
+        let tx = AlgorandTransaction {
+            sender: Some(keys.to_address().unwrap()),
+            ..get_sample_pay_tx()
+        };
+        let auth_address = get_sample_pay_tx().receiver.unwrap();
+        let options = SigningOptions::default().with_auth_address(auth_address);
+        let expected_error = format!(
+            "Cannot sign with keys for {} since the transaction must be authorized by {auth_address}!",
+            keys.to_address().unwrap(),
+        );
+        match tx.sign_with_options(&keys, &options) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_refuse_to_sign_rekey_tx_by_default() {
+        let keys = get_sample_algorand_keys();
+        let account = keys.to_address().unwrap();
+        let auth_address = get_sample_pay_tx().receiver.unwrap();
+        let tx = get_sample_rekey_tx(account, auth_address);
+        let expected_error = format!(
+            "Refusing to sign a transaction which rekeys {account} to {auth_address} unless rekeying is allowed!"
+        );
+        match tx.sign(&keys) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_sign_rekey_tx_when_allowed() {
+        let keys = get_sample_algorand_keys();
+        let account = keys.to_address().unwrap();
+        let tx = get_sample_rekey_tx(account, get_sample_pay_tx().receiver.unwrap());
+        let options = SigningOptions::default().allowing_rekey();
+        let result = tx.sign_with_options(&keys, &options).unwrap();
+        assert_eq!(result.transaction, tx);
+        assert_eq!(result.signer, None);
+    }
+
+    #[test]
+    fn should_sign_rekey_back_tx_with_auth_address_keys() {
+        let keys = get_sample_algorand_keys();
+        let auth_address = keys.to_address().unwrap();
+        let account = get_sample_pay_tx().sender.unwrap();
+        let tx = TransactionBuilder::rekey_back(account)
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap();
+        let options = SigningOptions::default()
+            .with_auth_address(auth_address)
+            .allowing_rekey();
+        let result = tx.sign_with_options(&keys, &options).unwrap();
+        assert_eq!(result.signer, Some(auth_address));
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+use crate::algorand_state_proofs::{
+    state_proof::StateProofJson,
+    state_proof_message::StateProofMessageJson,
+};
+
+#[skip_serializing_none]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct StateProofTransactionJson {
+    pub message: Option<StateProofMessageJson>,
+
+    #[serde(rename = "state-proof")]
+    pub state_proof: Option<StateProofJson>,
+
+    #[serde(rename = "state-proof-type")]
+    pub state_proof_type: Option<u64>,
+}
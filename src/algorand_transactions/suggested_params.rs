@@ -0,0 +1,360 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    algorand_errors::AlgorandError,
+    algorand_hash::AlgorandHash,
+    algorand_micro_algos::MicroAlgos,
+    algorand_transactions::{
+        transaction::AlgorandTransaction,
+        transaction_fees::AlgorandSignatureKind,
+    },
+    algorand_types::{Byte, Result},
+};
+
+/// ## Suggested Params Json
+///
+/// The json returned by an algod node's `/v2/transactions/params` endpoint.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SuggestedParamsJson {
+    #[serde(rename = "consensus-version")]
+    pub consensus_version: String,
+
+    pub fee: u64,
+
+    #[serde(rename = "genesis-hash")]
+    pub genesis_hash: String,
+
+    #[serde(rename = "genesis-id")]
+    pub genesis_id: String,
+
+    #[serde(rename = "last-round")]
+    pub last_round: u64,
+
+    #[serde(rename = "min-fee")]
+    pub min_fee: u64,
+}
+
+/// ## Suggested Params
+///
+/// The network dependent parameters required to build a transaction. The fee is treated as a fee
+/// per byte of the signed transaction unless it is flagged as a flat fee, and in either case the
+/// final fee will never be below the minimum fee.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SuggestedParams {
+    pub fee: MicroAlgos,
+    pub flat_fee: bool,
+    pub min_fee: MicroAlgos,
+    pub first_valid_round: u64,
+    pub last_valid_round: Option<u64>,
+    pub genesis_id: Option<String>,
+    pub genesis_hash: AlgorandHash,
+    pub consensus_version: Option<String>,
+}
+
+impl SuggestedParams {
+    /// ## New
+    ///
+    /// Create suggested params with a fee per byte, using the protocol's minimum fee, and with the
+    /// last valid round defaulting to the maximum validity window.
+    pub fn new(
+        fee_per_byte: MicroAlgos,
+        first_valid_round: u64,
+        genesis_hash: AlgorandHash,
+    ) -> Self {
+        Self {
+            genesis_hash,
+            first_valid_round,
+            flat_fee: false,
+            fee: fee_per_byte,
+            genesis_id: None,
+            last_valid_round: None,
+            consensus_version: None,
+            min_fee: MicroAlgos::minimum_fee(),
+        }
+    }
+
+    /// ## With Flat Fee
+    ///
+    /// Use the given fee as is, rather than as a fee per byte.
+    pub fn with_flat_fee(mut self, fee: MicroAlgos) -> Self {
+        self.fee = fee;
+        self.flat_fee = true;
+        self
+    }
+
+    /// ## With Min Fee
+    ///
+    /// Set the minimum fee a transaction built with these params may pay.
+    pub fn with_min_fee(mut self, min_fee: MicroAlgos) -> Self {
+        self.min_fee = min_fee;
+        self
+    }
+
+    /// ## With Last Valid Round
+    ///
+    /// Set the last round in which a transaction built with these params is valid.
+    pub fn with_last_valid_round(mut self, last_valid_round: u64) -> Self {
+        self.last_valid_round = Some(last_valid_round);
+        self
+    }
+
+    /// ## With Genesis ID
+    ///
+    /// Set the genesis ID of the network, eg `mainnet-v1.0`.
+    pub fn with_genesis_id(mut self, genesis_id: &str) -> Self {
+        self.genesis_id = Some(genesis_id.to_string());
+        self
+    }
+
+    /// ## With Consensus Version
+    ///
+    /// Set the consensus protocol version the params were suggested under.
+    pub fn with_consensus_version(mut self, consensus_version: &str) -> Self {
+        self.consensus_version = Some(consensus_version.to_string());
+        self
+    }
+
+    /// ## From Json
+    ///
+    /// Create suggested params from the json returned by an algod node. The node's last round is
+    /// used as the first valid round.
+    pub fn from_json(json: &SuggestedParamsJson) -> Result<Self> {
+        Ok(Self {
+            flat_fee: false,
+            last_valid_round: None,
+            fee: MicroAlgos(json.fee),
+            min_fee: MicroAlgos(json.min_fee),
+            first_valid_round: json.last_round,
+            genesis_id: Some(json.genesis_id.clone()),
+            genesis_hash: AlgorandHash::from_str(&json.genesis_hash)?,
+            consensus_version: Some(json.consensus_version.clone()),
+        })
+    }
+
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        Self::from_json(&serde_json::from_slice(bytes)?)
+    }
+
+    /// ## Calculate Fee
+    ///
+    /// Calculate the fee the given transaction should pay under these params, assuming it is
+    /// signed by its sender with a single signature.
+    pub fn calculate_fee(&self, tx: &AlgorandTransaction) -> Result<MicroAlgos> {
+        self.calculate_fee_covering(tx, &AlgorandSignatureKind::Single, MicroAlgos(0))
+    }
+
+    /// ## Calculate Required Fee
+    ///
+    /// Calculate the fee required of the given transaction as it stands, ie without accounting for
+    /// the size of any fee it will be made to carry.
+    pub(crate) fn calculate_required_fee(
+        &self,
+        tx: &AlgorandTransaction,
+        signature_kind: &AlgorandSignatureKind,
+    ) -> Result<MicroAlgos> {
+        let fee = if self.flat_fee {
+            self.fee
+        } else {
+            let size = tx.estimate_size_with_signature_kind(signature_kind)?;
+            self.fee.checked_mul(size).ok_or_else(|| {
+                format!(
+                    "Fee of {} per byte for a {size} byte transaction overflows!",
+                    self.fee
+                )
+            })?
+        };
+        Ok(if fee < self.min_fee {
+            self.min_fee
+        } else {
+            fee
+        })
+    }
+
+    /// ## Calculate Fee Covering
+    ///
+    /// Calculate the fee the given transaction should pay under these params, plus the given extra
+    /// fee it covers for other transactions. Since a larger fee can take more bytes to encode, a
+    /// per-byte fee is recalculated until it covers the size of the transaction carrying it.
+    pub(crate) fn calculate_fee_covering(
+        &self,
+        tx: &AlgorandTransaction,
+        signature_kind: &AlgorandSignatureKind,
+        extra_fee: MicroAlgos,
+    ) -> Result<MicroAlgos> {
+        let mut tx_with_fee = tx.clone();
+        tx_with_fee.fee = None;
+        let add_extra_fee = |fee: MicroAlgos| {
+            fee.checked_add(extra_fee)
+                .ok_or_else(|| format!("Covering an extra fee of {extra_fee} overflows!"))
+        };
+        let mut fee = add_extra_fee(self.calculate_required_fee(&tx_with_fee, signature_kind)?)?;
+        loop {
+            tx_with_fee.fee = Some(fee.0);
+            let required_fee =
+                add_extra_fee(self.calculate_required_fee(&tx_with_fee, signature_kind)?)?;
+            if required_fee <= fee {
+                return Ok(fee);
+            }
+            fee = required_fee;
+        }
+    }
+}
+
+impl FromStr for SuggestedParams {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_json(&serde_json::from_str(s)?)
+    }
+}
+
+impl AlgorandTransaction {
+    /// ## Apply Suggested Params
+    ///
+    /// Fill in the fee, validity window, genesis ID and genesis hash of the transaction from the
+    /// given suggested params.
+    pub fn apply_suggested_params(&self, params: &SuggestedParams) -> Result<Self> {
+        let mut tx = self.clone();
+        tx.first_valid_round = Some(params.first_valid_round);
+        tx.last_valid_round = Some(Self::calculate_last_valid_round(
+            params.first_valid_round,
+            params.last_valid_round,
+        )?);
+        tx.genesis_id = params.genesis_id.clone();
+        tx.genesis_hash = Some(params.genesis_hash);
+        tx.fee = Some(params.calculate_fee(&tx)?.0);
+        Ok(tx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_constants::{ALGORAND_MAINNET_GENESIS_HASH, ALGORAND_MAINNET_GENESIS_ID},
+        test_utils::get_sample_algorand_address,
+    };
+
+    fn get_sample_suggested_params_json_str() -> String {
+        format!(
+            "{{\"consensus-version\":\"https://github.com/algorandfoundation/specs/tree/44fa607d6051730f5264526bf3c108d51f0eadb6\",\"fee\":0,\"genesis-hash\":\"{ALGORAND_MAINNET_GENESIS_HASH}\",\"genesis-id\":\"{ALGORAND_MAINNET_GENESIS_ID}\",\"last-round\":27000000,\"min-fee\":1000}}"
+        )
+    }
+
+    fn get_sample_pay_tx() -> AlgorandTransaction {
+        AlgorandTransaction {
+            amount: Some(1337),
+            sender: Some(get_sample_algorand_address()),
+            receiver: Some(get_sample_algorand_address()),
+            txn_type: Some(crate::AlgorandTransactionType::Pay),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn should_get_suggested_params_from_str() {
+        let result = SuggestedParams::from_str(&get_sample_suggested_params_json_str()).unwrap();
+        assert_eq!(result.fee, MicroAlgos(0));
+        assert!(!result.flat_fee);
+        assert_eq!(result.min_fee, MicroAlgos(1000));
+        assert_eq!(result.first_valid_round, 27_000_000);
+        assert_eq!(result.last_valid_round, None);
+        assert_eq!(
+            result.genesis_id,
+            Some(ALGORAND_MAINNET_GENESIS_ID.to_string())
+        );
+        assert_eq!(
+            result.genesis_hash,
+            AlgorandHash::mainnet_genesis_hash().unwrap()
+        );
+        assert!(result
+            .consensus_version
+            .unwrap()
+            .ends_with("44fa607d6051730f5264526bf3c108d51f0eadb6"));
+    }
+
+    #[test]
+    fn should_calculate_fee_per_byte() {
+        let fee_per_byte = 10;
+        let params = SuggestedParams::new(
+            MicroAlgos(fee_per_byte),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        );
+        let tx = get_sample_pay_tx().apply_suggested_params(&params).unwrap();
+        let expected_fee = fee_per_byte * tx.estimate_size().unwrap();
+        assert_eq!(tx.fee, Some(expected_fee));
+        assert!(expected_fee > 1000);
+    }
+
+    #[test]
+    fn should_fail_to_calculate_fee_per_byte_if_it_overflows() {
+        let params = SuggestedParams::new(
+            MicroAlgos(u64::MAX / 2),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        );
+        assert!(get_sample_pay_tx().apply_suggested_params(&params).is_err());
+    }
+
+    #[test]
+    fn should_not_calculate_fee_below_min_fee() {
+        let params = SuggestedParams::new(
+            MicroAlgos(1),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+        .with_min_fee(MicroAlgos(2000));
+        let result = get_sample_pay_tx().apply_suggested_params(&params).unwrap();
+        assert_eq!(result.fee, Some(2000));
+    }
+
+    #[test]
+    fn should_use_flat_fee() {
+        let params = SuggestedParams::new(
+            MicroAlgos(0),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+        .with_flat_fee(MicroAlgos(1234));
+        let result = get_sample_pay_tx().apply_suggested_params(&params).unwrap();
+        assert_eq!(result.fee, Some(1234));
+    }
+
+    #[test]
+    fn should_apply_suggested_params_to_tx() {
+        let params = SuggestedParams::from_str(&get_sample_suggested_params_json_str())
+            .unwrap()
+            .with_last_valid_round(27_000_500);
+        let result = get_sample_pay_tx().apply_suggested_params(&params).unwrap();
+        assert_eq!(result.fee, Some(1000));
+        assert_eq!(result.first_valid_round, Some(27_000_000));
+        assert_eq!(result.last_valid_round, Some(27_000_500));
+        assert_eq!(
+            result.genesis_id,
+            Some(ALGORAND_MAINNET_GENESIS_ID.to_string())
+        );
+        assert_eq!(
+            result.genesis_hash,
+            Some(AlgorandHash::mainnet_genesis_hash().unwrap())
+        );
+    }
+
+    #[test]
+    fn should_fail_to_apply_suggested_params_with_invalid_validity_window() {
+        let expected_error = "Last valid round must be > than first valid round!";
+        let params = SuggestedParams::new(
+            MicroAlgos(0),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+        .with_last_valid_round(999);
+        match get_sample_pay_tx().apply_suggested_params(&params) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
@@ -6,6 +6,7 @@ use serde_json::Value as JsonValue;
 
 use crate::{
     algorand_address::AlgorandAddress,
+    algorand_blocks::block::AlgorandBlock,
     algorand_errors::AlgorandError,
     algorand_hash::AlgorandHash,
     algorand_micro_algos::MicroAlgos,
@@ -87,6 +88,16 @@ pub fn get_sample_pay_tx() -> AlgorandTransaction {
     .unwrap()
 }
 
+pub fn get_sample_asset_reconfiguration_tx() -> AlgorandTransaction {
+    // NOTE: See: https://algoexplorer.io/tx/VXSNM3GES4RGMD7DK4PJ5TPH7MXFQDYKENQWKTWBGLPNXJRAGO4Q
+    AlgorandBlock::from_str(&read_to_string(get_path_n(6).unwrap()).unwrap())
+        .unwrap()
+        .transactions
+        .unwrap()
+        .0[638]
+        .clone()
+}
+
 pub fn get_sample_acfg_tx_json_string() -> String {
     read_to_string("src/algorand_transactions/test_utils/acfg-tx.json").unwrap()
 }
@@ -111,7 +122,7 @@ pub fn get_sample_asset_parameters_json_str() -> String {
 
 mod tests {
     use super::*;
-    use crate::{AlgorandBlock, AlgorandHash};
+    use crate::AlgorandHash;
 
     #[test]
     fn should_get_sample_txs_json_strs_n() {
@@ -131,7 +142,7 @@ mod tests {
     #[test]
     fn should_get_sample_block_with_no_params_in_asset_cfg_tx() {
         let block =
-            AlgorandBlock::from_str(&read_to_string(&get_path_n(6).unwrap()).unwrap()).unwrap();
+            AlgorandBlock::from_str(&read_to_string(get_path_n(6).unwrap()).unwrap()).unwrap();
         let hash = block.hash().unwrap();
         // NOTE: See: https://algoexplorer.io/block/34866583
         let expected_hash =
@@ -10,16 +10,22 @@ use serde_with::skip_serializing_none;
 
 use crate::{
     algorand_address::AlgorandAddress,
-    algorand_applications::algorand_application_args::AlgorandApplicationArg,
+    algorand_applications::{
+        algorand_application_args::AlgorandApplicationArg,
+        algorand_box_reference::AlgorandBoxReference,
+        algorand_state_schema::{is_empty_schema, AlgorandStateSchema},
+    },
     algorand_constants::ALGORAND_MAX_NUM_ROUNDS,
     algorand_errors::AlgorandError,
     algorand_hash::AlgorandHash,
     algorand_keys::AlgorandKeys,
     algorand_micro_algos::MICRO_ALGOS_MULTIPLIER,
     algorand_signature::AlgorandSignature,
+    algorand_state_proofs::{state_proof::StateProof, state_proof_message::StateProofMessage},
     algorand_traits::ToMsgPackBytes,
     algorand_transactions::{
         application_transaction::{ApplicationTransactionJson, OnCompletion},
+        apply_data::AlgorandApplyData,
         asset_config_transaction::AssetConfigTransactionJson,
         asset_freeze_transaction::AssetFreezeTransactionJson,
         asset_parameters::AssetParameters,
@@ -27,12 +33,14 @@ use crate::{
         key_reg_transaction::KeyRegTransactionJson,
         pay_transaction::PaymentTransactionJson,
         signature_json::AlgorandSignatureJson,
+        signing_options::SigningOptions,
+        state_proof_transaction::StateProofTransactionJson,
         transaction_json::AlgorandTransactionJson,
         transaction_type::AlgorandTransactionType,
     },
     algorand_types::{Byte, Bytes, Result},
-    crypto_utils::{base32_encode_with_no_padding, sha512_256_hash_bytes},
-    predicates::{is_empty_vec, is_zero_option},
+    crypto_utils::{base32_encode_with_no_padding, sha256_hash_bytes, sha512_256_hash_bytes},
+    predicates::{is_empty_vec, is_false, is_zero_option},
 };
 
 impl ToMsgPackBytes for AlgorandTransaction {}
@@ -47,31 +55,31 @@ pub struct AlgorandTransaction {
     /// ## Asset Amount
     ///
     /// The amount of an asset to transfer.
-    #[serde(rename(serialize = "aamt"), skip_serializing_if = "is_zero_option")]
+    #[serde(rename = "aamt", skip_serializing_if = "is_zero_option")]
     pub asset_amount: Option<u64>,
 
     /// ## Asset Close To
     ///
     /// The address to send all remaining amount of asset to.
-    #[serde(rename(serialize = "aclose"))]
+    #[serde(rename = "aclose")]
     pub asset_close_to: Option<AlgorandAddress>,
 
     /// ## Asset Freeze Status
     ///
     /// The new freeze status of the asset.
-    #[serde(rename(serialize = "afrz"))]
+    #[serde(rename = "afrz", skip_serializing_if = "is_false")]
     pub asset_freeze_status: Option<bool>,
 
     /// ## Amount
     ///
     /// The total amount to be sent in microAlgos.
-    #[serde(rename(serialize = "amt"), skip_serializing_if = "is_zero_option")]
+    #[serde(rename = "amt", skip_serializing_if = "is_zero_option")]
     pub amount: Option<u64>,
 
     /// ## App Arguments
     ///
     /// Application arguments to be passed to the application being called
-    #[serde(rename(serialize = "apaa"), skip_serializing_if = "is_empty_vec")]
+    #[serde(rename = "apaa", skip_serializing_if = "is_empty_vec")]
     pub application_args: Option<Vec<AlgorandApplicationArg>>,
 
     /// ## On Completion
@@ -80,55 +88,94 @@ pub struct AlgorandTransaction {
     /// will have on the balance record of the sender or the application's
     /// creator. See the documentation for the OnCompletion type for more
     /// information on each possible value.
-    #[serde(rename(serialize = "apan"), skip_serializing_if = "is_zero_option")]
+    #[serde(rename = "apan", skip_serializing_if = "is_zero_option")]
     pub on_completion: Option<u64>,
 
+    /// ## Approval Program
+    ///
+    /// The program executed for every call to the application being created or updated, except
+    /// when the on completion is clear state.
+    #[serde(rename = "apap", with = "serde_bytes", default)]
+    pub approval_program: Option<Bytes>,
+
     /// ## Asset Parameters
     ///
     /// Asset paramets to include if the transaction is intended to create a new Algorand asset.
-    #[serde(rename(serialize = "apar"))]
+    #[serde(rename = "apar")]
     pub asset_parameters: Option<AssetParameters>,
 
     /// ## Foreign assets
     ///
     /// Asset IDs of assets that may be used by the application being called.
-    #[serde(rename(serialize = "apas"), skip_serializing_if = "is_empty_vec")]
+    #[serde(rename = "apas", skip_serializing_if = "is_empty_vec")]
     pub foreign_assets: Option<Vec<u64>>,
 
     /// ## Accounts
     ///
     /// Account addresses of accounts that may be accessed by the application being called.
-    #[serde(rename(serialize = "apat"), skip_serializing_if = "is_empty_vec")]
+    #[serde(rename = "apat", skip_serializing_if = "is_empty_vec")]
     pub accounts: Option<Vec<AlgorandAddress>>,
 
+    /// ## Boxes
+    ///
+    /// References to the boxes that may be accessed by the application being called.
+    #[serde(rename = "apbx", skip_serializing_if = "is_empty_vec")]
+    pub boxes: Option<Vec<AlgorandBoxReference>>,
+
+    /// ## Extra Program Pages
+    ///
+    /// The number of additional pages of program space requested by the application being
+    /// created.
+    #[serde(rename = "apep", skip_serializing_if = "is_zero_option")]
+    pub extra_program_pages: Option<u64>,
+
     /// ## Foreign applications
     ///
     /// Application IDs of applications that may be accessed by the application being called.
-    #[serde(rename(serialize = "apfa"), skip_serializing_if = "is_empty_vec")]
+    #[serde(rename = "apfa", skip_serializing_if = "is_empty_vec")]
     pub foreign_apps: Option<Vec<u64>>,
 
+    /// ## Global State Schema
+    ///
+    /// The global state schema of the application being created.
+    #[serde(rename = "apgs", skip_serializing_if = "is_empty_schema")]
+    pub global_state_schema: Option<AlgorandStateSchema>,
+
     /// ## Application ID
     ///
     /// The ID of an application to be called.
-    #[serde(rename(serialize = "apid"))]
+    #[serde(rename = "apid", skip_serializing_if = "is_zero_option")]
     pub application_id: Option<u64>,
 
+    /// ## Local State Schema
+    ///
+    /// The local state schema of the application being created.
+    #[serde(rename = "apls", skip_serializing_if = "is_empty_schema")]
+    pub local_state_schema: Option<AlgorandStateSchema>,
+
+    /// ## Clear State Program
+    ///
+    /// The program executed when an account clears its state for the application being created
+    /// or updated.
+    #[serde(rename = "apsu", with = "serde_bytes", default)]
+    pub clear_state_program: Option<Bytes>,
+
     /// ## Asset Receiver
     ///
     /// The asset receiver in an asset transfer transaction.
-    #[serde(rename(serialize = "arcv"))]
+    #[serde(rename = "arcv")]
     pub asset_receiver: Option<AlgorandAddress>,
 
     /// ## Asset Sender
     ///
     /// The address from which the funds will be clawed back from.
-    #[serde(rename(serialize = "asnd"))]
+    #[serde(rename = "asnd")]
     pub asset_sender: Option<AlgorandAddress>,
 
     /// ## Asset ID
     ///
     /// An ID pointing to an asset on the Algorand blockchain.
-    #[serde(rename(serialize = "caid"), skip_serializing_if = "is_zero_option")]
+    #[serde(rename = "caid", skip_serializing_if = "is_zero_option")]
     // FIXME This is the config tx asset id! Add a prefix for clarity?
     pub asset_id: Option<u64>,
 
@@ -136,19 +183,19 @@ pub struct AlgorandTransaction {
     ///
     /// When set, it indicates that the tx is requesting that the sendng account should be closed.
     /// All remaining funds after the tx fee & amount are paid are be transferred to this address.
-    #[serde(rename(serialize = "close"))]
+    #[serde(rename = "close")]
     pub close_remainder_to: Option<AlgorandAddress>,
 
     /// ## Asset Freeze Address
     ///
     /// Address of the account whose asset is being frozen or thawed.
-    #[serde(rename(serialize = "fadd"))]
+    #[serde(rename = "fadd")]
     pub asset_freeze_address: Option<AlgorandAddress>,
 
     /// ## Asset Freeze ID
     ///
     /// ID of the asset being frozen or thawed.
-    #[serde(rename(serialize = "faid"))]
+    #[serde(rename = "faid")]
     pub asset_freeze_id: Option<u64>,
 
     /// ## Fee
@@ -161,31 +208,31 @@ pub struct AlgorandTransaction {
     /// ## First Valid Round
     ///
     /// The first round after which the tx is valid.
-    #[serde(rename(serialize = "fv"))]
+    #[serde(rename = "fv")]
     pub first_valid_round: Option<u64>,
 
     /// ## Genesis ID
     ///
     /// The human-readable form of the genesis hash.
-    #[serde(rename(serialize = "gen"))]
+    #[serde(rename = "gen")]
     pub genesis_id: Option<String>,
 
     /// ## Genesis Hash
     ///
     /// The hash of the genesis block of the network on which the tx is valid.
-    #[serde(rename(serialize = "gh"))]
+    #[serde(rename = "gh")]
     pub genesis_hash: Option<AlgorandHash>,
 
     /// ## Group
     ///
     /// The hash of the tx group this tx belongs to, if any.
-    #[serde(rename(serialize = "grp"))]
+    #[serde(rename = "grp")]
     pub group: Option<AlgorandHash>,
 
     /// ## Last Valid Round
     ///
     /// The last round after which the tx is no longer valid.
-    #[serde(rename(serialize = "lv"))]
+    #[serde(rename = "lv")]
     pub last_valid_round: Option<u64>,
 
     /// ## Lease
@@ -200,43 +247,103 @@ pub struct AlgorandTransaction {
     /// spends. For example, if I send a tx to the network and later realize my fee was too
     /// low, I could send another tx with a higher fee, but the same lease value. This would
     /// ensure that only one of those txs ends up getting confirmed during the validity period.
-    #[serde(rename(serialize = "lx"))]
+    #[serde(rename = "lx")]
     pub lease: Option<AlgorandHash>,
 
+    /// ## Non Participation
+    ///
+    /// Marks the sender's account as never participating in consensus again, forgoing rewards.
+    #[serde(rename = "nonpart", skip_serializing_if = "is_false")]
+    pub non_participation: Option<bool>,
+
     /// # Note
     /// #
     /// Any data up to 1000 bytes.
-    #[serde(with = "serde_bytes")]
+    #[serde(with = "serde_bytes", default)]
     pub note: Option<Bytes>,
 
     /// ## Receiver
     ///
     /// The address of the account whom receives the amount.
-    #[serde(rename(serialize = "rcv"))]
+    #[serde(rename = "rcv")]
     pub receiver: Option<AlgorandAddress>,
 
     /// ## RekeyTo
     ///
     /// Specifies the authorized address. This address will be used to authorize all future txs.
-    #[serde(rename(serialize = "rekey"))]
+    #[serde(rename = "rekey")]
     pub rekey_to: Option<AlgorandAddress>,
 
+    /// ## Selection Key
+    ///
+    /// The VRF public key used to select the registering account for consensus committees.
+    #[serde(rename = "selkey", with = "serde_bytes", default)]
+    pub selection_key: Option<Bytes>,
+
     /// ## Sender
     ///
     /// The address of the account which signs the tx and pays the fee & amount.
-    #[serde(rename(serialize = "snd"))]
+    #[serde(rename = "snd")]
     pub sender: Option<AlgorandAddress>,
 
+    /// ## State Proof
+    ///
+    /// The state proof carried by a state proof tx.
+    #[serde(rename = "sp")]
+    pub state_proof: Option<StateProof>,
+
+    /// ## State Proof Message
+    ///
+    /// The message the state proof of a state proof tx attests to.
+    #[serde(rename = "spmsg")]
+    pub state_proof_message: Option<StateProofMessage>,
+
+    /// ## State Proof Key
+    ///
+    /// The root of the Merkle tree of Falcon keys the registering account signs state proofs with.
+    #[serde(rename = "sprfkey", with = "serde_bytes", default)]
+    pub state_proof_key: Option<Bytes>,
+
+    /// ## State Proof Type
+    ///
+    /// The type of the state proof of a state proof tx, of which there's currently only one.
+    #[serde(rename = "sptype", skip_serializing_if = "is_zero_option")]
+    pub state_proof_type: Option<u64>,
+
     /// ## Txn Type
     ///
     /// Specifies the type of tx.
-    #[serde(rename(serialize = "type"))]
+    #[serde(rename = "type")]
     pub txn_type: Option<AlgorandTransactionType>,
 
+    /// ## Vote First Round
+    ///
+    /// The first round for which the registered participation keys are valid.
+    #[serde(rename = "votefst", skip_serializing_if = "is_zero_option")]
+    pub vote_first_round: Option<u64>,
+
+    /// ## Vote Key Dilution
+    ///
+    /// The number of rounds covered by each second-level ephemeral voting key.
+    #[serde(rename = "votekd", skip_serializing_if = "is_zero_option")]
+    pub vote_key_dilution: Option<u64>,
+
+    /// ## Vote Key
+    ///
+    /// The root participation public key the registering account votes with.
+    #[serde(rename = "votekey", with = "serde_bytes", default)]
+    pub vote_key: Option<Bytes>,
+
+    /// ## Vote Last Round
+    ///
+    /// The last round for which the registered participation keys are valid.
+    #[serde(rename = "votelst", skip_serializing_if = "is_zero_option")]
+    pub vote_last_round: Option<u64>,
+
     /// ## Asset ID
     ///
     /// The unique ID of the asset to be transferred.
-    #[serde(rename(serialize = "xaid"))]
+    #[serde(rename = "xaid")]
     pub transfer_asset_id: Option<u64>,
 
     // NOTE: These fields are retained when building tx from JSON
@@ -249,11 +356,18 @@ pub struct AlgorandTransaction {
     #[serde(skip_serializing)]
     pub close_amount: Option<u64>,
 
+    #[serde(skip_serializing)]
+    pub created_asset_index: Option<u64>,
+
     #[serde(skip_serializing)]
     pub inner_txs: Option<Vec<AlgorandTransaction>>,
 
     #[serde(skip_serializing)]
     pub parent_tx_id: Option<AlgorandHash>,
+
+    // NOTE: This is retained when decoding a tx from a msgpack-ed block
+    #[serde(skip)]
+    pub apply_data: Option<AlgorandApplyData>,
 }
 
 impl FromStr for AlgorandTransaction {
@@ -304,6 +418,14 @@ impl AlgorandTransaction {
         AlgorandHash::from_slice(&sha512_256_hash_bytes(&self.encode_for_signing()?))
     }
 
+    /// ## To Raw Tx ID SHA-256
+    ///
+    /// The SHA-256 hash of the transaction, as committed to by the SHA-256 transactions root of
+    /// the block it's in.
+    pub fn to_raw_tx_id_sha256(&self) -> Result<AlgorandHash> {
+        AlgorandHash::from_slice(&sha256_hash_bytes(&self.encode_for_signing()?))
+    }
+
     pub fn group(&self) -> Result<AlgorandHash> {
         match self.group {
             Some(hash) => Ok(hash),
@@ -357,16 +479,51 @@ impl AlgorandTransaction {
 
     /// ## Sign
     ///
-    /// Sign the transaction with an AlgorandKey.
+    /// Sign the transaction with the sender's key. Signing fails if the keys are not the
+    /// sender's, or if the transaction would rekey the sender's account. Use `sign_with_options`
+    /// to sign on behalf of a rekeyed account, or to knowingly sign a rekey.
     pub fn sign(&self, keys: &AlgorandKeys) -> Result<AlgorandSignedTransaction> {
+        self.sign_with_options(keys, &SigningOptions::default())
+    }
+
+    /// ## Sign With Options
+    ///
+    /// Sign the transaction with the keys of the account authorized to send it, which is the
+    /// sender unless an auth address is given in the options. Signing fails if the keys do not
+    /// belong to that account, or if the transaction would rekey the sender's account and the
+    /// options do not allow it.
+    pub fn sign_with_options(
+        &self,
+        keys: &AlgorandKeys,
+        options: &SigningOptions,
+    ) -> Result<AlgorandSignedTransaction> {
+        let sender = self
+            .sender
+            .ok_or("Cannot sign a transaction without a sender!")?;
+        if let Some(rekey_to) = &self.rekey_to {
+            if !options.allow_rekey {
+                return Err(format!(
+                    "Refusing to sign a transaction which rekeys {sender} to {rekey_to} unless rekeying is allowed!"
+                )
+                .into());
+            }
+        };
+        let auth_address = options.auth_address.unwrap_or(sender);
         let signer_address = keys.to_address()?;
+        if signer_address != auth_address {
+            return Err(format!(
+                "Cannot sign with keys for {signer_address} since the transaction must be authorized by {auth_address}!"
+            )
+            .into());
+        };
         Ok(AlgorandSignedTransaction {
             transaction: self.clone(),
             transaction_id: Some(self.to_id()?),
             signature: keys.sign(&self.encode_for_signing()?),
-            signer: match &self.sender {
-                Some(sender) if signer_address != *sender => Some(signer_address),
-                _ => None,
+            signer: if auth_address != sender {
+                Some(auth_address)
+            } else {
+                None
             },
         })
     }
@@ -477,6 +634,7 @@ impl AlgorandTransaction {
                 Some(payment_json) => payment_json.close_amount,
                 None => None,
             },
+            created_asset_index: json.created_asset_index,
             application_args: match &json.application_transaction {
                 Some(app) => Some(app.maybe_get_application_args()?),
                 None => None,
@@ -493,6 +651,33 @@ impl AlgorandTransaction {
                 Some(app) => app.foreign_assets.clone(),
                 None => None,
             },
+            boxes: None,
+            approval_program: match &json.application_transaction {
+                Some(app) => app.maybe_get_approval_program()?,
+                None => None,
+            },
+            clear_state_program: match &json.application_transaction {
+                Some(app) => app.maybe_get_clear_state_program()?,
+                None => None,
+            },
+            global_state_schema: match &json.application_transaction {
+                Some(app) => app
+                    .global_state_schema
+                    .as_ref()
+                    .map(AlgorandStateSchema::from_json),
+                None => None,
+            },
+            local_state_schema: match &json.application_transaction {
+                Some(app) => app
+                    .local_state_schema
+                    .as_ref()
+                    .map(AlgorandStateSchema::from_json),
+                None => None,
+            },
+            extra_program_pages: match &json.application_transaction {
+                Some(app) => app.extra_program_pages,
+                None => None,
+            },
             inner_txs: match &json.id {
                 Some(id) => match &json.inner_txs {
                     Some(inner_txs) => Some(
@@ -513,6 +698,62 @@ impl AlgorandTransaction {
                 Some(parent_tx_id) => Some(AlgorandHash::from_str(parent_tx_id)?),
                 None => None,
             },
+            state_proof: match &json.state_proof_transaction {
+                Some(state_proof_tx) => match &state_proof_tx.state_proof {
+                    Some(state_proof) => Some(StateProof::from_json(state_proof)?),
+                    None => None,
+                },
+                None => None,
+            },
+            state_proof_message: match &json.state_proof_transaction {
+                Some(state_proof_tx) => match &state_proof_tx.message {
+                    Some(message) => Some(StateProofMessage::from_json(message)?),
+                    None => None,
+                },
+                None => None,
+            },
+            state_proof_type: match &json.state_proof_transaction {
+                Some(state_proof_tx) => state_proof_tx.state_proof_type,
+                None => None,
+            },
+            non_participation: match &json.key_reg_transaction {
+                Some(key_reg_tx) => key_reg_tx.non_participation,
+                None => None,
+            },
+            selection_key: match &json.key_reg_transaction {
+                Some(key_reg_tx) => match &key_reg_tx.selection_participation_key {
+                    Some(base64_str) => Some(base64_decode(base64_str)?),
+                    None => None,
+                },
+                None => None,
+            },
+            state_proof_key: match &json.key_reg_transaction {
+                Some(key_reg_tx) => match &key_reg_tx.state_proof_key {
+                    Some(base64_str) => Some(base64_decode(base64_str)?),
+                    None => None,
+                },
+                None => None,
+            },
+            vote_first_round: match &json.key_reg_transaction {
+                Some(key_reg_tx) => key_reg_tx.vote_first_valid,
+                None => None,
+            },
+            vote_key_dilution: match &json.key_reg_transaction {
+                Some(key_reg_tx) => key_reg_tx.vote_key_dilution,
+                None => None,
+            },
+            vote_key: match &json.key_reg_transaction {
+                Some(key_reg_tx) => match &key_reg_tx.vote_participation_key {
+                    Some(base64_str) => Some(base64_decode(base64_str)?),
+                    None => None,
+                },
+                None => None,
+            },
+            vote_last_round: match &json.key_reg_transaction {
+                Some(key_reg_tx) => key_reg_tx.vote_last_valid,
+                None => None,
+            },
+            apply_data: None,
         })
     }
 
@@ -520,6 +761,7 @@ impl AlgorandTransaction {
         Ok(AlgorandTransactionJson {
             fee: self.fee,
             id: Some(self.to_id()?),
+            created_asset_index: self.created_asset_index,
             last_valid: self.last_valid_round,
             genesis_id: self.genesis_id.clone(),
             signature: self.to_signature_json(),
@@ -530,6 +772,7 @@ impl AlgorandTransaction {
             sender: self.sender.as_ref().map(|x| x.to_string()),
             tx_type: self.txn_type.as_ref().map(|x| x.to_string()),
             key_reg_transaction: self.to_key_ref_transaction_json(),
+            state_proof_transaction: self.to_state_proof_transaction_json(),
             rekey_to: self.rekey_to.as_ref().map(|x| x.to_string()),
             genesis_hash: self.genesis_hash.as_ref().map(|x| x.to_string()),
             asset_freeze_transaction: self.to_asset_freeze_transaction_json(),
@@ -571,8 +814,32 @@ impl AlgorandTransaction {
     }
 
     fn to_key_ref_transaction_json(&self) -> Option<KeyRegTransactionJson> {
-        // FIXME Impl this! Check if empty too!
-        None
+        let json = KeyRegTransactionJson {
+            vote_last_valid: self.vote_last_round,
+            non_participation: self.non_participation,
+            vote_first_valid: self.vote_first_round,
+            vote_key_dilution: self.vote_key_dilution,
+            vote_participation_key: self.vote_key.as_ref().map(base64_encode),
+            selection_participation_key: self.selection_key.as_ref().map(base64_encode),
+            state_proof_key: self.state_proof_key.as_ref().map(base64_encode),
+        };
+        if json.is_empty() {
+            None
+        } else {
+            Some(json)
+        }
+    }
+
+    fn to_state_proof_transaction_json(&self) -> Option<StateProofTransactionJson> {
+        if self.state_proof.is_none() && self.state_proof_message.is_none() {
+            None
+        } else {
+            Some(StateProofTransactionJson {
+                message: self.state_proof_message.as_ref().map(|x| x.to_json()),
+                state_proof: self.state_proof.as_ref().map(|x| x.to_json()),
+                state_proof_type: Some(self.state_proof_type.unwrap_or_default()),
+            })
+        }
     }
 
     fn to_application_transaction_json(&self) -> Result<Option<ApplicationTransactionJson>> {
@@ -597,10 +864,11 @@ impl AlgorandTransaction {
                 Some(args) if !args.is_empty() => Some(args.iter().map(base64_encode).collect()),
                 _ => None,
             },
-            approval_program: None,
-            clear_state_program: None,
-            global_state_schema: None,
-            local_state_schema: None,
+            approval_program: self.approval_program.as_ref().map(base64_encode),
+            clear_state_program: self.clear_state_program.as_ref().map(base64_encode),
+            global_state_schema: self.global_state_schema.as_ref().map(|x| x.to_json()),
+            local_state_schema: self.local_state_schema.as_ref().map(|x| x.to_json()),
+            extra_program_pages: self.extra_program_pages,
         };
         if json.is_empty() {
             Ok(None)
@@ -660,17 +928,17 @@ impl AlgorandTransaction {
 #[skip_serializing_none]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct AlgorandSignedTransaction {
-    #[serde(rename(serialize = "sgnr"))]
+    #[serde(rename = "sgnr")]
     pub signer: Option<AlgorandAddress>,
 
-    #[serde(rename(serialize = "sig"))]
+    #[serde(rename = "sig")]
     pub signature: AlgorandSignature,
 
-    #[serde(rename(serialize = "txn"))]
+    #[serde(rename = "txn")]
     pub transaction: AlgorandTransaction,
 
     #[serde(skip_serializing)]
-    #[serde(rename(serialize = "txid"))]
+    #[serde(rename = "txid")]
     transaction_id: Option<String>,
 }
 
@@ -719,7 +987,14 @@ impl AlgorandTransaction {
             "rekey_to",
             "sender",
             "txn_type",
-            "transfer_asset_id"
+            "transfer_asset_id",
+            "non_participation",
+            "selection_key",
+            "state_proof_key",
+            "vote_first_round",
+            "vote_key_dilution",
+            "vote_key",
+            "vote_last_round"
         );
     }
 }
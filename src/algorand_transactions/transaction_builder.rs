@@ -0,0 +1,867 @@
+use std::marker::PhantomData;
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_applications::{
+        algorand_application_args::AlgorandApplicationArg,
+        algorand_box_reference::AlgorandBoxReference,
+        algorand_state_schema::AlgorandStateSchema,
+    },
+    algorand_hash::AlgorandHash,
+    algorand_micro_algos::MicroAlgos,
+    algorand_traits::ToAssetBaseUnits,
+    algorand_transactions::{
+        application_transaction::OnCompletion,
+        asset_parameters::AssetParameters,
+        asset_reconfiguration::AssetReconfiguration,
+        suggested_params::SuggestedParams,
+        transaction::AlgorandTransaction,
+        transaction_type::AlgorandTransactionType,
+    },
+    algorand_types::{Bytes, Result},
+};
+
+/// ## Transaction Kind
+///
+/// Marks the type of transaction a `TransactionBuilder` is building, restricting the type-specific
+/// builder methods to the transactions they apply to.
+pub trait TransactionKind {
+    fn tx_type() -> AlgorandTransactionType;
+}
+
+macro_rules! make_transaction_kinds {
+    ($($name:ident => $tx_type:ident),* $(,)?) => {
+        $(
+            #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+            pub struct $name;
+
+            impl TransactionKind for $name {
+                fn tx_type() -> AlgorandTransactionType {
+                    AlgorandTransactionType::$tx_type
+                }
+            }
+        )*
+    }
+}
+
+make_transaction_kinds!(
+    PaymentTx => Pay,
+    AssetTransferTx => AssetTransfer,
+    ApplicationCallTx => ApplicationCall,
+    AssetConfigTx => AssetConfiguration,
+    AssetFreezeTx => AssetFreeze,
+    KeyRegTx => KeyRegistration,
+);
+
+/// ## Transaction Builder
+///
+/// A fluent builder for an `AlgorandTransaction` of a given kind. The fee may either be set
+/// explicitly, or calculated from suggested params when the transaction is built, at which point
+/// all the fields required by the transaction type are checked for.
+#[derive(Debug, Clone)]
+pub struct TransactionBuilder<T: TransactionKind> {
+    tx: AlgorandTransaction,
+    fee: Option<MicroAlgos>,
+    last_valid_round: Option<u64>,
+    params: Option<SuggestedParams>,
+    kind: PhantomData<T>,
+}
+
+impl<T: TransactionKind> TransactionBuilder<T> {
+    fn new(tx: AlgorandTransaction) -> Self {
+        Self {
+            fee: None,
+            params: None,
+            last_valid_round: None,
+            kind: PhantomData,
+            tx: AlgorandTransaction {
+                txn_type: Some(T::tx_type()),
+                ..tx
+            },
+        }
+    }
+
+    /// ## Fee
+    ///
+    /// Set a flat fee for the transaction, overriding any fee calculated from suggested params.
+    pub fn fee(mut self, fee: MicroAlgos) -> Self {
+        self.fee = Some(fee);
+        self
+    }
+
+    /// ## Note
+    ///
+    /// Attach arbitrary data to the transaction.
+    pub fn note(mut self, note: Bytes) -> Self {
+        self.tx.note = Some(note);
+        self
+    }
+
+    /// ## Lease
+    ///
+    /// Set a lease on the transaction. Whilst this transaction possesses the lease, no other
+    /// transaction from the same sender specifying the same lease can be confirmed.
+    pub fn lease(mut self, lease: AlgorandHash) -> Self {
+        self.tx.lease = Some(lease);
+        self
+    }
+
+    /// ## Rekey To
+    ///
+    /// Rekey the sender's account to the given address, which will then be required to authorize
+    /// all future transactions from the sender.
+    pub fn rekey_to(mut self, address: AlgorandAddress) -> Self {
+        self.tx.rekey_to = Some(address);
+        self
+    }
+
+    /// ## Validity Window
+    ///
+    /// Set the rounds during which the transaction is valid. If no last valid round is given it
+    /// defaults to the maximum number of rounds after the first valid round.
+    pub fn validity_window(
+        mut self,
+        first_valid_round: u64,
+        last_valid_round: Option<u64>,
+    ) -> Self {
+        self.tx.first_valid_round = Some(first_valid_round);
+        self.last_valid_round = last_valid_round;
+        self
+    }
+
+    /// ## Genesis Hash
+    ///
+    /// Set the hash of the genesis block of the network the transaction is valid on.
+    pub fn genesis_hash(mut self, genesis_hash: AlgorandHash) -> Self {
+        self.tx.genesis_hash = Some(genesis_hash);
+        self
+    }
+
+    /// ## Genesis ID
+    ///
+    /// Set the genesis ID of the network the transaction is valid on, eg `mainnet-v1.0`.
+    pub fn genesis_id(mut self, genesis_id: &str) -> Self {
+        self.tx.genesis_id = Some(genesis_id.to_string());
+        self
+    }
+
+    /// ## Suggested Params
+    ///
+    /// Take the validity window and genesis information from the given suggested params, and
+    /// calculate the fee from them once the transaction is built.
+    pub fn suggested_params(mut self, params: &SuggestedParams) -> Self {
+        self.tx.first_valid_round = Some(params.first_valid_round);
+        self.tx.genesis_id = params.genesis_id.clone();
+        self.tx.genesis_hash = Some(params.genesis_hash);
+        self.last_valid_round = params.last_valid_round;
+        self.params = Some(params.clone());
+        self
+    }
+
+    /// ## Build
+    ///
+    /// Build the transaction, erroring if any of the fields required by its type are missing or
+    /// invalid.
+    pub fn build(self) -> Result<AlgorandTransaction> {
+        let mut tx = self.tx;
+        if let Some(first_valid_round) = tx.first_valid_round {
+            tx.last_valid_round = Some(AlgorandTransaction::calculate_last_valid_round(
+                first_valid_round,
+                self.last_valid_round,
+            )?);
+        };
+        tx.fee = match (self.fee, &self.params) {
+            (Some(fee), _) => Some(fee.check_if_satisfies_minimum_fee()?.0),
+            (None, Some(params)) => Some(params.calculate_fee(&tx)?.0),
+            (None, None) => None,
+        };
+        tx.check_required_fields()?;
+        tx.check_close_targets()?;
+        Ok(tx)
+    }
+}
+
+impl TransactionBuilder<PaymentTx> {
+    /// ## Payment
+    ///
+    /// Start building a payment of the given amount of microAlgos from the sender to the receiver.
+    pub fn payment(sender: AlgorandAddress, receiver: AlgorandAddress, amount: u64) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            receiver: Some(receiver),
+            amount: Some(amount),
+            ..Default::default()
+        })
+    }
+
+    /// ## Close Account
+    ///
+    /// Start building a zero-amount payment which closes the sender's account, sweeping all of its
+    /// remaining microAlgos to the given address.
+    pub fn close_account(sender: AlgorandAddress, close_to: AlgorandAddress) -> Self {
+        Self::payment(sender, close_to, 0).close_remainder_to(close_to)
+    }
+
+    /// ## Rekey Account
+    ///
+    /// Start building a zero-amount payment from the account to itself which rekeys it to the
+    /// given address, whose key will then be required to authorize all of its transactions.
+    pub fn rekey_account(account: AlgorandAddress, auth_address: AlgorandAddress) -> Self {
+        Self::payment(account, account, 0).rekey_to(auth_address)
+    }
+
+    /// ## Rekey Back
+    ///
+    /// Start building a zero-amount payment from a rekeyed account to itself which rekeys it back
+    /// to its own address, so that its own key authorizes its transactions once more. Note that
+    /// it must be signed with the key of the address the account is currently rekeyed to.
+    pub fn rekey_back(account: AlgorandAddress) -> Self {
+        Self::payment(account, account, 0).rekey_to(account)
+    }
+
+    /// ## Close Remainder To
+    ///
+    /// Close the sender's account, sending all remaining funds to the given address.
+    pub fn close_remainder_to(mut self, address: AlgorandAddress) -> Self {
+        self.tx.close_remainder_to = Some(address);
+        self
+    }
+}
+
+impl TransactionBuilder<AssetTransferTx> {
+    /// ## Asset Transfer
+    ///
+    /// Start building a transfer of the given amount of an asset from the sender to the receiver.
+    pub fn asset_transfer(
+        sender: AlgorandAddress,
+        asset_id: u64,
+        asset_amount: impl ToAssetBaseUnits,
+        asset_receiver: AlgorandAddress,
+    ) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            transfer_asset_id: Some(asset_id),
+            asset_amount: Some(asset_amount.to_asset_base_units()),
+            asset_receiver: Some(asset_receiver),
+            ..Default::default()
+        })
+    }
+
+    /// ## Asset Opt In
+    ///
+    /// Start building an opt-in to the given asset, ie a transfer of zero of the asset from the
+    /// sender to itself.
+    pub fn asset_opt_in(sender: AlgorandAddress, asset_id: u64) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            asset_receiver: Some(sender),
+            transfer_asset_id: Some(asset_id),
+            ..Default::default()
+        })
+    }
+
+    /// ## Asset Clawback
+    ///
+    /// Start building a revocation of the given amount of an asset from the revoked account to
+    /// the receiver. The sender must be the asset's clawback address, and it is the clawback
+    /// address which signs, not the revoked account.
+    pub fn asset_clawback(
+        clawback_address: AlgorandAddress,
+        asset_id: u64,
+        asset_amount: impl ToAssetBaseUnits,
+        revoked_account: AlgorandAddress,
+        asset_receiver: AlgorandAddress,
+    ) -> Self {
+        let mut builder =
+            Self::asset_transfer(clawback_address, asset_id, asset_amount, asset_receiver);
+        builder.tx.asset_sender = Some(revoked_account);
+        builder
+    }
+
+    /// ## Asset Close Out
+    ///
+    /// Start building a zero-amount transfer which closes the sender's holding of the given asset,
+    /// sweeping all of its remaining balance of the asset to the given address and opting it out.
+    pub fn asset_close_out(
+        sender: AlgorandAddress,
+        asset_id: u64,
+        close_to: AlgorandAddress,
+    ) -> Self {
+        Self::asset_transfer(sender, asset_id, 0, close_to).asset_close_to(close_to)
+    }
+
+    /// ## Asset Close To
+    ///
+    /// Close the sender's holding of the asset, sending all of its remaining balance of the asset
+    /// to the given address.
+    pub fn asset_close_to(mut self, address: AlgorandAddress) -> Self {
+        self.tx.asset_close_to = Some(address);
+        self
+    }
+}
+
+impl TransactionBuilder<ApplicationCallTx> {
+    /// ## Application Call
+    ///
+    /// Start building a call to the given application.
+    pub fn application_call(sender: AlgorandAddress, application_id: u64) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            application_id: Some(application_id),
+            ..Default::default()
+        })
+    }
+
+    /// ## Application Create
+    ///
+    /// Start building a transaction creating an application with the given programs and state
+    /// schemas.
+    pub fn application_create(
+        sender: AlgorandAddress,
+        approval_program: Bytes,
+        clear_state_program: Bytes,
+        global_state_schema: AlgorandStateSchema,
+        local_state_schema: AlgorandStateSchema,
+    ) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            approval_program: Some(approval_program),
+            clear_state_program: Some(clear_state_program),
+            global_state_schema: Some(global_state_schema),
+            local_state_schema: Some(local_state_schema),
+            ..Default::default()
+        })
+    }
+
+    /// ## Extra Program Pages
+    ///
+    /// Request additional pages of program space for the application being created.
+    pub fn extra_program_pages(mut self, extra_program_pages: u64) -> Self {
+        self.tx.extra_program_pages = Some(extra_program_pages);
+        self
+    }
+
+    /// ## On Completion
+    ///
+    /// Set the side-effect the call has on the sender's state for this application.
+    pub fn on_completion(mut self, on_completion: OnCompletion) -> Self {
+        self.tx.on_completion = Some(on_completion.to_u64());
+        self
+    }
+
+    /// ## Application Args
+    ///
+    /// Set the arguments passed to the application.
+    pub fn application_args(mut self, application_args: Vec<AlgorandApplicationArg>) -> Self {
+        self.tx.application_args = Some(application_args);
+        self
+    }
+
+    /// ## Accounts
+    ///
+    /// Set the accounts whose state the application may access.
+    pub fn accounts(mut self, accounts: Vec<AlgorandAddress>) -> Self {
+        self.tx.accounts = Some(accounts);
+        self
+    }
+
+    /// ## Foreign Apps
+    ///
+    /// Set the applications whose state the application may access.
+    pub fn foreign_apps(mut self, foreign_apps: Vec<u64>) -> Self {
+        self.tx.foreign_apps = Some(foreign_apps);
+        self
+    }
+
+    /// ## Boxes
+    ///
+    /// Set the boxes the application may access.
+    pub fn boxes(mut self, boxes: Vec<AlgorandBoxReference>) -> Self {
+        self.tx.boxes = Some(boxes);
+        self
+    }
+
+    /// ## Foreign Assets
+    ///
+    /// Set the assets whose parameters the application may access.
+    pub fn foreign_assets(mut self, foreign_assets: Vec<u64>) -> Self {
+        self.tx.foreign_assets = Some(foreign_assets);
+        self
+    }
+}
+
+impl TransactionBuilder<AssetConfigTx> {
+    /// ## Asset Configuration
+    ///
+    /// Start building a transaction creating an asset with the given parameters.
+    pub fn asset_configuration(sender: AlgorandAddress, asset_parameters: AssetParameters) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            asset_parameters: Some(asset_parameters),
+            ..Default::default()
+        })
+    }
+
+    /// ## Asset Reconfigure
+    ///
+    /// Start building a transaction changing the role addresses of the given asset, which
+    /// currently has the given parameters. The sender must be the asset's current manager, and
+    /// any role not explicitly changed keeps its current address.
+    pub fn asset_reconfigure(
+        sender: AlgorandAddress,
+        asset_id: u64,
+        current_asset_parameters: &AssetParameters,
+        changes: &AssetReconfiguration,
+    ) -> Result<Self> {
+        match current_asset_parameters.manager_address {
+            None => {
+                Err(format!("Cannot reconfigure asset {asset_id} since it has no manager!").into())
+            },
+            Some(manager) if manager != sender => Err(format!(
+                "Cannot reconfigure asset {asset_id} from {sender} since its manager is {manager}!"
+            )
+            .into()),
+            _ => Ok(Self::new(AlgorandTransaction {
+                sender: Some(sender),
+                asset_id: Some(asset_id),
+                asset_parameters: Some(current_asset_parameters.reconfigure(changes)?),
+                ..Default::default()
+            })),
+        }
+    }
+
+    /// ## Asset Destroy
+    ///
+    /// Start building a transaction destroying the given asset.
+    pub fn asset_destroy(sender: AlgorandAddress, asset_id: u64) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            asset_id: Some(asset_id),
+            ..Default::default()
+        })
+    }
+}
+
+impl TransactionBuilder<AssetFreezeTx> {
+    /// ## Asset Freeze
+    ///
+    /// Start building a transaction freezing or unfreezing the given account's holding of an
+    /// asset, such that it cannot or can once more send or receive it. The sender must be the
+    /// asset's freeze address.
+    pub fn asset_freeze(
+        freeze_address: AlgorandAddress,
+        asset_id: u64,
+        account: AlgorandAddress,
+        frozen: bool,
+    ) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(freeze_address),
+            asset_freeze_id: Some(asset_id),
+            asset_freeze_address: Some(account),
+            asset_freeze_status: Some(frozen),
+            ..Default::default()
+        })
+    }
+}
+
+impl TransactionBuilder<KeyRegTx> {
+    /// ## Key Registration Online
+    ///
+    /// Start building a transaction registering the given participation keys for the sender,
+    /// bringing its account online to participate in consensus between the given rounds. Since
+    /// state proofs were enabled a state proof key must also be registered.
+    pub fn key_registration_online(
+        sender: AlgorandAddress,
+        vote_key: Bytes,
+        selection_key: Bytes,
+        vote_first_round: u64,
+        vote_last_round: u64,
+        vote_key_dilution: u64,
+    ) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            vote_key: Some(vote_key),
+            selection_key: Some(selection_key),
+            vote_first_round: Some(vote_first_round),
+            vote_last_round: Some(vote_last_round),
+            vote_key_dilution: Some(vote_key_dilution),
+            ..Default::default()
+        })
+    }
+
+    /// ## State Proof Key
+    ///
+    /// Register the root of the Merkle tree of Falcon keys the account will sign state proofs
+    /// with.
+    pub fn state_proof_key(mut self, state_proof_key: Bytes) -> Self {
+        self.tx.state_proof_key = Some(state_proof_key);
+        self
+    }
+
+    /// ## Key Registration Offline
+    ///
+    /// Start building a transaction registering no participation keys for the sender, taking its
+    /// account offline.
+    pub fn key_registration_offline(sender: AlgorandAddress) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            ..Default::default()
+        })
+    }
+
+    /// ## Key Registration Non Participating
+    ///
+    /// Start building a transaction marking the sender's account as never participating in
+    /// consensus again. Note that this cannot be undone.
+    pub fn key_registration_non_participating(sender: AlgorandAddress) -> Self {
+        Self::new(AlgorandTransaction {
+            sender: Some(sender),
+            non_participation: Some(true),
+            ..Default::default()
+        })
+    }
+}
+
+impl AlgorandTransaction {
+    fn is_online_key_registration(&self) -> bool {
+        self.vote_key.is_some()
+            || self.selection_key.is_some()
+            || self.state_proof_key.is_some()
+            || self.vote_first_round.is_some()
+            || self.vote_last_round.is_some()
+            || self.vote_key_dilution.is_some()
+    }
+
+    /// ## Check Required Fields
+    ///
+    /// Check that all the fields required by the type of this transaction are present.
+    pub(crate) fn check_required_fields(&self) -> Result<()> {
+        let tx_type = match &self.txn_type {
+            Some(tx_type) => tx_type,
+            None => return Err("Cannot build transaction - no transaction type set!".into()),
+        };
+        let mut missing_fields = vec![];
+        let mut require = |is_present: bool, field: &'static str| {
+            if !is_present {
+                missing_fields.push(field)
+            }
+        };
+        require(self.sender.is_some(), "sender");
+        require(self.fee.is_some(), "fee");
+        require(self.first_valid_round.is_some(), "first_valid_round");
+        require(self.last_valid_round.is_some(), "last_valid_round");
+        require(self.genesis_hash.is_some(), "genesis_hash");
+        match tx_type {
+            AlgorandTransactionType::Pay => require(self.receiver.is_some(), "receiver"),
+            AlgorandTransactionType::AssetTransfer => {
+                require(self.transfer_asset_id.is_some(), "transfer_asset_id");
+                require(self.asset_receiver.is_some(), "asset_receiver");
+            },
+            AlgorandTransactionType::ApplicationCall => require(
+                self.application_id.is_some()
+                    || (self.approval_program.is_some() && self.clear_state_program.is_some()),
+                "application_id or approval_program and clear_state_program",
+            ),
+            AlgorandTransactionType::AssetConfiguration => require(
+                self.asset_id.is_some() || self.asset_parameters.is_some(),
+                "asset_id or asset_parameters",
+            ),
+            AlgorandTransactionType::AssetFreeze => {
+                require(self.asset_freeze_id.is_some(), "asset_freeze_id");
+                require(self.asset_freeze_address.is_some(), "asset_freeze_address");
+            },
+            // NOTE: An offline key registration has none of the participation fields, but an
+            // online one needs all those which every protocol version requires.
+            AlgorandTransactionType::KeyRegistration if self.is_online_key_registration() => {
+                require(self.vote_key.is_some(), "vote_key");
+                require(self.selection_key.is_some(), "selection_key");
+                require(self.vote_first_round.is_some(), "vote_first_round");
+                require(self.vote_last_round.is_some(), "vote_last_round");
+                require(self.vote_key_dilution.is_some(), "vote_key_dilution");
+            },
+            _ => (),
+        };
+        if missing_fields.is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Cannot build {tx_type} transaction - missing required fields: {}!",
+                missing_fields.join(", ")
+            )
+            .into())
+        }
+    }
+
+    /// ## Check Close Targets
+    ///
+    /// Check that neither the account nor any asset holding of the sender is being closed to the
+    /// sender itself.
+    pub(crate) fn check_close_targets(&self) -> Result<()> {
+        match (&self.sender, &self.close_remainder_to, &self.asset_close_to) {
+            (Some(sender), Some(close_to), _) if close_to == sender => {
+                Err(format!("Cannot close account {sender} to itself!").into())
+            },
+            (Some(sender), _, Some(close_to)) if close_to == sender => {
+                Err(format!("Cannot close asset holding of {sender} to itself!").into())
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use base64::decode as base64_decode;
+
+    use super::*;
+    use crate::{
+        algorand_errors::AlgorandError,
+        algorand_transactions::{asset_amount::AssetAmount, test_utils::get_sample_pay_tx},
+        test_utils::get_sample_algorand_address,
+    };
+
+    #[test]
+    fn should_build_pay_tx_matching_positional_constructor() {
+        let sample_tx = get_sample_pay_tx();
+        let result = TransactionBuilder::payment(
+            sample_tx.sender.unwrap(),
+            sample_tx.receiver.unwrap(),
+            1001337,
+        )
+        .fee(MicroAlgos::minimum_fee())
+        .validity_window(1000, None)
+        .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+        .build()
+        .unwrap();
+        assert_eq!(result, sample_tx);
+        assert_eq!(result.to_id().unwrap(), sample_tx.to_id().unwrap());
+    }
+
+    #[test]
+    fn should_set_optional_fields() {
+        let address = get_sample_algorand_address();
+        let close_to = get_sample_pay_tx().receiver.unwrap();
+        let lease = AlgorandHash::mainnet_genesis_hash().unwrap();
+        let result = TransactionBuilder::payment(address, address, 1)
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, Some(1500))
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .genesis_id("mainnet-v1.0")
+            .note(vec![1, 3, 3, 7])
+            .lease(lease)
+            .rekey_to(address)
+            .close_remainder_to(close_to)
+            .build()
+            .unwrap();
+        assert_eq!(result.last_valid_round, Some(1500));
+        assert_eq!(result.genesis_id, Some("mainnet-v1.0".to_string()));
+        assert_eq!(result.note, Some(vec![1, 3, 3, 7]));
+        assert_eq!(result.lease, Some(lease));
+        assert_eq!(result.rekey_to, Some(address));
+        assert_eq!(result.close_remainder_to, Some(close_to));
+    }
+
+    #[test]
+    fn should_build_rekey_account_tx() {
+        let address = get_sample_algorand_address();
+        let auth_address = get_sample_pay_tx().receiver.unwrap();
+        let result = TransactionBuilder::rekey_account(address, auth_address)
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(result.sender, Some(address));
+        assert_eq!(result.receiver, Some(address));
+        assert_eq!(result.amount, Some(0));
+        assert_eq!(result.rekey_to, Some(auth_address));
+    }
+
+    #[test]
+    fn should_build_rekey_back_tx() {
+        let address = get_sample_algorand_address();
+        let result = TransactionBuilder::rekey_back(address)
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(result.receiver, Some(address));
+        assert_eq!(result.rekey_to, Some(address));
+    }
+
+    #[test]
+    fn should_build_tx_with_suggested_params() {
+        let address = get_sample_algorand_address();
+        let params = SuggestedParams::new(
+            MicroAlgos(0),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+        .with_genesis_id("mainnet-v1.0");
+        let result = TransactionBuilder::asset_opt_in(address, 1337)
+            .suggested_params(&params)
+            .build()
+            .unwrap();
+        let expected_result =
+            AlgorandTransaction::asset_opt_in_with_params(1337, address, &params).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_build_asset_transfer_tx_with_asset_amount() {
+        let address = get_sample_algorand_address();
+        let amount = AssetAmount::from_decimal_str("12.5", 6).unwrap();
+        let result = TransactionBuilder::asset_transfer(address, 1337, amount, address)
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(result.asset_amount, Some(12_500_000));
+    }
+
+    #[test]
+    fn should_fail_to_build_tx_with_missing_fields() {
+        let address = get_sample_algorand_address();
+        let expected_error = "Cannot build axfer transaction - missing required fields: fee, first_valid_round, last_valid_round, genesis_hash!";
+        match TransactionBuilder::asset_transfer(address, 1337, 1, address).build() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_required_fields_check_for_type_specific_fields() {
+        let sample_tx = AlgorandTransaction {
+            receiver: None,
+            ..get_sample_pay_tx()
+        };
+        let expected_error = "Cannot build pay transaction - missing required fields: receiver!";
+        match sample_tx.check_required_fields() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_build_asset_freeze_tx() {
+        let freeze_address = get_sample_algorand_address();
+        let account = get_sample_pay_tx().receiver.unwrap();
+        let result = TransactionBuilder::asset_freeze(freeze_address, 1337, account, true)
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(result.txn_type, Some(AlgorandTransactionType::AssetFreeze));
+        assert_eq!(result.sender, Some(freeze_address));
+        assert_eq!(result.asset_freeze_id, Some(1337));
+        assert_eq!(result.asset_freeze_address, Some(account));
+        assert_eq!(result.asset_freeze_status, Some(true));
+    }
+
+    #[test]
+    fn should_fail_to_build_asset_freeze_tx_with_missing_fields() {
+        let sample_tx = AlgorandTransaction {
+            asset_freeze_address: None,
+            ..TransactionBuilder::asset_freeze(
+                get_sample_algorand_address(),
+                1337,
+                get_sample_algorand_address(),
+                false,
+            )
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap()
+        };
+        let expected_error =
+            "Cannot build afrz transaction - missing required fields: asset_freeze_address!";
+        match sample_tx.check_required_fields() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_build_online_key_reg_tx_matching_mainnet_tx() {
+        // NOTE: See: https://algoexplorer.io/tx/J7PM3VVIO3QI4NAAV5RKWVHSR6TCHQMIWA3CL37IKKOT5HXPEI3A
+        let result = TransactionBuilder::key_registration_online(
+            AlgorandAddress::from_str("RGCCNLDIWPYWIHGHD2BS45B5MEIU46PXCJS5BKZMEZRFYTQ5GHFX7SS5X4")
+                .unwrap(),
+            base64_decode("ovIT4d0En9cU2KWT2pap9KURilHLP5T9M4sz2jPmg1M=").unwrap(),
+            base64_decode("eGygSMS/6rRRPdU62RDGY+CQwuAM/OhxZY7K5KX28yI=").unwrap(),
+            17794000,
+            20794000,
+            10000,
+        )
+        .fee(MicroAlgos::minimum_fee())
+        .validity_window(17964817, Some(17965817))
+        .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+        .build()
+        .unwrap();
+        assert_eq!(
+            result.to_id().unwrap(),
+            "J7PM3VVIO3QI4NAAV5RKWVHSR6TCHQMIWA3CL37IKKOT5HXPEI3A"
+        );
+    }
+
+    #[test]
+    fn should_build_offline_and_non_participating_key_reg_txs() {
+        let address = get_sample_algorand_address();
+        let build = |builder: TransactionBuilder<KeyRegTx>| {
+            builder
+                .fee(MicroAlgos::minimum_fee())
+                .validity_window(1000, None)
+                .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+                .build()
+                .unwrap()
+        };
+        let offline = build(TransactionBuilder::key_registration_offline(address));
+        let non_participating = build(TransactionBuilder::key_registration_non_participating(
+            address,
+        ));
+        assert_eq!(
+            offline.txn_type,
+            Some(AlgorandTransactionType::KeyRegistration)
+        );
+        assert_eq!(offline.vote_key, None);
+        assert_eq!(offline.non_participation, None);
+        assert_eq!(non_participating.non_participation, Some(true));
+    }
+
+    #[test]
+    fn should_fail_to_build_online_key_reg_tx_with_missing_fields() {
+        let sample_tx = AlgorandTransaction {
+            selection_key: None,
+            vote_key_dilution: None,
+            ..TransactionBuilder::key_registration_online(
+                get_sample_algorand_address(),
+                vec![0; 32],
+                vec![1; 32],
+                1000,
+                2000,
+                10,
+            )
+            .state_proof_key(vec![2; 64])
+            .fee(MicroAlgos::minimum_fee())
+            .validity_window(1000, None)
+            .genesis_hash(AlgorandHash::mainnet_genesis_hash().unwrap())
+            .build()
+            .unwrap()
+        };
+        let expected_error = "Cannot build keyreg transaction - missing required fields: selection_key, vote_key_dilution!";
+        match sample_tx.check_required_fields() {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
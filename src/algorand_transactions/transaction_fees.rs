@@ -0,0 +1,440 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_logic_sig::AlgorandLogicSig,
+    algorand_micro_algos::MicroAlgos,
+    algorand_multisig::AlgorandMultisig,
+    algorand_signature::AlgorandSignature,
+    algorand_traits::ToMsgPackBytes,
+    algorand_transactions::{
+        suggested_params::SuggestedParams,
+        transaction::AlgorandTransaction,
+        transactions_group::AlgorandTxGroup,
+    },
+    algorand_types::Result,
+};
+
+/// ## Algorand Signature Kind
+///
+/// The way in which a transaction is to be authorized, which determines how much the signature
+/// adds to the size of the signed transaction.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum AlgorandSignatureKind {
+    /// A single ED25519 signature by the sender.
+    #[default]
+    Single,
+
+    /// A multisig, which will carry signatures from as many of its participants as its threshold
+    /// requires. Any signatures it already carries are counted towards that threshold.
+    Multisig(AlgorandMultisig),
+
+    /// A logic sig, whose program, arguments and any delegating signature are all included.
+    LogicSig(AlgorandLogicSig),
+}
+
+#[skip_serializing_none]
+#[derive(Serialize)]
+struct SignedTransactionSizeEstimate<'a> {
+    lsig: Option<&'a AlgorandLogicSig>,
+    msig: Option<AlgorandMultisig>,
+    sgnr: Option<AlgorandAddress>,
+    sig: Option<AlgorandSignature>,
+    txn: &'a AlgorandTransaction,
+}
+
+impl ToMsgPackBytes for SignedTransactionSizeEstimate<'_> {}
+
+fn get_placeholder_signature() -> AlgorandSignature {
+    AlgorandSignature::from_byte_array([0u8; 64])
+}
+
+fn fill_multisig_to_threshold(multisig: &AlgorandMultisig) -> AlgorandMultisig {
+    let mut filled_multisig = multisig.clone();
+    let mut num_missing = (multisig.threshold as usize).saturating_sub(multisig.num_signatures());
+    filled_multisig
+        .subsigs
+        .iter_mut()
+        .filter(|subsig| subsig.signature.is_none())
+        .for_each(|subsig| {
+            if num_missing > 0 {
+                subsig.signature = Some(get_placeholder_signature());
+                num_missing -= 1;
+            }
+        });
+    filled_multisig
+}
+
+impl AlgorandTransaction {
+    /// ## Estimate Size
+    ///
+    /// Estimate the size in bytes of this transaction once signed by its sender with a single
+    /// signature.
+    pub fn estimate_size(&self) -> Result<u64> {
+        self.estimate_size_with_signature_kind(&AlgorandSignatureKind::Single)
+    }
+
+    /// ## Estimate Size With Signature Kind
+    ///
+    /// Estimate the size in bytes of this transaction once authorized in the given way. Should
+    /// the authorizing multisig's address differ from the sender's, the size of the signer field
+    /// is included too.
+    pub fn estimate_size_with_signature_kind(
+        &self,
+        signature_kind: &AlgorandSignatureKind,
+    ) -> Result<u64> {
+        let mut estimate = SignedTransactionSizeEstimate {
+            lsig: None,
+            msig: None,
+            sgnr: None,
+            sig: None,
+            txn: self,
+        };
+        match signature_kind {
+            AlgorandSignatureKind::Single => estimate.sig = Some(get_placeholder_signature()),
+            AlgorandSignatureKind::Multisig(multisig) => {
+                let multisig_address = multisig.to_address()?;
+                if self.sender != Some(multisig_address) {
+                    estimate.sgnr = Some(multisig_address)
+                };
+                estimate.msig = Some(fill_multisig_to_threshold(multisig));
+            },
+            AlgorandSignatureKind::LogicSig(logic_sig) => estimate.lsig = Some(logic_sig),
+        };
+        Ok(estimate.to_msg_pack_bytes()?.len() as u64)
+    }
+
+    /// ## Fee For
+    ///
+    /// Calculate the fee this transaction should pay under the given params, assuming it is
+    /// signed by its sender with a single signature.
+    pub fn fee_for(&self, params: &SuggestedParams) -> Result<MicroAlgos> {
+        params.calculate_fee(self)
+    }
+
+    /// ## Fee For Signature Kind
+    ///
+    /// Calculate the fee this transaction should pay under the given params, once authorized in
+    /// the given way.
+    pub fn fee_for_signature_kind(
+        &self,
+        params: &SuggestedParams,
+        signature_kind: &AlgorandSignatureKind,
+    ) -> Result<MicroAlgos> {
+        params.calculate_fee_covering(self, signature_kind, MicroAlgos(0))
+    }
+}
+
+impl AlgorandTxGroup {
+    fn regroup(transactions: &[AlgorandTransaction]) -> Result<Self> {
+        Self::new(
+            &transactions
+                .iter()
+                .cloned()
+                .map(|mut tx| {
+                    tx.group = None;
+                    tx
+                })
+                .collect::<Vec<AlgorandTransaction>>(),
+        )
+    }
+
+    fn check_index(&self, index: usize) -> Result<()> {
+        if index < self.txs.len() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Cannot get transaction {index} from a group of {} transactions!",
+                self.txs.len()
+            )
+            .into())
+        }
+    }
+
+    /// ## With Pooled Fees
+    ///
+    /// Have the transaction at the given index pay the fees of the whole group under the given
+    /// params, setting the fees of every other transaction to zero. Since changing a fee changes
+    /// the group, the group ID is recomputed.
+    pub fn with_pooled_fees(&self, payer_index: usize, params: &SuggestedParams) -> Result<Self> {
+        self.check_index(payer_index)?;
+        let mut transactions = self.txs.clone();
+        let mut other_fees = MicroAlgos(0);
+        for (i, tx) in transactions.iter_mut().enumerate() {
+            if i != payer_index {
+                tx.fee = Some(0);
+                other_fees = other_fees
+                    .checked_add(params.calculate_required_fee(tx, &AlgorandSignatureKind::Single)?)
+                    .ok_or("Pooled fees of the group overflow!")?;
+            }
+        }
+        let payer = &transactions[payer_index];
+        let payer_fee =
+            params.calculate_fee_covering(payer, &AlgorandSignatureKind::Single, other_fees)?;
+        transactions[payer_index].fee = Some(payer_fee.0);
+        Self::regroup(&transactions)
+    }
+
+    /// ## With Inner Transaction Fees
+    ///
+    /// Have the transaction at the given index, usually an application call, pay the fees of the
+    /// given number of inner transactions it will issue, each of which requires the minimum fee.
+    /// Since changing a fee changes the group, the group ID is recomputed.
+    pub fn with_inner_transaction_fees(
+        &self,
+        index: usize,
+        num_inner_txs: u64,
+        params: &SuggestedParams,
+    ) -> Result<Self> {
+        self.check_index(index)?;
+        let mut transactions = self.txs.clone();
+        let inner_fees = params.min_fee.checked_mul(num_inner_txs).ok_or_else(|| {
+            format!(
+                "Fees for {num_inner_txs} inner transactions at {} each overflow!",
+                params.min_fee
+            )
+        })?;
+        let fee = params.calculate_fee_covering(
+            &transactions[index],
+            &AlgorandSignatureKind::Single,
+            inner_fees,
+        )?;
+        transactions[index].fee = Some(fee.0);
+        Self::regroup(&transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        algorand_errors::AlgorandError,
+        algorand_hash::AlgorandHash,
+        algorand_transactions::transaction_builder::TransactionBuilder,
+        test_utils::{get_sample_algorand_address, get_sample_algorand_keys},
+    };
+
+    fn get_sample_params(fee_per_byte: u64) -> SuggestedParams {
+        SuggestedParams::new(
+            MicroAlgos(fee_per_byte),
+            1000,
+            AlgorandHash::mainnet_genesis_hash().unwrap(),
+        )
+    }
+
+    fn get_sample_pay_tx(params: &SuggestedParams) -> AlgorandTransaction {
+        TransactionBuilder::payment(
+            get_sample_algorand_address(),
+            AlgorandAddress::from_str("IL5YIRUX577LJ5FVOMATHTB6XR7KQDJTGWG24VLDGFHU2NOWKH67UL2ULI")
+                .unwrap(),
+            1_000_000,
+        )
+        .suggested_params(params)
+        .build()
+        .unwrap()
+    }
+
+    fn get_sample_app_call_tx(params: &SuggestedParams) -> AlgorandTransaction {
+        TransactionBuilder::application_call(get_sample_algorand_address(), 1337)
+            .suggested_params(params)
+            .build()
+            .unwrap()
+    }
+
+    fn get_sample_multisig() -> AlgorandMultisig {
+        AlgorandMultisig::new(2, &[
+            get_sample_algorand_address(),
+            AlgorandAddress::from_str("IL5YIRUX577LJ5FVOMATHTB6XR7KQDJTGWG24VLDGFHU2NOWKH67UL2ULI")
+                .unwrap(),
+            AlgorandAddress::from_str("3XOLRWTASJY25KA6PVMAC3MQBWY4RW3HRAKTSL6ZXJJEJA4B2ODQP3OWGA")
+                .unwrap(),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn should_estimate_size_of_single_signed_tx() {
+        let tx = get_sample_pay_tx(&get_sample_params(0));
+        let expected_result = tx
+            .sign(&get_sample_algorand_keys())
+            .unwrap()
+            .to_msg_pack_bytes()
+            .unwrap()
+            .len() as u64;
+        let result = tx.estimate_size().unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_estimate_size_of_multisig_tx() {
+        let multisig = get_sample_multisig();
+        let tx = AlgorandTransaction {
+            sender: Some(multisig.to_address().unwrap()),
+            ..get_sample_pay_tx(&get_sample_params(0))
+        };
+        let single_size = tx.estimate_size().unwrap();
+        let result = tx
+            .estimate_size_with_signature_kind(&AlgorandSignatureKind::Multisig(multisig.clone()))
+            .unwrap();
+        // NOTE: The `sig` field is replaced by an `msig` field, which holds the version,
+        // threshold, & three subsigs, two of which carry signatures.
+        let expected_msig_size =
+            5 + 1 + (7 + 1 + 3 * (1 + 3 + 34) + 2 * (2 + 66)) + (4 + 1) + (2 + 1);
+        let expected_sig_size = 4 + 66;
+        assert_eq!(result, single_size - expected_sig_size + expected_msig_size);
+    }
+
+    #[test]
+    fn should_include_signer_in_size_of_rekeyed_multisig_tx() {
+        let multisig = get_sample_multisig();
+        let tx = get_sample_pay_tx(&get_sample_params(0));
+        let kind = AlgorandSignatureKind::Multisig(multisig.clone());
+        let result = tx.estimate_size_with_signature_kind(&kind).unwrap();
+        let tx_from_multisig = AlgorandTransaction {
+            sender: Some(multisig.to_address().unwrap()),
+            ..tx
+        };
+        let size_without_signer = tx_from_multisig
+            .estimate_size_with_signature_kind(&kind)
+            .unwrap();
+        assert_eq!(result, size_without_signer + 5 + 34);
+    }
+
+    #[test]
+    fn should_estimate_size_of_logic_sig_tx() {
+        let tx = get_sample_pay_tx(&get_sample_params(0));
+        let program = vec![0x06, 0x81, 0x01];
+        let lsig = AlgorandLogicSig::new(&program, &[]);
+        let result = tx
+            .estimate_size_with_signature_kind(&AlgorandSignatureKind::LogicSig(lsig.clone()))
+            .unwrap();
+        let expected_lsig_size = 5 + lsig.to_msg_pack_bytes().unwrap().len() as u64;
+        let expected_sig_size = 4 + 66;
+        assert_eq!(
+            result,
+            tx.estimate_size().unwrap() - expected_sig_size + expected_lsig_size
+        );
+    }
+
+    #[test]
+    fn should_calculate_fee_for_tx_per_byte() {
+        let fee_per_byte = 10;
+        let params = get_sample_params(fee_per_byte);
+        let tx = get_sample_pay_tx(&params);
+        let result = tx.fee_for(&params).unwrap();
+        assert_eq!(
+            result,
+            MicroAlgos(fee_per_byte * tx.estimate_size().unwrap())
+        );
+        assert_eq!(tx.fee, Some(result.0));
+    }
+
+    #[test]
+    fn should_calculate_larger_fee_for_multisig_tx() {
+        let params = get_sample_params(10);
+        let tx = get_sample_pay_tx(&params);
+        let kind = AlgorandSignatureKind::Multisig(get_sample_multisig());
+        let result = tx.fee_for_signature_kind(&params, &kind).unwrap();
+        let mut tx_with_fee = tx.clone();
+        tx_with_fee.fee = Some(result.0);
+        let expected_result = MicroAlgos(
+            10 * tx_with_fee
+                .estimate_size_with_signature_kind(&kind)
+                .unwrap(),
+        );
+        assert_eq!(result, expected_result);
+        assert!(result > tx.fee_for(&params).unwrap());
+    }
+
+    #[test]
+    fn should_pool_fees_in_group() {
+        let params = get_sample_params(0);
+        let group = AlgorandTxGroup::new(&[
+            get_sample_pay_tx(&params),
+            get_sample_app_call_tx(&params),
+            get_sample_pay_tx(&params),
+        ])
+        .unwrap();
+        let result = group.with_pooled_fees(1, &params).unwrap();
+        let fees = result
+            .txs
+            .iter()
+            .map(|tx| tx.fee.unwrap_or_default())
+            .collect::<Vec<u64>>();
+        assert_eq!(fees, vec![0, 3000, 0]);
+        assert_ne!(result.group_id, group.group_id);
+        let expected_group = AlgorandTxGroup::new(
+            &result
+                .txs
+                .iter()
+                .cloned()
+                .map(|mut tx| {
+                    tx.group = None;
+                    tx
+                })
+                .collect::<Vec<AlgorandTransaction>>(),
+        )
+        .unwrap();
+        assert_eq!(result, expected_group);
+        result
+            .txs
+            .iter()
+            .for_each(|tx| assert_eq!(tx.group().unwrap(), result.group_id));
+    }
+
+    #[test]
+    fn should_pool_per_byte_fees_in_group() {
+        let params = get_sample_params(10);
+        let group =
+            AlgorandTxGroup::new(&[get_sample_pay_tx(&params), get_sample_app_call_tx(&params)])
+                .unwrap();
+        let result = group.with_pooled_fees(0, &params).unwrap();
+        let payer = &result.txs[0];
+        let other = &result.txs[1];
+        assert_eq!(other.fee, Some(0));
+        let expected_fee = 10 * (payer.estimate_size().unwrap() + other.estimate_size().unwrap());
+        assert_eq!(payer.fee, Some(expected_fee));
+    }
+
+    #[test]
+    fn should_cover_inner_transaction_fees() {
+        let params = get_sample_params(0);
+        let group =
+            AlgorandTxGroup::new(&[get_sample_pay_tx(&params), get_sample_app_call_tx(&params)])
+                .unwrap();
+        let result = group.with_inner_transaction_fees(1, 3, &params).unwrap();
+        assert_eq!(result.txs[0].fee, Some(1000));
+        assert_eq!(result.txs[1].fee, Some(4000));
+        assert_ne!(result.group_id, group.group_id);
+    }
+
+    #[test]
+    fn should_fail_to_cover_inner_transaction_fees_if_they_overflow() {
+        let params = get_sample_params(0);
+        let group =
+            AlgorandTxGroup::new(&[get_sample_pay_tx(&params), get_sample_app_call_tx(&params)])
+                .unwrap();
+        let expected_error =
+            "Fees for 18446744073709551615 inner transactions at 0.001000 ALGO each overflow!";
+        match group.with_inner_transaction_fees(1, u64::MAX, &params) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_pool_fees_with_out_of_range_payer() {
+        let params = get_sample_params(0);
+        let group = AlgorandTxGroup::new(&[get_sample_pay_tx(&params)]).unwrap();
+        let expected_error = "Cannot get transaction 1 from a group of 1 transactions!";
+        match group.with_pooled_fees(1, &params) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
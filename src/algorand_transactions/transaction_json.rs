@@ -14,6 +14,7 @@ use crate::{
         key_reg_transaction::KeyRegTransactionJson,
         pay_transaction::PaymentTransactionJson,
         signature_json::AlgorandSignatureJson,
+        state_proof_transaction::StateProofTransactionJson,
         transaction_type::AlgorandTransactionType,
     },
     algorand_types::{Byte, Bytes, Result},
@@ -70,11 +71,17 @@ pub struct AlgorandTransactionJson {
     #[serde(rename = "asset-config-transaction")]
     pub asset_config_transaction: Option<AssetConfigTransactionJson>,
 
-    #[serde(rename = "key-reg-transaction")]
+    #[serde(rename = "keyreg-transaction")]
     pub key_reg_transaction: Option<KeyRegTransactionJson>,
 
+    #[serde(rename = "state-proof-transaction")]
+    pub state_proof_transaction: Option<StateProofTransactionJson>,
+
     pub id: Option<String>,
 
+    #[serde(rename = "created-asset-index")]
+    pub created_asset_index: Option<u64>,
+
     #[serde(rename = "inner-txns")]
     pub inner_txs: Option<Vec<AlgorandTransactionJson>>,
 
@@ -252,6 +259,7 @@ impl AlgorandTransactionJson {
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use super::*;
     use crate::algorand_transactions::test_utils::{
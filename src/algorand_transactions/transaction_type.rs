@@ -1,10 +1,10 @@
 use std::{default::Default, fmt, str::FromStr};
 
-use serde::{Deserialize, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{algorand_errors::AlgorandError, algorand_types::Result};
 
-#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AlgorandTransactionType {
     Pay,
     StateProof,
@@ -43,12 +43,20 @@ impl Serialize for AlgorandTransactionType {
     }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for AlgorandTransactionType {
     fn default() -> Self {
         Self::Pay
     }
 }
 
+impl<'de> Deserialize<'de> for AlgorandTransactionType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
 impl FromStr for AlgorandTransactionType {
     type Err = AlgorandError;
 
@@ -0,0 +1,285 @@
+use crate::{
+    algorand_consensus::consensus_params::ConsensusParams,
+    algorand_errors::AlgorandError,
+    algorand_transactions::{
+        transaction::AlgorandTransaction,
+        transactions_group::AlgorandTxGroup,
+    },
+    algorand_types::Result,
+};
+
+fn check_max(violations: &mut Vec<String>, description: &str, value: usize, max: usize) {
+    if value > max {
+        violations.push(format!(
+            "{description} of {value} exceeds the maximum of {max}"
+        ))
+    }
+}
+
+fn violations_to_result(violations: Vec<String>) -> Result<()> {
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(AlgorandError::ValidationErrors(violations))
+    }
+}
+
+impl AlgorandTransaction {
+    /// ## Get Violations
+    ///
+    /// Get a description of every way in which this transaction breaks the given consensus
+    /// params. Note that the lease is not checked since its type guarantees it is 32 bytes.
+    pub(crate) fn get_violations(&self, params: &ConsensusParams) -> Vec<String> {
+        let mut violations = vec![];
+        let mut check = |description: &str, value: usize, max: usize| {
+            check_max(&mut violations, description, value, max)
+        };
+        let count = |maybe_vec: Option<usize>| maybe_vec.unwrap_or_default();
+
+        if let Some(note) = &self.note {
+            check("Note size", note.len(), params.max_txn_note_bytes);
+        };
+
+        if let Some(args) = &self.application_args {
+            check(
+                "Number of application args",
+                args.len(),
+                params.max_app_args,
+            );
+            check(
+                "Total application args size",
+                args.iter().map(|arg| arg.len()).sum(),
+                params.max_app_total_arg_len,
+            );
+        };
+
+        let num_accounts = count(self.accounts.as_ref().map(|x| x.len()));
+        let num_foreign_apps = count(self.foreign_apps.as_ref().map(|x| x.len()));
+        let num_foreign_assets = count(self.foreign_assets.as_ref().map(|x| x.len()));
+        let num_boxes = count(self.boxes.as_ref().map(|x| x.len()));
+        check(
+            "Number of accounts",
+            num_accounts,
+            params.max_app_txn_accounts,
+        );
+        check(
+            "Number of foreign apps",
+            num_foreign_apps,
+            params.max_app_txn_foreign_apps,
+        );
+        check(
+            "Number of foreign assets",
+            num_foreign_assets,
+            params.max_app_txn_foreign_assets,
+        );
+        check(
+            "Number of box references",
+            num_boxes,
+            params.max_app_box_references,
+        );
+        check(
+            "Total number of references",
+            num_accounts + num_foreign_apps + num_foreign_assets + num_boxes,
+            params.max_app_total_txn_references,
+        );
+
+        if let Some(asset_parameters) = &self.asset_parameters {
+            let size =
+                |maybe_string: &Option<String>| count(maybe_string.as_ref().map(|x| x.len()));
+            check(
+                "Asset name size",
+                size(&asset_parameters.asset_name),
+                params.max_asset_name_bytes,
+            );
+            check(
+                "Asset unit name size",
+                size(&asset_parameters.unit_name),
+                params.max_asset_unit_name_bytes,
+            );
+            check(
+                "Asset URL size",
+                size(&asset_parameters.asset_url),
+                params.max_asset_url_bytes,
+            );
+        };
+
+        if let Some(fee) = self.fee {
+            if fee < params.min_txn_fee {
+                violations.push(format!(
+                    "Fee of {fee} is below the minimum of {}",
+                    params.min_txn_fee
+                ))
+            }
+        };
+
+        if let (Some(first_valid_round), Some(last_valid_round)) =
+            (self.first_valid_round, self.last_valid_round)
+        {
+            if last_valid_round < first_valid_round {
+                violations.push("Last valid round is before the first valid round".to_string())
+            } else if last_valid_round - first_valid_round > params.max_txn_life {
+                violations.push(format!(
+                    "Validity window of {} rounds exceeds the maximum of {}",
+                    last_valid_round - first_valid_round,
+                    params.max_txn_life
+                ))
+            }
+        };
+
+        violations
+    }
+
+    /// ## Validate
+    ///
+    /// Check this transaction against the limits of the given consensus params, returning every
+    /// violation found at once.
+    pub fn validate(&self, params: &ConsensusParams) -> Result<()> {
+        violations_to_result(self.get_violations(params))
+    }
+}
+
+impl AlgorandTxGroup {
+    /// ## Validate Transactions
+    ///
+    /// Check the given transactions, intended to be grouped, against the limits of the given
+    /// consensus params, returning every violation found at once.
+    pub fn validate_transactions(
+        transactions: &[AlgorandTransaction],
+        params: &ConsensusParams,
+    ) -> Result<()> {
+        let mut violations = vec![];
+        check_max(
+            &mut violations,
+            "Group size",
+            transactions.len(),
+            params.max_tx_group_size,
+        );
+        transactions.iter().enumerate().for_each(|(i, tx)| {
+            violations.extend(
+                tx.get_violations(params)
+                    .iter()
+                    .map(|violation| format!("Transaction {i}: {violation}")),
+            )
+        });
+        violations_to_result(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        algorand_applications::{
+            algorand_application_args::AlgorandApplicationArg,
+            algorand_box_reference::AlgorandBoxReference,
+        },
+        algorand_transactions::test_utils::{get_sample_acfg_tx_json_string, get_sample_pay_tx},
+    };
+
+    #[test]
+    fn should_validate_valid_tx() {
+        let result = get_sample_pay_tx().validate(&ConsensusParams::default());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_validate_tx_with_note_too_large() {
+        let tx = AlgorandTransaction {
+            note: Some(vec![0u8; 1025]),
+            ..get_sample_pay_tx()
+        };
+        let expected_violations = vec!["Note size of 1025 exceeds the maximum of 1024".to_string()];
+        match tx.validate(&ConsensusParams::default()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::ValidationErrors(violations)) => {
+                assert_eq!(violations, expected_violations)
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_return_all_violations_at_once() {
+        let tx = AlgorandTransaction {
+            application_args: Some(vec![AlgorandApplicationArg::new(vec![0u8; 1025]); 2]),
+            accounts: Some(vec![get_sample_pay_tx().sender.unwrap(); 5]),
+            foreign_apps: Some(vec![1, 2]),
+            boxes: Some(vec![AlgorandBoxReference::new(0, b"box".to_vec()); 2]),
+            ..get_sample_pay_tx()
+        };
+        let expected_violations = vec![
+            "Total application args size of 2050 exceeds the maximum of 2048".to_string(),
+            "Number of accounts of 5 exceeds the maximum of 4".to_string(),
+            "Total number of references of 9 exceeds the maximum of 8".to_string(),
+        ];
+        match tx.validate(&ConsensusParams::default()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::ValidationErrors(violations)) => {
+                assert_eq!(violations, expected_violations)
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_validate_oversized_group() {
+        let txs = vec![get_sample_pay_tx(); 17];
+        let expected_violations = vec!["Group size of 17 exceeds the maximum of 16".to_string()];
+        match AlgorandTxGroup::validate_transactions(&txs, &ConsensusParams::default()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::ValidationErrors(violations)) => {
+                assert_eq!(violations, expected_violations)
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_validate_asset_parameters_exceeding_limits() {
+        let mut tx = AlgorandTransaction::from_str(&get_sample_acfg_tx_json_string()).unwrap();
+        let mut asset_parameters = tx.asset_parameters.clone().unwrap();
+        asset_parameters.asset_name = Some("a".repeat(33));
+        asset_parameters.unit_name = Some("a".repeat(9));
+        asset_parameters.asset_url = Some("a".repeat(97));
+        tx.asset_parameters = Some(asset_parameters);
+        let expected_violations = vec![
+            "Asset name size of 33 exceeds the maximum of 32".to_string(),
+            "Asset unit name size of 9 exceeds the maximum of 8".to_string(),
+            "Asset URL size of 97 exceeds the maximum of 96".to_string(),
+        ];
+        match tx.validate(&ConsensusParams::default()) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::ValidationErrors(violations)) => {
+                assert_eq!(violations, expected_violations)
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_reject_box_references_before_boxes_were_introduced() {
+        let tx = AlgorandTransaction {
+            boxes: Some(vec![AlgorandBoxReference::new(0, b"box".to_vec())]),
+            ..get_sample_pay_tx()
+        };
+        let expected_violations =
+            vec!["Number of box references of 1 exceeds the maximum of 0".to_string()];
+        assert!(tx
+            .validate(
+                &ConsensusParams::from_protocol("44fa607d6051730f5264526bf3c108d51f0eadb6")
+                    .unwrap()
+            )
+            .is_ok());
+        match tx.validate(
+            &ConsensusParams::from_protocol("bc36005dbd776e6d1eaf0c560619bb183215645c").unwrap(),
+        ) {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::ValidationErrors(violations)) => {
+                assert_eq!(violations, expected_violations)
+            },
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+}
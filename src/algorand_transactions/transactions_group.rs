@@ -17,10 +17,10 @@ pub struct AlgorandTxGroup {
     tx_group_hashes: Vec<AlgorandHash>,
 
     #[serde(skip_serializing)]
-    txs: Vec<AlgorandTransaction>,
+    pub(crate) txs: Vec<AlgorandTransaction>,
 
     #[serde(skip_serializing)]
-    group_id: AlgorandHash,
+    pub(crate) group_id: AlgorandHash,
 }
 
 impl AlgorandTxGroup {
@@ -60,6 +60,13 @@ impl AlgorandTxGroup {
         Ok(base64_encode(self.group_id.to_bytes()))
     }
 
+    /// ## Transactions
+    ///
+    /// Get the transactions of this group, each with the group ID assigned.
+    pub fn transactions(&self) -> &[AlgorandTransaction] {
+        &self.txs
+    }
+
     fn compute_group_id(&self) -> Result<AlgorandHash> {
         if self.tx_group_hashes.is_empty() {
             return Err(AlgorandError::Custom("Empty transactions list".to_string()));
@@ -125,6 +132,7 @@ impl AlgorandTxGroup {
 }
 
 #[cfg(test)]
+#[allow(clippy::vec_init_then_push)]
 mod tests {
     use std::str::FromStr;
 
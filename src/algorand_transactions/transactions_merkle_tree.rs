@@ -0,0 +1,356 @@
+use std::{fmt, str::FromStr};
+
+use crate::{
+    algorand_applications::algorand_eval_delta::to_msg_pack_bytes_with_raw_strs,
+    algorand_blocks::signed_transaction_in_block::AlgorandSignedTransactionInBlock,
+    algorand_errors::AlgorandError,
+    algorand_hash::AlgorandHash,
+    algorand_transactions::transaction::AlgorandTransaction,
+    algorand_types::{Byte, Bytes, Result},
+    crypto_utils::{sha256_hash_bytes, sha512_256_hash_bytes},
+};
+
+// NOTE: These prefixes are used to domain-separate the various hashes used in the protocol.
+const MERKLE_ARRAY_ELEMENT_PREFIX: [u8; 2] = *b"MA";
+const VECTOR_COMMITMENT_BOTTOM_LEAF_PREFIX: [u8; 2] = *b"MB";
+const TRANSACTION_MERKLE_LEAF_PREFIX: [u8; 2] = *b"TL";
+const SIGNED_TRANSACTION_IN_BLOCK_PREFIX: [u8; 4] = *b"STIB";
+
+/// ## Algorand Merkle Hash Type
+///
+/// The hash function a transactions Merkle tree is built with. Blocks commit to their payset
+/// with both, the SHA-512/256 root being the `txn` field of the block header, and the SHA-256
+/// root being the `txn256` field.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AlgorandMerkleHashType {
+    #[default]
+    Sha512_256,
+    Sha256,
+}
+
+impl AlgorandMerkleHashType {
+    pub(crate) fn hash_bytes(&self, bytes: &[Byte]) -> Bytes {
+        match self {
+            Self::Sha512_256 => sha512_256_hash_bytes(bytes),
+            Self::Sha256 => sha256_hash_bytes(bytes),
+        }
+    }
+}
+
+impl fmt::Display for AlgorandMerkleHashType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sha512_256 => write!(f, "sha512_256"),
+            Self::Sha256 => write!(f, "sha256"),
+        }
+    }
+}
+
+impl FromStr for AlgorandMerkleHashType {
+    type Err = AlgorandError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "sha512_256" => Ok(Self::Sha512_256),
+            "sha256" => Ok(Self::Sha256),
+            _ => Err(format!("Unsupported Merkle hash type: '{s}'!").into()),
+        }
+    }
+}
+
+/// ## Algorand Transactions Merkle Tree
+///
+/// The Merkle tree a block commits to its payset with. Each leaf is the hash of a transaction's
+/// ID along with the hash of its signed transaction in block, and each node is the hash of its
+/// children. The tree of a single transaction is just its leaf, and that of no transactions has
+/// a zeroed root.
+///
+/// The SHA-512/256 tree replaces a missing right child with a zeroed hash. The SHA-256 tree is
+/// instead a vector commitment, whose leaves are padded to a power of two with the hash of an
+/// empty `MB` prefixed bottom leaf, and placed at the bit-reversal of their index, such that a
+/// leaf's position in the tree does not depend on the number of leaves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AlgorandTransactionsMerkleTree {
+    hash_type: AlgorandMerkleHashType,
+    stib_hashes: Vec<Bytes>,
+    layers: Vec<Vec<Bytes>>,
+}
+
+impl AlgorandTransactionsMerkleTree {
+    pub(crate) fn hash_leaf(
+        hash_type: AlgorandMerkleHashType,
+        tx_id: &[Byte],
+        stib_hash: &[Byte],
+    ) -> Bytes {
+        hash_type.hash_bytes(&[&TRANSACTION_MERKLE_LEAF_PREFIX[..], tx_id, stib_hash].concat())
+    }
+
+    pub(crate) fn hash_node(
+        hash_type: AlgorandMerkleHashType,
+        left: &[Byte],
+        right: &[Byte],
+    ) -> Bytes {
+        hash_type.hash_bytes(&[&MERKLE_ARRAY_ELEMENT_PREFIX[..], left, right].concat())
+    }
+
+    fn hash_stib(
+        hash_type: AlgorandMerkleHashType,
+        stib: &AlgorandSignedTransactionInBlock,
+    ) -> Result<Bytes> {
+        Ok(hash_type.hash_bytes(
+            &[
+                SIGNED_TRANSACTION_IN_BLOCK_PREFIX.to_vec(),
+                to_msg_pack_bytes_with_raw_strs(stib)?,
+            ]
+            .concat(),
+        ))
+    }
+
+    /// ## New
+    ///
+    /// Build the tree from a block's transactions, with their genesis ID & hash restored, along
+    /// with the signed transactions in block they were decoded from.
+    pub(crate) fn new(
+        hash_type: AlgorandMerkleHashType,
+        txs: &[AlgorandTransaction],
+        payset: &[AlgorandSignedTransactionInBlock],
+    ) -> Result<Self> {
+        if txs.len() != payset.len() {
+            return Err(format!(
+                "Cannot build transactions Merkle tree from {} txs and {} signed txs in block!",
+                txs.len(),
+                payset.len()
+            )
+            .into());
+        };
+        let stib_hashes = payset
+            .iter()
+            .map(|stib| Self::hash_stib(hash_type, stib))
+            .collect::<Result<Vec<Bytes>>>()?;
+        let leaves = txs
+            .iter()
+            .zip(stib_hashes.iter())
+            .map(|(tx, stib_hash)| {
+                let tx_id = hash_type.hash_bytes(&tx.encode_for_signing()?);
+                Ok(Self::hash_leaf(hash_type, &tx_id, stib_hash))
+            })
+            .collect::<Result<Vec<Bytes>>>()?;
+        let mut layers = vec![match hash_type {
+            AlgorandMerkleHashType::Sha512_256 => leaves,
+            AlgorandMerkleHashType::Sha256 => Self::to_vector_commitment_leaves(hash_type, leaves),
+        }];
+        while layers[layers.len() - 1].len() > 1 {
+            let next_layer = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => Self::hash_node(hash_type, left, right),
+                    _ => Self::hash_node(hash_type, &pair[0], &[0u8; 32]),
+                })
+                .collect();
+            layers.push(next_layer);
+        }
+        Ok(Self {
+            hash_type,
+            stib_hashes,
+            layers,
+        })
+    }
+
+    fn to_vector_commitment_leaves(
+        hash_type: AlgorandMerkleHashType,
+        leaves: Vec<Bytes>,
+    ) -> Vec<Bytes> {
+        let depth = Self::calculate_vector_commitment_depth(leaves.len());
+        let mut padded_leaves = vec![Self::hash_bottom_leaf(hash_type); 1 << depth];
+        leaves.into_iter().enumerate().for_each(|(index, leaf)| {
+            padded_leaves[Self::reverse_bits(index, depth)] = leaf;
+        });
+        padded_leaves
+    }
+
+    /// ## Hash Bottom Leaf
+    ///
+    /// The hash of the empty leaves a vector commitment is padded with.
+    pub(crate) fn hash_bottom_leaf(hash_type: AlgorandMerkleHashType) -> Bytes {
+        hash_type.hash_bytes(&VECTOR_COMMITMENT_BOTTOM_LEAF_PREFIX)
+    }
+
+    fn calculate_vector_commitment_depth(num_leaves: usize) -> usize {
+        num_leaves.next_power_of_two().trailing_zeros() as usize
+    }
+
+    /// ## Reverse Bits
+    ///
+    /// Reverse the lowest `depth` bits of the index, which maps a leaf's index to its position
+    /// in a vector commitment and back again.
+    pub(crate) fn reverse_bits(index: usize, depth: usize) -> usize {
+        if depth == 0 {
+            index
+        } else {
+            index.reverse_bits() >> (usize::BITS as usize - depth)
+        }
+    }
+
+    /// ## Position
+    ///
+    /// Get the position in a tree of the given depth of the leaf of the transaction at the given
+    /// index.
+    pub(crate) fn position(hash_type: AlgorandMerkleHashType, index: usize, depth: usize) -> usize {
+        match hash_type {
+            AlgorandMerkleHashType::Sha512_256 => index,
+            AlgorandMerkleHashType::Sha256 => Self::reverse_bits(index, depth),
+        }
+    }
+
+    pub(crate) fn hash_type(&self) -> AlgorandMerkleHashType {
+        self.hash_type
+    }
+
+    /// ## Depth
+    ///
+    /// The number of layers above the leaves, which is also the length of each proof.
+    pub(crate) fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    pub(crate) fn root(&self) -> Result<AlgorandHash> {
+        match self.layers[self.depth()].first() {
+            Some(root) => AlgorandHash::from_slice(root),
+            None => Ok(AlgorandHash::default()),
+        }
+    }
+
+    pub(crate) fn stib_hash(&self, index: usize) -> Result<Bytes> {
+        match self.stib_hashes.get(index) {
+            Some(stib_hash) => Ok(stib_hash.clone()),
+            None => Err(format!(
+                "Cannot get tx @ index {index} - only {} txs in tree!",
+                self.stib_hashes.len()
+            )
+            .into()),
+        }
+    }
+
+    /// ## Prove
+    ///
+    /// Get the sibling of each node on the path from the leaf of the transaction at the given
+    /// index to the root, where a missing sibling is a zeroed hash.
+    pub(crate) fn prove(&self, index: usize) -> Result<Vec<Bytes>> {
+        self.stib_hash(index)?;
+        let position = Self::position(self.hash_type, index, self.depth());
+        Ok(self.layers[..self.depth()]
+            .iter()
+            .enumerate()
+            .map(|(depth, layer)| {
+                layer
+                    .get((position >> depth) ^ 1)
+                    .cloned()
+                    .unwrap_or_else(|| vec![0u8; 32])
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_blocks::{
+        block::AlgorandBlock,
+        test_utils::get_sample_block_msg_pack_bytes,
+    };
+
+    fn get_sample_tree(
+        hash_type: AlgorandMerkleHashType,
+        num_txs: usize,
+    ) -> AlgorandTransactionsMerkleTree {
+        let block = AlgorandBlock::from_msg_pack_bytes(&get_sample_block_msg_pack_bytes()).unwrap();
+        AlgorandTransactionsMerkleTree::new(
+            hash_type,
+            &block.get_transactions().unwrap()[..num_txs],
+            &block.payset.unwrap()[..num_txs],
+        )
+        .unwrap()
+    }
+
+    fn fold_proof_to_root(tree: &AlgorandTransactionsMerkleTree, index: usize) -> AlgorandHash {
+        let position =
+            AlgorandTransactionsMerkleTree::position(tree.hash_type, index, tree.depth());
+        let leaf = tree.layers[0][position].clone();
+        let root =
+            tree.prove(index)
+                .unwrap()
+                .iter()
+                .enumerate()
+                .fold(leaf, |hash, (depth, sibling)| {
+                    if (position >> depth) & 1 == 0 {
+                        AlgorandTransactionsMerkleTree::hash_node(tree.hash_type, &hash, sibling)
+                    } else {
+                        AlgorandTransactionsMerkleTree::hash_node(tree.hash_type, sibling, &hash)
+                    }
+                });
+        AlgorandHash::from_slice(&root).unwrap()
+    }
+
+    #[test]
+    fn should_get_hash_type_from_str() {
+        let result = AlgorandMerkleHashType::from_str("sha256").unwrap();
+        assert_eq!(result, AlgorandMerkleHashType::Sha256);
+        assert_eq!(result.to_string(), "sha256");
+        let result = AlgorandMerkleHashType::from_str("sha512_256").unwrap();
+        assert_eq!(result, AlgorandMerkleHashType::Sha512_256);
+    }
+
+    #[test]
+    fn should_fail_to_get_unknown_hash_type_from_str() {
+        let expected_error = "Unsupported Merkle hash type: 'sha3_256'!";
+        match AlgorandMerkleHashType::from_str("sha3_256") {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
+
+    #[test]
+    fn should_reverse_bits_of_index() {
+        assert_eq!(AlgorandTransactionsMerkleTree::reverse_bits(1, 3), 4);
+        assert_eq!(AlgorandTransactionsMerkleTree::reverse_bits(6, 3), 3);
+        assert_eq!(AlgorandTransactionsMerkleTree::reverse_bits(0, 0), 0);
+    }
+
+    #[test]
+    fn should_prove_every_tx_in_trees_with_odd_number_of_txs() {
+        [
+            AlgorandMerkleHashType::Sha512_256,
+            AlgorandMerkleHashType::Sha256,
+        ]
+        .iter()
+        .for_each(|hash_type| {
+            let tree = get_sample_tree(*hash_type, 5);
+            assert_eq!(tree.depth(), 3);
+            (0..5).for_each(|i| assert_eq!(fold_proof_to_root(&tree, i), tree.root().unwrap()));
+        });
+    }
+
+    #[test]
+    fn should_pad_sha256_tree_with_hash_of_bottom_leaf() {
+        let tree = get_sample_tree(AlgorandMerkleHashType::Sha256, 3);
+        assert_eq!(
+            hex::encode(&tree.layers[0][3]),
+            "1d09f6fa23235881514da0e5f8f789767e6b28e721fa8b6a30ae7f19aa3ea6e7"
+        );
+    }
+
+    #[test]
+    fn should_get_root_of_single_tx_tree_as_its_leaf() {
+        let tree = get_sample_tree(AlgorandMerkleHashType::Sha512_256, 1);
+        assert_eq!(tree.depth(), 0);
+        assert!(tree.prove(0).unwrap().is_empty());
+        assert_eq!(tree.root().unwrap().to_bytes(), tree.layers[0][0]);
+    }
+
+    #[test]
+    fn should_get_zeroed_root_of_empty_tree() {
+        let tree = get_sample_tree(AlgorandMerkleHashType::Sha512_256, 0);
+        assert_eq!(tree.root().unwrap(), AlgorandHash::default());
+    }
+}
@@ -0,0 +1,59 @@
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use num_bigint::BigUint;
+
+use crate::algorand_types::{Byte, Result};
+
+const CURVE25519_A: u32 = 486662;
+
+lazy_static! {
+    static ref FIELD_PRIME: BigUint = (BigUint::from(1u8) << 255) - 19u8;
+}
+
+fn invert(x: &BigUint) -> BigUint {
+    x.modpow(&(&*FIELD_PRIME - 2u8), &FIELD_PRIME)
+}
+
+fn negate(x: &BigUint) -> BigUint {
+    (&*FIELD_PRIME - x % &*FIELD_PRIME) % &*FIELD_PRIME
+}
+
+fn to_32_le_bytes(x: &BigUint) -> [Byte; 32] {
+    let mut bytes = [0u8; 32];
+    let le_bytes = x.to_bytes_le();
+    bytes[..le_bytes.len()].copy_from_slice(&le_bytes);
+    bytes
+}
+
+/// ## Elligator2 Hash To Curve
+///
+/// Map 32 uniformly random bytes to a point in the prime order subgroup of edwards25519, the way
+/// libsodium's `ge25519_from_uniform` does. The top bit of the last byte is the sign of the
+/// point's `x` coordinate, and the rest are the field element the Elligator2 map is applied to.
+///
+/// The map gives a Montgomery `u` coordinate, either `-A / (1 + 2r^2)` or `A / (1 + 2r^2) - A`,
+/// depending on which is on the curve. That's converted to the Edwards `y` coordinate
+/// `(u - 1) / (u + 1)`, and the resulting point multiplied by the cofactor.
+pub(crate) fn hash_to_curve_elligator2(bytes: &[Byte; 32]) -> Result<EdwardsPoint> {
+    let p = &*FIELD_PRIME;
+    let x_sign = bytes[31] & 0x80;
+    let mut r_bytes = *bytes;
+    r_bytes[31] &= 0x7f;
+    let r = BigUint::from_bytes_le(&r_bytes) % p;
+    let a = BigUint::from(CURVE25519_A);
+    let one = BigUint::from(1u8);
+    let u = negate(&(&a * invert(&((&r * &r * 2u8 + &one) % p)) % p));
+    let e = ((&u * &u * &u) + (&a * &u * &u) + &u) % p;
+    let is_square = e.modpow(&((p - 1u8) >> 1), p) != p - 1u8;
+    let u = if is_square {
+        u
+    } else {
+        (negate(&u) + negate(&a)) % p
+    };
+    let y = (&u + p - 1u8) * invert(&((&u + 1u8) % p)) % p;
+    let mut y_bytes = to_32_le_bytes(&y);
+    y_bytes[31] |= x_sign;
+    match CompressedEdwardsY(y_bytes).decompress() {
+        Some(point) => Ok(point.mul_by_cofactor()),
+        None => Err("Elligator2 map did not give a point on the curve!".into()),
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod binomial_cdf;
+pub(crate) mod elligator2;
+pub(crate) mod seed;
+pub(crate) mod sortition;
+pub(crate) mod test_utils;
+pub(crate) mod vrf_proof;
+pub(crate) mod vrf_public_key;
@@ -0,0 +1,267 @@
+use serde::Serialize;
+
+use crate::{
+    algorand_address::AlgorandAddress,
+    algorand_blocks::block_header::AlgorandBlockHeader,
+    algorand_consensus::consensus_params::ConsensusParams,
+    algorand_hash::AlgorandHash,
+    algorand_types::{Byte, Bytes, Result},
+    algorand_vrf::{vrf_proof::AlgorandVrfProof, vrf_public_key::AlgorandVrfPublicKey},
+    crypto_utils::sha512_256_hash_bytes,
+};
+
+const SEED_PREFIX: [u8; 2] = *b"SD";
+const PROPOSER_SEED_PREFIX: [u8; 2] = *b"PS";
+
+#[derive(Serialize)]
+struct SeedInput {
+    #[serde(rename = "a", with = "serde_bytes")]
+    alpha: Bytes,
+
+    #[serde(
+        rename = "h",
+        with = "serde_bytes",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    history: Bytes,
+}
+
+impl SeedInput {
+    // NOTE: The VRF output is hashed along with the address of the proposer who proved it.
+    fn new(vrf_output: &[Byte], proposer: &AlgorandAddress, history: Bytes) -> Self {
+        Self {
+            alpha: sha512_256_hash_bytes(&[vrf_output, &proposer.to_bytes()].concat()),
+            history,
+        }
+    }
+
+    fn hash(&self) -> Result<AlgorandHash> {
+        AlgorandHash::from_slice(&sha512_256_hash_bytes(
+            &[
+                PROPOSER_SEED_PREFIX.to_vec(),
+                rmp_serde::to_vec_named(self)?,
+            ]
+            .concat(),
+        ))
+    }
+}
+
+impl AlgorandBlockHeader {
+    /// ## Derive Seed
+    ///
+    /// Derive the seed of this header's round from the proposer's VRF proof of the previous seed,
+    /// ie that of the round `seed_lookback` rounds earlier, as the proposer of a block agreed upon
+    /// in the first period of its round does.
+    ///
+    /// The seed is the `PS` prefixed hash of the hash of the VRF output & the proposer's address,
+    /// along with, in the first `seed_lookback` rounds of every
+    /// `seed_lookback * seed_refresh_interval`, the history digest, ie the hash of the block
+    /// header that many rounds earlier.
+    pub fn derive_seed(
+        &self,
+        proposer: &AlgorandAddress,
+        vrf_public_key: &AlgorandVrfPublicKey,
+        seed_proof: &AlgorandVrfProof,
+        previous_seed: &AlgorandHash,
+        history_digest: Option<&AlgorandHash>,
+        params: &ConsensusParams,
+    ) -> Result<AlgorandHash> {
+        let refresh_period = params.seed_lookback * params.seed_refresh_interval;
+        if refresh_period == 0 {
+            return Err("Consensus params have no seed refresh period!".into());
+        };
+        let history = if self.round % refresh_period < params.seed_lookback {
+            match history_digest {
+                Some(digest) => digest.to_bytes(),
+                None => {
+                    return Err(format!(
+                        "Seed of round {} requires the digest of the header of round {}!",
+                        self.round,
+                        self.round.saturating_sub(refresh_period)
+                    )
+                    .into())
+                },
+            }
+        } else {
+            vec![]
+        };
+        let vrf_output = vrf_public_key.verify(
+            seed_proof,
+            &[&SEED_PREFIX[..], &previous_seed.to_bytes()].concat(),
+        )?;
+        SeedInput::new(&vrf_output, proposer, history).hash()
+    }
+
+    /// ## Verify Seed
+    ///
+    /// Verify that this header's seed was derived from the previous seed by the given proposer,
+    /// with the given VRF public key, per `derive_seed`.
+    pub fn verify_seed(
+        &self,
+        proposer: &AlgorandAddress,
+        vrf_public_key: &AlgorandVrfPublicKey,
+        seed_proof: &AlgorandVrfProof,
+        previous_seed: &AlgorandHash,
+        history_digest: Option<&AlgorandHash>,
+        params: &ConsensusParams,
+    ) -> Result<()> {
+        let seed = match &self.seed {
+            Some(seed) => seed,
+            None => return Err("No seed in block header!".into()),
+        };
+        let derived_seed = self.derive_seed(
+            proposer,
+            vrf_public_key,
+            seed_proof,
+            previous_seed,
+            history_digest,
+            params,
+        )?;
+        if &derived_seed == seed {
+            Ok(())
+        } else {
+            Err(format!(
+                "Block header seed does not match that derived for round {}!",
+                self.round
+            )
+            .into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        algorand_blocks::test_utils::get_sample_block_header_n,
+        algorand_vrf::test_utils::{get_sample_vrf_test_vectors, vrf_prove},
+    };
+
+    struct SeedFixture {
+        header: AlgorandBlockHeader,
+        proposer: AlgorandAddress,
+        vrf_public_key: AlgorandVrfPublicKey,
+        seed_proof: AlgorandVrfProof,
+        previous_seed: AlgorandHash,
+        history_digest: Option<AlgorandHash>,
+    }
+
+    impl SeedFixture {
+        // NOTE: Since blocks don't include their proposer's seed proof, the sample header's seed
+        // is replaced with one derived from a proof made with a VRF test vector's secret key.
+        fn new(round: u64, history_digest: Option<AlgorandHash>) -> Self {
+            let mut header = get_sample_block_header_n(0);
+            header.round = round;
+            let previous_seed = get_sample_block_header_n(1).seed.unwrap();
+            let (vrf_public_key, seed_proof) = vrf_prove(
+                &get_sample_vrf_test_vectors()[0].secret_key,
+                &[&SEED_PREFIX[..], &previous_seed.to_bytes()].concat(),
+            );
+            let proposer = AlgorandAddress::from_bytes(&[7u8; 32]).unwrap();
+            header.seed = Some(
+                header
+                    .derive_seed(
+                        &proposer,
+                        &vrf_public_key,
+                        &seed_proof,
+                        &previous_seed,
+                        history_digest.as_ref(),
+                        &ConsensusParams::default(),
+                    )
+                    .unwrap(),
+            );
+            Self {
+                header,
+                proposer,
+                vrf_public_key,
+                seed_proof,
+                previous_seed,
+                history_digest,
+            }
+        }
+
+        fn verify_seed(&self) -> Result<()> {
+            self.header.verify_seed(
+                &self.proposer,
+                &self.vrf_public_key,
+                &self.seed_proof,
+                &self.previous_seed,
+                self.history_digest.as_ref(),
+                &ConsensusParams::default(),
+            )
+        }
+    }
+
+    // NOTE: Taken independently of this crate, per go-algorand's `seedInput`, whose `a` & `h`
+    // digests are encoded as binary, omitting the history when there's none.
+    #[test]
+    fn should_hash_seed_input() {
+        let vector = &get_sample_vrf_test_vectors()[0];
+        let proposer = AlgorandAddress::from_bytes(&vector.public_key.to_bytes()).unwrap();
+        let seed_input = SeedInput::new(&vector.output, &proposer, vec![]);
+        let result = hex::encode(rmp_serde::to_vec_named(&seed_input).unwrap());
+        let expected_result =
+            "81a161c4203916c3ef56058c9b97340953d9b28986c3e328b14d7619a734c763d3466d4266";
+        assert_eq!(result, expected_result);
+        assert_eq!(
+            seed_input.hash().unwrap().to_bytes(),
+            hex::decode("95ed133acfb8a342d5bc2a4d46a8db9adeb163850cd45f916ad1201c266047fc")
+                .unwrap()
+        );
+        let seed_input = SeedInput::new(&vector.output, &proposer, vec![0x22; 32]);
+        assert_eq!(
+            seed_input.hash().unwrap().to_bytes(),
+            hex::decode("3aca45f3a0a279206050c33eb2c059bad9a662a41470496754e3fa038fd7280e")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_verify_seed() {
+        SeedFixture::new(17962555, None).verify_seed().unwrap();
+    }
+
+    #[test]
+    fn should_verify_seed_committing_to_history() {
+        let history_digest = get_sample_block_header_n(2).hash().unwrap();
+        SeedFixture::new(17962561, Some(history_digest))
+            .verify_seed()
+            .unwrap();
+    }
+
+    #[test]
+    fn should_fail_to_verify_seed_without_required_history_digest() {
+        let history_digest = get_sample_block_header_n(2).hash().unwrap();
+        let mut fixture = SeedFixture::new(17962561, Some(history_digest));
+        fixture.history_digest = None;
+        assert!(fixture.verify_seed().is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_seed_derived_from_different_previous_seed() {
+        let mut fixture = SeedFixture::new(17962555, None);
+        fixture.previous_seed = get_sample_block_header_n(2).seed.unwrap();
+        assert!(fixture.verify_seed().is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_seed_proven_by_different_vrf_key() {
+        let mut fixture = SeedFixture::new(17962555, None);
+        fixture.vrf_public_key = get_sample_vrf_test_vectors()[1].public_key;
+        assert!(fixture.verify_seed().is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_seed_of_different_proposer() {
+        let mut fixture = SeedFixture::new(17962555, None);
+        fixture.proposer = AlgorandAddress::from_bytes(&[8u8; 32]).unwrap();
+        assert!(fixture.verify_seed().is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_sample_block_seed_with_unrelated_proof() {
+        let mut fixture = SeedFixture::new(17962555, None);
+        fixture.header = get_sample_block_header_n(0);
+        assert!(fixture.verify_seed().is_err());
+    }
+}
@@ -0,0 +1,309 @@
+use std::cmp::Ordering;
+
+use num_bigint::BigUint;
+
+use crate::{
+    algorand_hash::AlgorandHash,
+    algorand_micro_algos::MicroAlgos,
+    algorand_types::Result,
+    algorand_vrf::binomial_cdf::binomial_cdf,
+};
+
+const VRF_OUTPUT_BITS: u64 = 256;
+const RATIO_PRECISION: u64 = VRF_OUTPUT_BITS + 8;
+
+// NOTE: As go-algorand does, walks the binomial CDF for `n` trials with success probability `p`,
+// returning the first `j` whose CDF is at least the given ratio, or `money` if there's none.
+fn binomial_cdf_walk(n: f64, p: f64, ratio: f64, money: u64) -> u64 {
+    (0..money)
+        .find(|j| ratio <= binomial_cdf(n, p, *j as f64))
+        .unwrap_or(money)
+}
+
+// NOTE: Shifts the value right by the given number of bits, rounding to the nearest, ties to even.
+fn round_shift_right(value: &BigUint, shift: u64) -> BigUint {
+    if shift == 0 {
+        return value.clone();
+    };
+    let quotient = value >> shift;
+    let remainder = value - (&quotient << shift);
+    let half = BigUint::from(1u8) << (shift - 1);
+    match remainder.cmp(&half) {
+        Ordering::Less => quotient,
+        Ordering::Greater => quotient + 1u8,
+        Ordering::Equal if quotient.bit(0) => quotient + 1u8,
+        Ordering::Equal => quotient,
+    }
+}
+
+/// ## Algorand Sortition
+///
+/// Cryptographic sortition, which selects an account's share of a committee, as the number of
+/// sub-users, ie units of its stake, chosen by a VRF output. Each of the `stake` sub-users is
+/// chosen with probability `expected_committee_size / total_online_stake`, so the number chosen
+/// is binomially distributed, and the VRF output picks a point on its CDF.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AlgorandSortition {
+    pub stake: MicroAlgos,
+    pub total_online_stake: MicroAlgos,
+    pub expected_committee_size: f64,
+}
+
+impl AlgorandSortition {
+    pub fn new(
+        stake: MicroAlgos,
+        total_online_stake: MicroAlgos,
+        expected_committee_size: f64,
+    ) -> Result<Self> {
+        if total_online_stake.0 == 0 {
+            return Err("Sortition requires a non-zero total online stake!".into());
+        };
+        if stake > total_online_stake {
+            return Err(format!(
+                "Sortition stake of {stake} exceeds the total online stake of {total_online_stake}!"
+            )
+            .into());
+        };
+        if !expected_committee_size.is_finite()
+            || expected_committee_size < 0.0
+            || expected_committee_size > total_online_stake.0 as f64
+        {
+            return Err(format!(
+                "Invalid expected committee size of {expected_committee_size} for sortition!"
+            )
+            .into());
+        };
+        Ok(Self {
+            stake,
+            total_online_stake,
+            expected_committee_size,
+        })
+    }
+
+    // NOTE: The VRF output as a fraction of the maximum 256 bit value. Like go-algorand, which
+    // divides big floats of 264 bits' precision, the quotient is rounded to 264 bits and then to
+    // the nearest float. The quotient is first taken to more bits than that, with the remainder
+    // kept as a sticky bit below them so that it's only rounded as a tie when it's exactly one.
+    fn get_ratio(vrf_output: &AlgorandHash) -> f64 {
+        let output = BigUint::from_bytes_be(&vrf_output.to_bytes());
+        if output.bits() == 0 {
+            return 0.0;
+        };
+        let max_output = (BigUint::from(1u8) << VRF_OUTPUT_BITS) - 1u8;
+        let scale = RATIO_PRECISION + 2 * VRF_OUTPUT_BITS;
+        let scaled_output = output << scale;
+        let quotient = &scaled_output / &max_output;
+        let is_inexact = &quotient * &max_output != scaled_output;
+        let quotient: BigUint = (quotient << 1u8) + u8::from(is_inexact);
+        let shift = quotient.bits() - RATIO_PRECISION;
+        let ratio = round_shift_right(&quotient, shift);
+        let float_shift = ratio.bits().saturating_sub(f64::MANTISSA_DIGITS as u64);
+        let mantissa = u64::try_from(round_shift_right(&ratio, float_shift)).unwrap_or_default();
+        let exponent = (shift + float_shift) as i32 - scale as i32 - 1;
+        mantissa as f64 * 2f64.powi(exponent)
+    }
+
+    /// ## Select
+    ///
+    /// Get the number of sub-users the given VRF output selects, by walking the binomial CDF
+    /// until it reaches the output's ratio to the maximum output, as go-algorand does. An
+    /// account is in the committee if any are selected, and votes with a weight of that many.
+    pub fn select(&self, vrf_output: &AlgorandHash) -> u64 {
+        binomial_cdf_walk(
+            self.stake.0 as f64,
+            self.expected_committee_size / self.total_online_stake.0 as f64,
+            Self::get_ratio(vrf_output),
+            self.stake.0,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use super::*;
+
+    // NOTE: The VRF output of the given bits set, as a big-endian 256 bit number.
+    fn get_vrf_output_with_bits(bits: &[u64]) -> AlgorandHash {
+        let output = bits.iter().fold(BigUint::from(0u8), |output, bit| {
+            output | (BigUint::from(1u8) << bit)
+        });
+        let bytes = output.to_bytes_be();
+        let mut padded_bytes = [0u8; 32];
+        padded_bytes[32 - bytes.len()..].copy_from_slice(&bytes);
+        AlgorandHash::from_slice(&padded_bytes).unwrap()
+    }
+
+    // NOTE: A VRF output of the given ratio to 2^256, which rounds to the same ratio to the
+    // maximum output for any ratio well away from the smallest.
+    fn get_vrf_output_with_ratio(ratio: f64) -> AlgorandHash {
+        let scaled_ratio = (ratio * 2f64.powi(256)).to_bits();
+        let exponent = (scaled_ratio >> 52) - 1075;
+        let mantissa = (scaled_ratio & ((1 << 52) - 1)) | (1 << 52);
+        let bytes = (BigUint::from(mantissa) << exponent).to_bytes_be();
+        let mut padded_bytes = [0u8; 32];
+        padded_bytes[32 - bytes.len()..].copy_from_slice(&bytes);
+        AlgorandHash::from_slice(&padded_bytes).unwrap()
+    }
+
+    fn get_max_vrf_output() -> AlgorandHash {
+        AlgorandHash::from_slice(&[0xff; 32]).unwrap()
+    }
+
+    fn get_mean_selection(sortition: AlgorandSortition, number_of_trials: u64) -> f64 {
+        let mut rng = StdRng::seed_from_u64(1337);
+        let hit_count = (0..number_of_trials)
+            .map(|_| {
+                let mut vrf_output = [0u8; 32];
+                rng.fill_bytes(&mut vrf_output);
+                sortition.select(&AlgorandHash::from_slice(&vrf_output).unwrap())
+            })
+            .sum::<u64>();
+        hit_count as f64 / number_of_trials as f64
+    }
+
+    #[test]
+    fn should_get_ratio_of_vrf_output_to_max() {
+        assert_eq!(AlgorandSortition::get_ratio(&AlgorandHash::default()), 0.0);
+        assert_eq!(AlgorandSortition::get_ratio(&get_max_vrf_output()), 1.0);
+        assert_eq!(
+            AlgorandSortition::get_ratio(&get_vrf_output_with_bits(&[255])),
+            0.5
+        );
+        assert_eq!(
+            AlgorandSortition::get_ratio(&get_vrf_output_with_bits(&[0])),
+            2f64.powi(-256)
+        );
+    }
+
+    #[test]
+    fn should_round_ratio_using_every_bit_of_vrf_output() {
+        // NOTE: 2^255 + 2^202 is exactly halfway between two floats as a fraction of 2^256, but
+        // it's just over halfway as a fraction of 2^256 - 1, so rounds up. Bar the lowest bit set
+        // beneath it, it's just under, so rounds down, despite the top 128 bits being the same.
+        assert_eq!(
+            AlgorandSortition::get_ratio(&get_vrf_output_with_bits(&[255, 202])),
+            0.5 + 2f64.powi(-53)
+        );
+        let bits = [255].into_iter().chain(0..202).collect::<Vec<u64>>();
+        assert_eq!(
+            AlgorandSortition::get_ratio(&get_vrf_output_with_bits(&bits)),
+            0.5
+        );
+    }
+
+    #[test]
+    fn should_select_sub_user_only_when_ratio_exceeds_cdf() {
+        // NOTE: A single sub-user chosen with probability 1/2 has a CDF of exactly 1/2 at zero.
+        let sortition = AlgorandSortition::new(MicroAlgos(1), MicroAlgos(2), 1.0).unwrap();
+        assert_eq!(sortition.select(&AlgorandHash::default()), 0);
+        assert_eq!(sortition.select(&get_vrf_output_with_bits(&[255])), 0);
+        assert_eq!(sortition.select(&get_vrf_output_with_bits(&[255, 202])), 1);
+        assert_eq!(sortition.select(&get_max_vrf_output()), 1);
+    }
+
+    #[test]
+    fn should_select_all_sub_users_for_max_vrf_output() {
+        // NOTE: The CDF never quite reaches one for fewer successes than trials, so the walk
+        // carries on past where it plateaus.
+        let sortition = AlgorandSortition::new(MicroAlgos(100), MicroAlgos(200), 20.0).unwrap();
+        assert_eq!(sortition.select(&get_max_vrf_output()), 100);
+    }
+
+    #[test]
+    fn should_select_all_sub_users_when_every_one_is_chosen() {
+        let sortition = AlgorandSortition::new(MicroAlgos(10), MicroAlgos(10), 10.0).unwrap();
+        assert_eq!(sortition.select(&AlgorandHash::default()), 0);
+        assert_eq!(sortition.select(&get_vrf_output_with_bits(&[0])), 10);
+        assert_eq!(sortition.select(&get_max_vrf_output()), 10);
+    }
+
+    #[test]
+    fn should_select_no_sub_users_when_none_are_chosen() {
+        let sortition = AlgorandSortition::new(MicroAlgos(10), MicroAlgos(10), 0.0).unwrap();
+        assert_eq!(sortition.select(&get_max_vrf_output()), 0);
+    }
+
+    #[test]
+    fn should_select_no_sub_users_for_zero_stake() {
+        let sortition = AlgorandSortition::new(MicroAlgos(0), MicroAlgos(200), 20.0).unwrap();
+        assert_eq!(sortition.select(&get_max_vrf_output()), 0);
+    }
+
+    #[test]
+    fn should_select_expected_number_of_sub_users_on_average() {
+        let sortition = AlgorandSortition::new(MicroAlgos(100), MicroAlgos(200), 20.0).unwrap();
+        let result = get_mean_selection(sortition, 10_000);
+        assert!((result - 10.0).abs() <= 10.0 / 50.0);
+    }
+
+    #[test]
+    fn should_select_expected_number_of_sub_users_for_cert_committee_on_average() {
+        let sortition = AlgorandSortition::new(
+            MicroAlgos::from_algos(50_000_000).unwrap(),
+            MicroAlgos::from_algos(2_000_000_000).unwrap(),
+            2990.0,
+        )
+        .unwrap();
+        let result = get_mean_selection(sortition, 1_000);
+        assert!((result - 74.75).abs() <= 74.75 / 50.0);
+    }
+
+    #[test]
+    fn should_select_exact_quantiles_for_cert_committee() {
+        // NOTE: The exact binomial CDF of the number of sub-users chosen at `k - 1` & `k`, taken
+        // to 60 significant figures with mpmath, for the sortition's `p` as a float. A ratio
+        // between them must select exactly `k`.
+        let sortition = AlgorandSortition::new(
+            MicroAlgos::from_algos(50_000_000).unwrap(),
+            MicroAlgos::from_algos(2_000_000_000).unwrap(),
+            2990.0,
+        )
+        .unwrap();
+        [
+            (20, 1.4956930017738494e-14, 5.689291043204968e-14),
+            (40, 4.038845471645388e-06, 7.748131207393277e-06),
+            (50, 0.0009907612645306588, 0.0015327277798759214),
+            (55, 0.007308776462259456, 0.010338636739829652),
+            (60, 0.03518225133120704, 0.04597133219493038),
+            (70, 0.2759178162402376, 0.31673718005650114),
+            (75, 0.49616489501632904, 0.5421604145162414),
+            (80, 0.7132267701644296, 0.7504364141629588),
+            (90, 0.9528170720415984, 0.9625795354125445),
+            (100, 0.9969423949405309, 0.9977888492543827),
+            (110, 0.9999197428986448, 0.9999468324306612),
+            (120, 0.9999991012370888, 0.9999994515347761),
+            (130, 0.9999999954609373, 0.9999999974344954),
+        ]
+        .iter()
+        .for_each(|(k, lower_cdf, upper_cdf)| {
+            let vrf_output = get_vrf_output_with_ratio((lower_cdf + upper_cdf) / 2.0);
+            assert_eq!(sortition.select(&vrf_output), *k);
+        });
+    }
+
+    #[test]
+    fn should_fail_to_create_sortition_with_stake_exceeding_total() {
+        assert!(AlgorandSortition::new(MicroAlgos(201), MicroAlgos(200), 20.0).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_create_sortition_with_zero_total_stake() {
+        assert!(AlgorandSortition::new(MicroAlgos(0), MicroAlgos(0), 20.0).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_create_sortition_with_invalid_expected_committee_size() {
+        [f64::NAN, -1.0, 201.0]
+            .iter()
+            .for_each(|expected_committee_size| {
+                assert!(AlgorandSortition::new(
+                    MicroAlgos(100),
+                    MicroAlgos(200),
+                    *expected_committee_size
+                )
+                .is_err())
+            });
+    }
+}
@@ -0,0 +1,106 @@
+#![cfg(test)]
+use curve25519_dalek::{constants::ED25519_BASEPOINT_POINT, scalar::Scalar};
+use sha2::{Digest, Sha512};
+
+use crate::{
+    algorand_types::{Byte, Bytes},
+    algorand_vrf::{
+        vrf_proof::AlgorandVrfProof,
+        vrf_public_key::{ecvrf_hash_points, AlgorandVrfPublicKey},
+    },
+};
+
+pub struct VrfTestVector {
+    pub secret_key: Bytes,
+    pub public_key: AlgorandVrfPublicKey,
+    pub message: Bytes,
+    pub proof: AlgorandVrfProof,
+    pub output: Bytes,
+}
+
+impl VrfTestVector {
+    fn new(secret_key: &str, public_key: &str, message: &str, proof: &str, output: &str) -> Self {
+        Self {
+            secret_key: hex::decode(secret_key).unwrap(),
+            public_key: AlgorandVrfPublicKey::from_slice(&hex::decode(public_key).unwrap())
+                .unwrap(),
+            message: hex::decode(message).unwrap(),
+            proof: AlgorandVrfProof::from_slice(&hex::decode(proof).unwrap()).unwrap(),
+            output: hex::decode(output).unwrap(),
+        }
+    }
+}
+
+/// The ECVRF-ED25519-SHA512-Elligator2 test vectors from version 03 of the IETF VRF draft.
+pub fn get_sample_vrf_test_vectors() -> Vec<VrfTestVector> {
+    vec![
+        VrfTestVector::new(
+            "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "",
+            "b6b4699f87d56126c9117a7da55bd0085246f4c56dbc95d20172612e9d38e8d7ca65e573a126ed88d4e30a46f80a666854d675cf3ba81de0de043c3774f061560f55edc256a787afe701677c0f602900",
+            "5b49b554d05c0cd5a5325376b3387de59d924fd1e13ded44648ab33c21349a603f25b84ec5ed887995b33da5e3bfcb87cd2f64521c4c62cf825cffabbe5d31cc",
+        ),
+        VrfTestVector::new(
+            "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "72",
+            "ae5b66bdf04b4c010bfe32b2fc126ead2107b697634f6f7337b9bff8785ee111200095ece87dde4dbe87343f6df3b107d91798c8a7eb1245d3bb9c5aafb093358c13e6ae1111a55717e895fd15f99f07",
+            "94f4487e1b2fec954309ef1289ecb2e15043a2461ecc7b2ae7d4470607ef82eb1cfa97d84991fe4a7bfdfd715606bc27e2967a6c557cfb5875879b671740b7d8",
+        ),
+        VrfTestVector::new(
+            "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "af82",
+            "dfa2cba34b611cc8c833a6ea83b8eb1bb5e2ef2dd1b0c481bc42ff36ae7847f6ab52b976cfd5def172fa412defde270c8b8bdfbaae1c7ece17d9833b1bcf31064fff78ef493f820055b561ece45e1009",
+            "2031837f582cd17a9af9e0c7ef5a6540e3453ed894b62c293686ca3c1e319dde9d0aa489a4b59a9594fc2328bc3deff3c8a0929a369a72b1180a596e016b5ded",
+        ),
+    ]
+}
+
+/// Prove the message with the given ed25519 secret key seed, deterministically deriving the
+/// nonce the way libsodium does.
+pub fn vrf_prove(
+    secret_key: &[Byte],
+    message: &[Byte],
+) -> (AlgorandVrfPublicKey, AlgorandVrfProof) {
+    let expanded_key = Sha512::digest(secret_key);
+    let mut x_bytes = [0u8; 32];
+    x_bytes.copy_from_slice(&expanded_key[..32]);
+    x_bytes[0] &= 248;
+    x_bytes[31] &= 127;
+    x_bytes[31] |= 64;
+    let x = Scalar::from_bytes_mod_order(x_bytes);
+    let public_key =
+        AlgorandVrfPublicKey::from_slice((x * ED25519_BASEPOINT_POINT).compress().as_bytes())
+            .unwrap();
+    let h = public_key.hash_to_curve(message).unwrap();
+    let gamma = x * h;
+    let mut nonce_bytes = [0u8; 64];
+    nonce_bytes.copy_from_slice(&Sha512::digest(
+        [&expanded_key[32..], h.compress().as_bytes()].concat(),
+    ));
+    let k = Scalar::from_bytes_mod_order_wide(&nonce_bytes);
+    let c_bytes = ecvrf_hash_points(&[h, gamma, k * ED25519_BASEPOINT_POINT, k * h]);
+    let mut c_scalar_bytes = [0u8; 32];
+    c_scalar_bytes[..16].copy_from_slice(&c_bytes);
+    let s = k + Scalar::from_bytes_mod_order(c_scalar_bytes) * x;
+    let proof = AlgorandVrfProof::from_slice(
+        &[gamma.compress().as_bytes(), &c_bytes[..], s.as_bytes()].concat(),
+    )
+    .unwrap();
+    (public_key, proof)
+}
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_prove_test_vectors() {
+        get_sample_vrf_test_vectors().iter().for_each(|vector| {
+            let (public_key, proof) = vrf_prove(&vector.secret_key, &vector.message);
+            assert_eq!(public_key, vector.public_key);
+            assert_eq!(proof, vector.proof);
+        });
+    }
+}
@@ -0,0 +1,98 @@
+use base64::decode as base64_decode;
+use curve25519_dalek::{
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    scalar::Scalar,
+};
+use sha2::{Digest, Sha512};
+
+use crate::algorand_types::{Byte, Bytes, Result};
+
+pub(crate) const ECVRF_SUITE: Byte = 0x04;
+
+const ALGORAND_VRF_PROOF_NUM_BYTES: usize = 80;
+const ECVRF_PROOF_TO_HASH_DOMAIN_SEPARATOR: Byte = 0x03;
+
+/// ## Algorand VRF Proof
+///
+/// An ECVRF-ED25519-SHA512-Elligator2 proof, as per version 03 of the IETF VRF draft, which is
+/// the variant Algorand's proposers prove their seeds & credentials with. It's the point `Gamma`,
+/// followed by the 16 byte challenge `c` and the 32 byte scalar `s`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlgorandVrfProof([Byte; ALGORAND_VRF_PROOF_NUM_BYTES]);
+
+impl AlgorandVrfProof {
+    /// ## From Slice
+    ///
+    /// Construct a VRF proof from a slice of bytes. Errors if the number of bytes is not the
+    /// expected amount.
+    pub fn from_slice(bytes: &[Byte]) -> Result<Self> {
+        let number_of_bytes = bytes.len();
+        if number_of_bytes != ALGORAND_VRF_PROOF_NUM_BYTES {
+            Err(format!(
+                "Wrong number of bytes for VRF proof! Got {number_of_bytes}, expected {ALGORAND_VRF_PROOF_NUM_BYTES}."
+            )
+            .into())
+        } else {
+            Ok(Self(bytes.try_into()?))
+        }
+    }
+
+    pub fn from_base_64(s: &str) -> Result<Self> {
+        Self::from_slice(&base64_decode(s)?)
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        self.0.to_vec()
+    }
+
+    pub(crate) fn get_gamma(&self) -> Result<EdwardsPoint> {
+        CompressedEdwardsY::from_slice(&self.0[..32])
+            .decompress()
+            .ok_or_else(|| "VRF proof's gamma is not a point on the curve!".into())
+    }
+
+    pub(crate) fn get_c(&self) -> Scalar {
+        let mut c_bytes = [0u8; 32];
+        c_bytes[..16].copy_from_slice(&self.0[32..48]);
+        Scalar::from_bytes_mod_order(c_bytes)
+    }
+
+    pub(crate) fn get_c_bytes(&self) -> &[Byte] {
+        &self.0[32..48]
+    }
+
+    pub(crate) fn get_s(&self) -> Scalar {
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&self.0[48..]);
+        Scalar::from_bytes_mod_order(s_bytes)
+    }
+
+    /// ## To Hash
+    ///
+    /// Get the VRF output the proof commits to, without verifying the proof. That's the SHA-512
+    /// hash of the suite & domain separator bytes, followed by the cofactor multiple of `Gamma`.
+    pub fn to_hash(&self) -> Result<Bytes> {
+        let mut hasher = Sha512::new();
+        hasher.update([ECVRF_SUITE, ECVRF_PROOF_TO_HASH_DOMAIN_SEPARATOR]);
+        hasher.update(self.get_gamma()?.mul_by_cofactor().compress().as_bytes());
+        Ok(hasher.finalize().to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_vrf::test_utils::get_sample_vrf_test_vectors;
+
+    #[test]
+    fn should_get_vrf_output_from_proof() {
+        get_sample_vrf_test_vectors()
+            .iter()
+            .for_each(|vector| assert_eq!(vector.proof.to_hash().unwrap(), vector.output));
+    }
+
+    #[test]
+    fn should_fail_to_get_vrf_proof_from_wrong_number_of_bytes() {
+        assert!(AlgorandVrfProof::from_slice(&[0u8; 79]).is_err());
+    }
+}
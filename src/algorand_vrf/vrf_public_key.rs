@@ -0,0 +1,158 @@
+use base64::decode as base64_decode;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use sha2::{Digest, Sha512};
+
+use crate::{
+    algorand_types::{Byte, Bytes, Result},
+    algorand_vrf::{
+        elligator2::hash_to_curve_elligator2,
+        vrf_proof::{AlgorandVrfProof, ECVRF_SUITE},
+    },
+};
+
+const ALGORAND_VRF_PUBLIC_KEY_NUM_BYTES: usize = 32;
+const ECVRF_HASH_TO_CURVE_DOMAIN_SEPARATOR: Byte = 0x01;
+const ECVRF_HASH_POINTS_DOMAIN_SEPARATOR: Byte = 0x02;
+
+/// ## ECVRF Hash Points
+///
+/// The challenge of a VRF proof, namely the first 16 bytes of the SHA-512 hash of the suite &
+/// domain separator bytes followed by each of the given points.
+pub(crate) fn ecvrf_hash_points(points: &[EdwardsPoint]) -> Bytes {
+    let mut hasher = Sha512::new();
+    hasher.update([ECVRF_SUITE, ECVRF_HASH_POINTS_DOMAIN_SEPARATOR]);
+    points
+        .iter()
+        .for_each(|point| hasher.update(point.compress().as_bytes()));
+    hasher.finalize()[..16].to_vec()
+}
+
+/// ## Algorand VRF Public Key
+///
+/// The public key a participant's VRF proofs, such as those of the seeds of the blocks they
+/// propose, are verified with. It's the key registered as the selection key of their
+/// participation keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AlgorandVrfPublicKey([Byte; ALGORAND_VRF_PUBLIC_KEY_NUM_BYTES]);
+
+impl AlgorandVrfPublicKey {
+    /// ## From Slice
+    ///
+    /// Construct a VRF public key from a slice of bytes. Errors if the number of bytes is not the
+    /// expected amount.
+    pub fn from_slice(bytes: &[Byte]) -> Result<Self> {
+        let number_of_bytes = bytes.len();
+        if number_of_bytes != ALGORAND_VRF_PUBLIC_KEY_NUM_BYTES {
+            Err(format!(
+                "Wrong number of bytes for VRF public key! Got {number_of_bytes}, expected {ALGORAND_VRF_PUBLIC_KEY_NUM_BYTES}."
+            )
+            .into())
+        } else {
+            Ok(Self(bytes.try_into()?))
+        }
+    }
+
+    pub fn from_base_64(s: &str) -> Result<Self> {
+        Self::from_slice(&base64_decode(s)?)
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        self.0.to_vec()
+    }
+
+    // NOTE: As in libsodium, keys must be canonically encoded points outside the small order
+    // subgroup, lest a proof be valid for more than one output.
+    fn get_point(&self) -> Result<EdwardsPoint> {
+        match CompressedEdwardsY(self.0).decompress() {
+            Some(point) if !point.is_small_order() && point.compress().as_bytes() == &self.0 => {
+                Ok(point)
+            },
+            _ => Err("Invalid VRF public key!".into()),
+        }
+    }
+
+    pub(crate) fn hash_to_curve(&self, message: &[Byte]) -> Result<EdwardsPoint> {
+        let mut hasher = Sha512::new();
+        hasher.update([ECVRF_SUITE, ECVRF_HASH_TO_CURVE_DOMAIN_SEPARATOR]);
+        hasher.update(self.0);
+        hasher.update(message);
+        let mut r_bytes = [0u8; 32];
+        r_bytes.copy_from_slice(&hasher.finalize()[..32]);
+        r_bytes[31] &= 0x7f;
+        hash_to_curve_elligator2(&r_bytes)
+    }
+
+    /// ## Verify
+    ///
+    /// Verify the VRF proof of the given message, returning the proof's 64 byte output if it's
+    /// valid.
+    ///
+    /// For `H` the message hashed to the curve, `Y` this key and `B` the base point, a proof
+    /// `(Gamma, c, s)` is valid if `c` is the hash of `H`, `Gamma`, `U = s * B - c * Y` and
+    /// `V = s * H - c * Gamma`.
+    pub fn verify(&self, proof: &AlgorandVrfProof, message: &[Byte]) -> Result<Bytes> {
+        let y = self.get_point()?;
+        let gamma = proof.get_gamma()?;
+        let (c, s) = (proof.get_c(), proof.get_s());
+        let h = self.hash_to_curve(message)?;
+        let u = EdwardsPoint::vartime_double_scalar_mul_basepoint(&-c, &y, &s);
+        let v = s * h - c * gamma;
+        if ecvrf_hash_points(&[h, gamma, u, v]) == proof.get_c_bytes() {
+            proof.to_hash()
+        } else {
+            Err("VRF proof verification failed!".into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorand_vrf::test_utils::get_sample_vrf_test_vectors;
+
+    #[test]
+    fn should_verify_vrf_proofs() {
+        get_sample_vrf_test_vectors().iter().for_each(|vector| {
+            let result = vector
+                .public_key
+                .verify(&vector.proof, &vector.message)
+                .unwrap();
+            assert_eq!(result, vector.output);
+        });
+    }
+
+    #[test]
+    fn should_fail_to_verify_vrf_proof_of_different_message() {
+        let vector = &get_sample_vrf_test_vectors()[0];
+        assert!(vector
+            .public_key
+            .verify(&vector.proof, b"different")
+            .is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_vrf_proof_under_different_key() {
+        let vectors = get_sample_vrf_test_vectors();
+        assert!(vectors[1]
+            .public_key
+            .verify(&vectors[0].proof, &vectors[0].message)
+            .is_err());
+    }
+
+    #[test]
+    fn should_fail_to_verify_vrf_proof_with_small_order_public_key() {
+        let vector = &get_sample_vrf_test_vectors()[0];
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let public_key = AlgorandVrfPublicKey::from_slice(&identity).unwrap();
+        assert!(public_key.verify(&vector.proof, &vector.message).is_err());
+    }
+
+    #[test]
+    fn should_get_points_in_prime_order_subgroup_when_hashing_to_curve() {
+        let vector = &get_sample_vrf_test_vectors()[0];
+        let point = vector.public_key.hash_to_curve(&vector.message).unwrap();
+        assert!(point.is_torsion_free());
+        assert!(!point.is_small_order());
+    }
+}
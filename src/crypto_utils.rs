@@ -1,5 +1,5 @@
 use base32::{decode as decodeFromBase32, encode as encodeInBase32, Alphabet as Base32Alphabet};
-use sha2::{Digest, Sha512_256};
+use sha2::{Digest, Sha256, Sha512_256};
 
 use crate::algorand_types::{Byte, Bytes, Result};
 
@@ -9,6 +9,12 @@ pub fn sha512_256_hash_bytes(bytes: &[u8]) -> Bytes {
     hasher.finalize().to_vec()
 }
 
+pub fn sha256_hash_bytes(bytes: &[u8]) -> Bytes {
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    hasher.finalize().to_vec()
+}
+
 fn base_32_encode_maybe_with_padding(bytes: &[Byte], padding: bool) -> String {
     encodeInBase32(Base32Alphabet::RFC4648 { padding }, bytes)
 }
@@ -24,9 +30,68 @@ pub fn base32_decode(s: &str) -> Result<Bytes> {
     }
 }
 
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// ## Base58 Encode
+///
+/// Encode bytes with the bitcoin base58 alphabet, where each leading zero byte is encoded as a
+/// leading `1`.
+pub fn base58_encode(bytes: &[Byte]) -> String {
+    let num_leading_zeros = bytes.iter().take_while(|byte| **byte == 0).count();
+    let mut digits: Vec<u8> = vec![];
+    for byte in &bytes[num_leading_zeros..] {
+        let mut carry = *byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    std::iter::repeat_n('1', num_leading_zeros)
+        .chain(
+            digits
+                .iter()
+                .rev()
+                .map(|digit| BASE58_ALPHABET[*digit as usize] as char),
+        )
+        .collect()
+}
+
+pub fn base58_decode(s: &str) -> Result<Bytes> {
+    let num_leading_ones = s.chars().take_while(|c| *c == '1').count();
+    let mut bytes: Bytes = vec![];
+    for c in s[num_leading_ones..].chars() {
+        let mut carry = match BASE58_ALPHABET.iter().position(|x| *x as char == c) {
+            Some(index) => index as u32,
+            None => {
+                return Err(
+                    format!("Error decoding string from base58: invalid char '{c}'!").into(),
+                )
+            },
+        };
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+    Ok(std::iter::repeat_n(0, num_leading_ones)
+        .chain(bytes.into_iter().rev())
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::algorand_errors::AlgorandError;
 
     fn get_bytes_to_encode() -> Bytes {
         b"Man is distinguished, not only by his reason, but by this singular passion from other animals, which is a lust of the mind, that by a perseverance of delight in the continued and indefatigable generation of knowledge, exceeds the short vehemence of any carnal pleasure.".to_vec()
@@ -57,4 +122,40 @@ mod tests {
         let decoded = base32_decode(&get_encoded_str()).unwrap();
         assert_eq!(decoded, get_bytes_to_encode());
     }
+
+    #[test]
+    fn should_sha256_hash_bytes_correctly() {
+        let result = hex::encode(sha256_hash_bytes(b"abc"));
+        // NOTE: `expected_result from https://en.wikipedia.org/wiki/SHA-2
+        let expected_result = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_base58_encode_correctly() {
+        let bytes =
+            hex::decode("1220cd3fe1495ce7545fe34e6c915780c678b4958e1134331850587e50529d0e3b2f")
+                .unwrap();
+        let result = base58_encode(&bytes);
+        let expected_result = "Qmc9rnPbtNCBnVncAGo5AtfZymepPKNqnUNVTzmF87iVok";
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_base58_round_trip_leading_zeros() {
+        let bytes = vec![0, 0, 1, 2, 3];
+        let encoded = base58_encode(&bytes);
+        assert_eq!(encoded, "11Ldp");
+        assert_eq!(base58_decode(&encoded).unwrap(), bytes);
+    }
+
+    #[test]
+    fn should_fail_to_base58_decode_invalid_char() {
+        let expected_error = "Error decoding string from base58: invalid char '0'!";
+        match base58_decode("Qm0") {
+            Ok(_) => panic!("Should not have succeeded!"),
+            Err(AlgorandError::Custom(error)) => assert_eq!(error, expected_error),
+            Err(_) => panic!("Wrong error received!"),
+        }
+    }
 }
@@ -1,5 +1,4 @@
 #![allow(dead_code)] // FIXME rm!
-#![allow(clippy::too_many_arguments)]
 
 //! # Rust-Algorand
 //!
@@ -9,47 +8,163 @@ extern crate quick_error;
 #[macro_use]
 extern crate lazy_static;
 
+mod algorand_accounts;
 mod algorand_address;
 mod algorand_applications;
 mod algorand_blocks;
 mod algorand_checksum;
 mod algorand_compact_certificates;
+mod algorand_consensus;
 mod algorand_constants;
 mod algorand_encoding;
 mod algorand_errors;
+mod algorand_falcon;
 mod algorand_genesis_id;
 mod algorand_hash;
+#[cfg(any(feature = "indexer", feature = "kmd"))]
+mod algorand_http;
+#[cfg(feature = "indexer")]
+mod algorand_indexer;
 mod algorand_keys;
+#[cfg(feature = "kmd")]
+mod algorand_kmd;
+mod algorand_logic_sig;
 mod algorand_micro_algos;
 mod algorand_mnemonic;
+mod algorand_multisig;
+mod algorand_nfts;
 mod algorand_signature;
+mod algorand_state_proofs;
 mod algorand_traits;
 mod algorand_transactions;
 mod algorand_types;
+mod algorand_vrf;
 mod crypto_utils;
 mod predicates;
 mod test_utils;
 
+#[cfg(any(feature = "indexer", feature = "kmd"))]
+pub use crate::algorand_http::{
+    http_transport::{
+        AlgorandHttpMethod,
+        AlgorandHttpRequest,
+        AlgorandHttpResponse,
+        AlgorandHttpTransport,
+    },
+    std_http_transport::StdHttpTransport,
+};
+#[cfg(feature = "indexer")]
+pub use crate::algorand_indexer::{
+    account_search_query::AccountSearchQuery,
+    indexer_client::{IndexerClient, IndexerSearchIterator, IndexerSearchQuery},
+    indexer_page::IndexerPage,
+    transaction_search_query::{AddressRole, TransactionSearchQuery},
+};
+#[cfg(feature = "kmd")]
+pub use crate::algorand_kmd::{
+    kmd_client::KmdClient,
+    kmd_json::{KmdWalletHandleJson, KmdWalletJson},
+    kmd_secrets::{WalletHandleToken, WalletPassword},
+};
 pub use crate::{
+    algorand_accounts::{
+        account_holdings::AccountHoldings,
+        account_json::{
+            AlgorandAccountJson,
+            ApplicationJson,
+            ApplicationLocalStateJson,
+            ApplicationParamsJson,
+            AssetHoldingJson,
+            AssetJson,
+        },
+    },
     algorand_address::AlgorandAddress,
     algorand_applications::{
         algorand_application_args::AlgorandApplicationArg,
         algorand_application_ids::AlgorandAppId,
+        algorand_box_reference::AlgorandBoxReference,
+        algorand_eval_delta::{
+            AlgorandByteString,
+            AlgorandEvalDelta,
+            AlgorandStateDelta,
+            AlgorandValueDelta,
+        },
+        algorand_state_schema::AlgorandStateSchema,
+    },
+    algorand_blocks::{
+        block::AlgorandBlock,
+        block_json::AlgorandBlockJson,
+        header_chain::AlgorandHeaderChain,
+        light_block_header::AlgorandLightBlockHeader,
+        light_block_header_proof::{
+            AlgorandLightBlockHeaderProof,
+            AlgorandLightBlockHeaderProofJson,
+        },
+        signed_transaction_in_block::AlgorandSignedTransactionInBlock,
+    },
+    algorand_consensus::{
+        consensus_params::ConsensusParams,
+        consensus_registry::ConsensusRegistry,
     },
-    algorand_blocks::{block::AlgorandBlock, block_json::AlgorandBlockJson},
     algorand_errors::AlgorandError,
+    algorand_falcon::falcon_verifier::AlgorandFalconVerifier,
     algorand_genesis_id::AlgorandGenesisId,
     algorand_hash::AlgorandHash,
     algorand_keys::AlgorandKeys,
+    algorand_logic_sig::AlgorandLogicSig,
     algorand_micro_algos::MicroAlgos,
     algorand_mnemonic::AlgorandMnemonic,
+    algorand_multisig::{AlgorandMultisig, AlgorandMultisigSubsig},
+    algorand_nfts::{
+        arc19_template_url::{Arc19Codec, Arc19TemplateUrl},
+        arc3_metadata::{Arc3Localization, Arc3Metadata},
+        arc69_metadata::{Arc69Attribute, Arc69Metadata},
+    },
+    algorand_state_proofs::{
+        merkle_array_proof::{AlgorandHashFactory, MerkleArrayProof},
+        state_proof::{
+            AlgorandFalconVerifyingKey,
+            AlgorandMerkleSignature,
+            AlgorandParticipant,
+            AlgorandReveal,
+            AlgorandSigSlotCommit,
+            MerkleSignatureVerifier,
+            StateProof,
+        },
+        state_proof_message::StateProofMessage,
+        state_proof_verifier::StateProofVerifier,
+    },
+    algorand_traits::ToAssetBaseUnits,
     algorand_transactions::{
         algorand_transaction_proof::{AlgorandTransactionProof, AlgorandTransactionProofJson},
+        application_transaction::OnCompletion,
+        apply_data::AlgorandApplyData,
+        asset_amount::AssetAmount,
         asset_parameters::AssetParameters,
+        asset_reconfiguration::{AssetReconfiguration, AssetRoleChange},
+        signing_options::SigningOptions,
+        suggested_params::{SuggestedParams, SuggestedParamsJson},
         transaction::{AlgorandSignedTransaction, AlgorandTransaction},
+        transaction_builder::{
+            ApplicationCallTx,
+            AssetConfigTx,
+            AssetFreezeTx,
+            AssetTransferTx,
+            KeyRegTx,
+            PaymentTx,
+            TransactionBuilder,
+            TransactionKind,
+        },
+        transaction_fees::AlgorandSignatureKind,
         transaction_json::AlgorandTransactionJson,
         transaction_type::AlgorandTransactionType,
         transactions::AlgorandTransactions,
         transactions_group::AlgorandTxGroup,
+        transactions_merkle_tree::AlgorandMerkleHashType,
+    },
+    algorand_vrf::{
+        sortition::AlgorandSortition,
+        vrf_proof::AlgorandVrfProof,
+        vrf_public_key::AlgorandVrfPublicKey,
     },
 };